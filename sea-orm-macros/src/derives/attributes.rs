@@ -15,6 +15,7 @@ pub mod derive_attr {
         pub table_name: Option<syn::Lit>,
         pub comment: Option<syn::Lit>,
         pub table_iden: Option<()>,
+        pub typed_columns: Option<()>,
         pub rename_all: Option<syn::Lit>,
     }
 }
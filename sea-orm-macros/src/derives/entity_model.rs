@@ -14,6 +14,7 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
     let mut comment = quote! {None};
     let mut schema_name = quote! { None };
     let mut table_iden = false;
+    let mut typed_columns = false;
     let mut rename_all: Option<CaseStyle> = None;
 
     attrs
@@ -31,6 +32,8 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     schema_name = quote! { Some(#name) };
                 } else if meta.path.is_ident("table_iden") {
                     table_iden = true;
+                } else if meta.path.is_ident("typed_columns") {
+                    typed_columns = true;
                 } else if meta.path.is_ident("rename_all") {
                     rename_all = Some((&meta).try_into()?);
                 } else {
@@ -78,6 +81,8 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
     let mut columns_save_as: Punctuated<_, Comma> = Punctuated::new();
     let mut primary_keys: Punctuated<_, Comma> = Punctuated::new();
     let mut primary_key_types: Punctuated<_, Comma> = Punctuated::new();
+    let mut typed_column_fields: Punctuated<_, Comma> = Punctuated::new();
+    let mut typed_column_values: Punctuated<_, Comma> = Punctuated::new();
     let mut auto_increment = true;
     if table_iden {
         if let Some(table_name) = table_name {
@@ -110,6 +115,7 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     let mut select_as = None;
                     let mut save_as = None;
                     let mut indexed = false;
+                    let mut full_text = false;
                     let mut ignore = false;
                     let mut unique = false;
                     let mut sql_type = None;
@@ -126,7 +132,7 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
 
                     let mut enum_name = None;
                     let mut is_primary_key = false;
-                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(StringLen::N(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", nullable, indexed, unique)]
+                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(StringLen::N(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", nullable, indexed, full_text, unique)]
                     for attr in field.attrs.iter() {
                         if !attr.path().is_ident("sea_orm") {
                             continue;
@@ -201,6 +207,8 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                 nullable = true;
                             } else if meta.path.is_ident("indexed") {
                                 indexed = true;
+                            } else if meta.path.is_ident("full_text") {
+                                full_text = true;
                             } else if meta.path.is_ident("unique") {
                                 unique = true;
                             } else {
@@ -239,6 +247,15 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                         });
                     }
 
+                    let field_type = &field.ty;
+                    typed_column_fields.push(quote! {
+                        #[doc = " Generated by sea-orm-macros"]
+                        pub #ident: sea_orm::prelude::TypedColumn<Column, #field_type>
+                    });
+                    typed_column_values.push(quote! {
+                        #ident: sea_orm::prelude::TypedColumn::new(Column::#field_name)
+                    });
+
                     if is_primary_key {
                         primary_keys.push(quote! {
                             #variant_attrs
@@ -283,6 +300,9 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     if indexed {
                         match_row = quote! { #match_row.indexed() };
                     }
+                    if full_text {
+                        match_row = quote! { #match_row.full_text() };
+                    }
                     if unique {
                         match_row = quote! { #match_row.unique() };
                     }
@@ -346,6 +366,23 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
         }
     };
 
+    let typed_columns = if typed_columns {
+        quote! {
+            #[doc = " Generated by sea-orm-macros"]
+            #[derive(Copy, Clone, Debug)]
+            pub struct TypedColumns {
+                #typed_column_fields
+            }
+
+            #[doc = " Generated by sea-orm-macros"]
+            pub const COLUMN: TypedColumns = TypedColumns {
+                #typed_column_values
+            };
+        }
+    } else {
+        quote! {}
+    };
+
     Ok(quote! {
         #[doc = " Generated by sea-orm-macros"]
         #[derive(Copy, Clone, Debug, sea_orm::prelude::EnumIter, sea_orm::prelude::DeriveColumn)]
@@ -384,6 +421,8 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
             }
         }
 
+        #typed_columns
+
         #entity_def
 
         #primary_key
@@ -17,6 +17,7 @@ enum Error {
     EntityNotSpecified,
     NotSupportGeneric(Span),
     OverlappingAttributes(Span),
+    WindowWithoutExpr(Span),
     Syn(syn::Error),
 }
 
@@ -32,6 +33,12 @@ enum ColumnAs {
         expr: syn::Expr,
         field: syn::Ident,
     },
+    /// from an expr over a window
+    Window {
+        expr: syn::Expr,
+        window: syn::Expr,
+        field: syn::Ident,
+    },
     /// nesting another struct
     Nested {
         typ: Type,
@@ -109,6 +116,7 @@ impl DerivePartialModel {
 
             let mut from_col = None;
             let mut from_expr = None;
+            let mut window = None;
             let mut nested = false;
             let mut nested_alias = None;
             let mut skip = false;
@@ -129,6 +137,8 @@ impl DerivePartialModel {
                             from_col = Some(format_ident!("{}", s.to_upper_camel_case()));
                         } else if let Some(s) = meta.get_as_kv("from_expr") {
                             from_expr = Some(syn::parse_str::<Expr>(&s).map_err(Error::Syn)?);
+                        } else if let Some(s) = meta.get_as_kv("window") {
+                            window = Some(syn::parse_str::<Expr>(&s).map_err(Error::Syn)?);
                         } else if let Some(s) = meta.get_as_kv("alias") {
                             nested_alias = Some(s);
                         }
@@ -138,6 +148,10 @@ impl DerivePartialModel {
 
             let field_name = field.ident.unwrap();
 
+            if window.is_some() && from_expr.is_none() {
+                return Err(Error::WindowWithoutExpr(field_span));
+            }
+
             let col_as = match (from_col, from_expr, nested) {
                 (Some(col), None, false) => {
                     if entity.is_none() {
@@ -149,9 +163,16 @@ impl DerivePartialModel {
                         field: field_name,
                     }
                 }
-                (None, Some(expr), false) => ColumnAs::Expr {
-                    expr,
-                    field: field_name,
+                (None, Some(expr), false) => match window {
+                    Some(window) => ColumnAs::Window {
+                        expr,
+                        window,
+                        field: field_name,
+                    },
+                    None => ColumnAs::Expr {
+                        expr,
+                        field: field_name,
+                    },
                 },
                 (None, None, true) => ColumnAs::Nested {
                     typ: field.ty,
@@ -206,6 +227,7 @@ impl DerivePartialModel {
                         ident: match col_as {
                             ColumnAs::Col { field, .. } => field,
                             ColumnAs::Expr { field, .. } => field,
+                            ColumnAs::Window { field, .. } => field,
                             ColumnAs::Nested { field, .. } => field,
                             ColumnAs::Skip(field) => field,
                         }
@@ -230,6 +252,7 @@ impl DerivePartialModel {
                         match col_as {
                             ColumnAs::Col { field, .. } => Some(field),
                             ColumnAs::Expr { field, .. } => Some(field),
+                            ColumnAs::Window { .. } => None,
                             ColumnAs::Nested { .. } => None,
                             ColumnAs::Skip(_) => None,
                         }
@@ -319,6 +342,21 @@ impl DerivePartialModel {
                     };
                 )
             }
+            ColumnAs::Window {
+                expr,
+                window,
+                field,
+            } => {
+                let field = field.unraw().to_string();
+                quote!(let #select_ident =
+                    if let Some(prefix) = pre {
+                        let ident = format!("{prefix}{}", #field);
+                        sea_orm::SelectColumns::select_window_as(#select_ident, #expr, #window, ident)
+                    } else {
+                        sea_orm::SelectColumns::select_window_as(#select_ident, #expr, #window, #field)
+                    };
+                )
+            }
             ColumnAs::Nested { typ, field, alias } => {
                 let field = field.unraw().to_string();
                 let alias_ref: Option<&str> = alias.as_deref();
@@ -368,6 +406,9 @@ pub fn expand_derive_partial_model(input: syn::DeriveInput) -> syn::Result<Token
         Err(Error::OverlappingAttributes(span)) => Ok(quote_spanned! {
             span => compile_error!("you can only use one of `from_col`, `from_expr`, `nested`");
         }),
+        Err(Error::WindowWithoutExpr(span)) => Ok(quote_spanned! {
+            span => compile_error!("`window` can only be used together with `from_expr`");
+        }),
         Err(Error::EntityNotSpecified) => Ok(quote_spanned! {
             ident_span => compile_error!("you need specific which entity you are using")
         }),
@@ -460,4 +501,30 @@ mod test {
 
         Ok(())
     }
+
+    const CODE_SNIPPET_3: &str = r#"
+        #[sea_orm(entity = "Entity")]
+        struct PartialModel {
+            #[sea_orm(from_expr = "WindowFunc::row_number()", window = "Window::new()")]
+            row_num: i64,
+        }
+        "#;
+
+    #[test]
+    fn test_load_macro_input_3() -> StdResult<()> {
+        let input = parse_str::<DeriveInput>(CODE_SNIPPET_3)?;
+
+        let middle = DerivePartialModel::new(input).unwrap();
+        assert_eq!(middle.fields.len(), 1);
+        assert_eq!(
+            middle.fields[0],
+            ColumnAs::Window {
+                expr: syn::parse_str("WindowFunc::row_number()").unwrap(),
+                window: syn::parse_str("Window::new()").unwrap(),
+                field: format_ident!("row_num"),
+            }
+        );
+
+        Ok(())
+    }
 }
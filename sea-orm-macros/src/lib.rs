@@ -786,6 +786,37 @@ pub fn derive_from_json_query_result(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// A `from_expr` can be evaluated over a window by adding `window`, which accepts a `Window`.
+/// ```
+/// use sea_orm::{
+///     entity::prelude::*, tests_cfg::fruit, DbBackend, DerivePartialModel, FromQueryResult,
+///     Order, QueryTrait, Window, WindowFunc,
+/// };
+///
+/// #[derive(Debug, FromQueryResult, DerivePartialModel)]
+/// #[sea_orm(entity = "fruit::Entity")]
+/// struct RankedFruit {
+///     name: String,
+///     #[sea_orm(
+///         from_expr = "WindowFunc::rank()",
+///         window = "Window::new().partition_by(fruit::Column::CakeId).order_by(fruit::Column::Name, Order::Asc)"
+///     )]
+///     rank: i64,
+/// }
+///
+/// assert_eq!(
+///     fruit::Entity::find()
+///         .into_partial_model::<RankedFruit>()
+///         .into_statement(DbBackend::Postgres)
+///         .to_string(),
+///     [
+///         r#"SELECT "fruit"."name" AS "name", RANK() OVER ("#,
+///         r#"PARTITION BY "fruit"."cake_id" ORDER BY "fruit"."name" ASC ) AS "rank" FROM "fruit""#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+///
 /// Since SeaORM 1.1.7, `DerivePartialModel` can also assumes the function of `FromQueryResult`.
 /// This is necessary to support nested partial models.
 ///
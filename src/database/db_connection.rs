@@ -590,6 +590,14 @@ impl DbBackend {
         }
     }
 
+    /// The maximum number of bind parameters in a single statement
+    pub fn max_bind_params(&self) -> usize {
+        match self {
+            Self::Postgres | Self::MySql => 65535,
+            Self::Sqlite => 32766,
+        }
+    }
+
     /// A getter for database dependent boolean value
     pub fn boolean_value(&self, boolean: bool) -> sea_query::Value {
         match self {
@@ -1,11 +1,13 @@
 use crate::{
-    debug_print, error::*, AccessMode, ConnectionTrait, DbBackend, DbErr, ExecResult,
-    InnerConnection, IsolationLevel, QueryResult, Statement, StreamTrait, TransactionStream,
-    TransactionTrait,
+    debug_print, error::*, executor::backend_name, AccessMode, ConnectionTrait, CopyData,
+    DbBackend, DbErr, ExecResult, InnerConnection, IsolationLevel, QueryResult, Statement,
+    StreamTrait, TransactionStream, TransactionTrait,
 };
 #[cfg(feature = "sqlx-dep")]
 use crate::{sqlx_error_to_exec_err, sqlx_error_to_query_err};
-use futures_util::lock::Mutex;
+use futures_util::{lock::Mutex, Stream};
+#[cfg(feature = "sqlx-postgres")]
+use futures_util::{lock::MutexGuard, StreamExt};
 #[cfg(feature = "sqlx-dep")]
 use sqlx::TransactionManager;
 use std::{future::Future, pin::Pin, sync::Arc};
@@ -193,6 +195,54 @@ impl DatabaseTransaction {
         Ok(())
     }
 
+    /// Send data to a `COPY ... FROM STDIN` statement within the transaction,
+    /// see [`CopyTrait::copy_in_raw`](crate::CopyTrait::copy_in_raw)
+    #[instrument(level = "trace", skip(data))]
+    #[allow(unused_variables)]
+    pub(crate) async fn copy_in_raw<S>(&self, sql: &str, data: S) -> Result<u64, DbErr>
+    where
+        S: Stream<Item = Result<Vec<u8>, DbErr>>,
+    {
+        debug_print!("{}", sql);
+
+        match &mut *self.conn.lock().await {
+            #[cfg(feature = "sqlx-postgres")]
+            InnerConnection::Postgres(conn) => {
+                let copy = conn
+                    .copy_in_raw(sql)
+                    .await
+                    .map_err(sqlx_error_to_exec_err)?;
+                crate::driver::sqlx_postgres::send_copy_data(copy, data).await
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(backend_not_supported(
+                backend_name(self.backend),
+                "COPY FROM STDIN",
+            )),
+        }
+    }
+
+    /// Stream the data of a `COPY ... TO STDOUT` statement within the transaction,
+    /// see [`CopyTrait::copy_out_raw`](crate::CopyTrait::copy_out_raw).
+    /// The statement is sent once the stream is polled, and the transaction
+    /// cannot run any other statement until the stream is dropped.
+    #[instrument(level = "trace")]
+    #[allow(unused_variables)]
+    pub(crate) async fn copy_out_raw(&self, sql: &str) -> Result<CopyData<'_>, DbErr> {
+        debug_print!("{}", sql);
+
+        let conn = self.conn.lock().await;
+        match *conn {
+            #[cfg(feature = "sqlx-postgres")]
+            InnerConnection::Postgres(_) => Ok(postgres_copy_out(conn, sql.to_owned())),
+            #[allow(unreachable_patterns)]
+            _ => Err(backend_not_supported(
+                backend_name(self.backend),
+                "COPY TO STDOUT",
+            )),
+        }
+    }
+
     // the rollback is queued and will be performed on next async operation, like returning the connection to the pool
     #[instrument(level = "trace")]
     fn start_rollback(&mut self) -> Result<(), DbErr> {
@@ -231,6 +281,20 @@ impl DatabaseTransaction {
     }
 }
 
+#[cfg(feature = "sqlx-postgres")]
+fn postgres_copy_out(mut conn: MutexGuard<'_, InnerConnection>, sql: String) -> CopyData<'_> {
+    Box::pin(async_stream::try_stream! {
+        if let InnerConnection::Postgres(c) = &mut *conn {
+            let mut data = crate::driver::sqlx_postgres::copy_data(
+                c.copy_out_raw(&sql).await.map_err(sqlx_error_to_query_err)?,
+            );
+            while let Some(chunk) = data.next().await {
+                yield chunk?;
+            }
+        }
+    })
+}
+
 impl Drop for DatabaseTransaction {
     fn drop(&mut self) {
         self.start_rollback().expect("Fail to rollback transaction");
@@ -1,12 +1,12 @@
-use futures_util::lock::Mutex;
+use futures_util::{lock::Mutex, stream::BoxStream, Stream, StreamExt};
 use log::LevelFilter;
 use sea_query::Values;
-use std::{fmt::Write, future::Future, pin::Pin, sync::Arc};
+use std::{fmt::Write, future::Future, ops::DerefMut, pin::Pin, sync::Arc};
 
 use sqlx::{
     pool::PoolConnection,
-    postgres::{PgConnectOptions, PgQueryResult, PgRow},
-    Connection, Executor, PgPool, Postgres,
+    postgres::{PgConnectOptions, PgCopyIn, PgPoolCopyExt, PgQueryResult, PgRow},
+    Connection, Executor, PgConnection, PgPool, Postgres,
 };
 
 use sea_query_binder::SqlxValues;
@@ -259,6 +259,36 @@ impl SqlxPostgresPoolConnection {
         self.metric_callback = Some(Arc::new(callback));
     }
 
+    /// Send data to a `COPY ... FROM STDIN` statement, returning the number of rows copied.
+    /// The copy is aborted if the data stream yields an error.
+    #[instrument(level = "trace", skip(data))]
+    pub async fn copy_in_raw<S>(&self, sql: &str, data: S) -> Result<u64, DbErr>
+    where
+        S: Stream<Item = Result<Vec<u8>, DbErr>>,
+    {
+        debug_print!("{}", sql);
+
+        let copy = self
+            .pool
+            .copy_in_raw(sql)
+            .await
+            .map_err(sqlx_error_to_exec_err)?;
+        send_copy_data(copy, data).await
+    }
+
+    /// Stream the data of a `COPY ... TO STDOUT` statement
+    #[instrument(level = "trace")]
+    pub async fn copy_out_raw(&self, sql: &str) -> Result<CopyData<'static>, DbErr> {
+        debug_print!("{}", sql);
+
+        let data = self
+            .pool
+            .copy_out_raw(sql)
+            .await
+            .map_err(sqlx_error_to_query_err)?;
+        Ok(copy_data(data))
+    }
+
     /// Checks if a connection to the database is still valid.
     pub async fn ping(&self) -> Result<(), DbErr> {
         let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
@@ -281,6 +311,44 @@ impl SqlxPostgresPoolConnection {
     }
 }
 
+/// Send data to a `COPY ... FROM STDIN` statement which has begun,
+/// aborting it if the data stream yields an error
+pub(crate) async fn send_copy_data<C, S>(mut copy: PgCopyIn<C>, data: S) -> Result<u64, DbErr>
+where
+    C: DerefMut<Target = PgConnection>,
+    S: Stream<Item = Result<Vec<u8>, DbErr>>,
+{
+    let mut data = std::pin::pin!(data);
+    while let Some(chunk) = data.next().await {
+        let res = match chunk {
+            Ok(chunk) => copy
+                .send(chunk)
+                .await
+                .map(|_| ())
+                .map_err(sqlx_error_to_exec_err),
+            Err(err) => Err(err),
+        };
+        if let Err(err) = res {
+            // the original error is more relevant than a failure to abort
+            let _ = copy.abort(err.to_string()).await;
+            return Err(err);
+        }
+    }
+    copy.finish().await.map_err(sqlx_error_to_exec_err)
+}
+
+/// The data of a `COPY ... TO STDOUT` statement
+pub(crate) fn copy_data<'a, B>(data: BoxStream<'a, Result<B, sqlx::Error>>) -> CopyData<'a>
+where
+    B: AsRef<[u8]> + 'a,
+{
+    Box::pin(data.map(|chunk| {
+        chunk
+            .map(|chunk| chunk.as_ref().to_vec())
+            .map_err(sqlx_error_to_query_err)
+    }))
+}
+
 impl From<PgRow> for QueryResult {
     fn from(row: PgRow) -> QueryResult {
         QueryResult {
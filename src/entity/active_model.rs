@@ -1,6 +1,6 @@
 use crate::{
     error::*, ConnectionTrait, DeleteResult, EntityTrait, Iterable, PrimaryKeyArity,
-    PrimaryKeyToColumn, PrimaryKeyTrait, Value,
+    PrimaryKeyToColumn, PrimaryKeyTrait, TypedColumn, Value,
 };
 use async_trait::async_trait;
 use sea_query::{Nullable, ValueTuple};
@@ -81,6 +81,15 @@ pub trait ActiveModelTrait: Clone + Debug {
         self.try_set(c, v).unwrap()
     }
 
+    /// Set the value of a [TypedColumn], checking its type at compile time
+    fn set_typed<T, V>(&mut self, c: TypedColumn<<Self::Entity as EntityTrait>::Column, T>, v: V)
+    where
+        T: Into<Value>,
+        V: Into<T>,
+    {
+        self.set(c.column(), v.into().into())
+    }
+
     /// Set the Value into an ActiveModel, return error if failed.
     ///
     /// This method is provided to prevent breaking changes, will be removed in next major version.
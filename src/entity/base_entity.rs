@@ -1,7 +1,8 @@
 use crate::{
-    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, Delete, DeleteMany, DeleteOne,
-    FromQueryResult, Insert, ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, Related,
-    RelationBuilder, RelationTrait, RelationType, Select, Update, UpdateMany, UpdateOne,
+    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, CopyIn, CopyOut, Delete, DeleteMany,
+    DeleteOne, FromQueryResult, Insert, ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait,
+    QueryFilter, Related, RelationBuilder, RelationTrait, RelationType, Select, Update, UpdateMany,
+    UpdateOne,
 };
 use sea_query::{Alias, Iden, IntoIden, IntoTableRef, IntoValueTuple, TableRef};
 use std::fmt::Debug;
@@ -997,6 +998,36 @@ pub trait EntityTrait: EntityName {
         }
         delete
     }
+
+    /// Bulk load Models into the table with Postgres `COPY ... FROM STDIN`, see [`CopyIn`]
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, tests_cfg::cake};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::copy_in().statement(&[cake::Column::Name]),
+    ///     r#"COPY "cake" ("name") FROM STDIN (FORMAT csv)"#
+    /// );
+    /// ```
+    fn copy_in() -> CopyIn<Self> {
+        CopyIn::new()
+    }
+
+    /// Stream the rows of the table out as Models with Postgres `COPY ... TO STDOUT`, see [`CopyOut`]
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::copy_out()
+    ///         .filter(cake::Column::Id.gt(10))
+    ///         .statement(),
+    ///     r#"COPY (SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" > 10) TO STDOUT (FORMAT csv)"#
+    /// );
+    /// ```
+    fn copy_out() -> CopyOut<Self> {
+        CopyOut::new()
+    }
 }
 
 #[cfg(test)]
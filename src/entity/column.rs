@@ -1,4 +1,12 @@
-use crate::{DbBackend, EntityName, Iden, IdenStatic, IntoSimpleExpr, Iterable};
+use super::json_path;
+use crate::{
+    DbBackend, EntityName, FullTextSearch, Iden, IdenStatic, IntoSimpleExpr, Iterable, JsonPath,
+};
+#[cfg(feature = "postgres-array")]
+use sea_query::{
+    extension::postgres::{PgBinOper, PgFunc},
+    Func,
+};
 use sea_query::{
     Alias, BinOper, DynIden, Expr, IntoIden, IntoLikeExpr, SeaRc, SelectStatement, SimpleExpr,
     Value,
@@ -16,6 +24,7 @@ pub struct ColumnDef {
     pub(crate) null: bool,
     pub(crate) unique: bool,
     pub(crate) indexed: bool,
+    pub(crate) full_text: bool,
     pub(crate) default: Option<SimpleExpr>,
     pub(crate) comment: Option<String>,
 }
@@ -263,6 +272,342 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     bind_subquery_func!(in_subquery);
     bind_subquery_func!(not_in_subquery);
 
+    /// Extract the JSON value at `path` of a JSON column, with
+    /// `->` on Postgres, `JSON_EXTRACT` on MySQL and `json_extract` on SQLite.
+    /// Usable in filters, ordering and selects alike.
+    ///
+    /// The type of the column is not checked: on Postgres it has to be `json` or `jsonb`,
+    /// while MySQL and SQLite also accept JSON stored as text.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Expr, tests_cfg::recipe, DbBackend, JsonPath};
+    ///
+    /// let path = JsonPath::from("tags").index(0);
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .filter(Expr::expr(recipe::Column::Details.json_get(DbBackend::Postgres, path.clone())).is_not_null())
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "recipe"."id", "recipe"."details" FROM "recipe" WHERE (("recipe"."details" -> 'tags') -> 0) IS NOT NULL"#
+    /// );
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .filter(Expr::expr(recipe::Column::Details.json_get(DbBackend::MySql, path)).is_not_null())
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `recipe`.`id`, `recipe`.`details` FROM `recipe` WHERE JSON_EXTRACT(`recipe`.`details`, '$.tags[0]') IS NOT NULL"
+    /// );
+    /// ```
+    fn json_get<P>(&self, db_backend: DbBackend, path: P) -> SimpleExpr
+    where
+        P: Into<JsonPath>,
+    {
+        json_path::json_get(
+            Expr::col((self.entity_name(), *self)).into(),
+            db_backend,
+            &path.into(),
+            false,
+        )
+    }
+
+    /// Extract the value at `path` of a JSON column as text, i.e. strings are unquoted, with
+    /// `->>` on Postgres, `JSON_UNQUOTE(JSON_EXTRACT(..))` on MySQL and `json_extract` on SQLite.
+    /// The type of the column is not checked, see [`ColumnTrait::json_get`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::recipe, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .filter(recipe::Column::Details.json_get_text(DbBackend::Postgres, ["a", "b"]).eq("c"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "recipe"."id", "recipe"."details" FROM "recipe" WHERE (("recipe"."details" -> 'a') ->> 'b') = 'c'"#
+    /// );
+    /// ```
+    fn json_get_text<P>(&self, db_backend: DbBackend, path: P) -> SimpleExpr
+    where
+        P: Into<JsonPath>,
+    {
+        json_path::json_get(
+            Expr::col((self.entity_name(), *self)).into(),
+            db_backend,
+            &path.into(),
+            true,
+        )
+    }
+
+    /// Whether the JSON document of the column has a value at `path`, with
+    /// `?` on Postgres, `JSON_CONTAINS_PATH` on MySQL and `json_type` on SQLite.
+    /// A JSON `null` counts as present.
+    ///
+    /// The type of the column is not checked, but on Postgres a column which is not
+    /// `jsonb` is cast to it, as `?` is only defined for `jsonb`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::recipe, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .filter(recipe::Column::Details.json_has_key(DbBackend::Sqlite, "a"))
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     r#"SELECT "recipe"."id", "recipe"."details" FROM "recipe" WHERE json_type("recipe"."details", '$.a') IS NOT NULL"#
+    /// );
+    /// ```
+    fn json_has_key<P>(&self, db_backend: DbBackend, path: P) -> SimpleExpr
+    where
+        P: Into<JsonPath>,
+    {
+        json_path::json_has_key(
+            Expr::col((self.entity_name(), *self)).into(),
+            self.def().get_column_type(),
+            db_backend,
+            &path.into(),
+        )
+    }
+
+    /// Whether the JSON value at `path` of the column contains `value`, with
+    /// `@>` on Postgres and `JSON_CONTAINS` on MySQL. SQLite has no such operator,
+    /// the containment rules are spelled out with `json_type`, `json_extract` and `json_each`.
+    ///
+    /// The type of the column is not checked, but on Postgres a column which is not
+    /// `jsonb` is cast to it, as `@>` is only defined for `jsonb`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::recipe, DbBackend, JsonPath};
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .filter(recipe::Column::Details.json_contains(
+    ///             DbBackend::MySql,
+    ///             JsonPath::root(),
+    ///             serde_json::json!({ "a": 1 })
+    ///         ))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     r#"SELECT `recipe`.`id`, `recipe`.`details` FROM `recipe` WHERE JSON_CONTAINS(`recipe`.`details`, '{\"a\":1}')"#
+    /// );
+    /// ```
+    #[cfg(feature = "with-json")]
+    fn json_contains<P, V>(&self, db_backend: DbBackend, path: P, value: V) -> SimpleExpr
+    where
+        P: Into<JsonPath>,
+        V: Into<serde_json::Value>,
+    {
+        json_path::json_contains(
+            Expr::col((self.entity_name(), *self)).into(),
+            self.def().get_column_type(),
+            db_backend,
+            &path.into(),
+            value.into(),
+        )
+    }
+
+    /// Whether the array column contains every element of `v`, with `@>` on Postgres.
+    /// For arrays stored as JSON on MySQL and SQLite (`json-array` feature), with
+    /// `JSON_CONTAINS` and `json_each` respectively.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::menu, DbBackend};
+    ///
+    /// let items = vec!["apple pie".to_owned(), "cheese cake".to_owned()];
+    /// assert_eq!(
+    ///     menu::Entity::find()
+    ///         .filter(menu::Column::Items.array_contains(DbBackend::Postgres, items.clone()))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "menu"."id", "menu"."items" FROM "menu" WHERE "menu"."items" @> ARRAY ['apple pie','cheese cake']"#
+    /// );
+    /// assert_eq!(
+    ///     menu::Entity::find()
+    ///         .filter(menu::Column::Items.array_contains(DbBackend::MySql, items))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `menu`.`id`, `menu`.`items` FROM `menu` WHERE JSON_CONTAINS(`menu`.`items`, JSON_ARRAY('apple pie', 'cheese cake'))"
+    /// );
+    /// ```
+    #[cfg(feature = "postgres-array")]
+    fn array_contains<V>(&self, db_backend: DbBackend, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        let col = Expr::col((self.entity_name(), *self));
+        match db_backend {
+            DbBackend::Postgres => col.binary(PgBinOper::Contains, self.save_as(Expr::val(v))),
+            DbBackend::MySql => {
+                json_path::func("JSON_CONTAINS", [col.into(), json_array(v.into())])
+            }
+            DbBackend::Sqlite => array_elements(v.into())
+                .into_iter()
+                .map(|elem| Expr::val(elem).in_subquery(json_each_values(col.clone().into())))
+                .reduce(SimpleExpr::and)
+                .unwrap_or_else(|| Expr::value(true)),
+        }
+    }
+
+    /// Whether every element of the array column is in `v`, with `<@` on Postgres.
+    /// For arrays stored as JSON on MySQL and SQLite (`json-array` feature), with
+    /// `JSON_CONTAINS` and `json_each` respectively.
+    #[cfg(feature = "postgres-array")]
+    fn array_contained_by<V>(&self, db_backend: DbBackend, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        let col = Expr::col((self.entity_name(), *self));
+        match db_backend {
+            DbBackend::Postgres => col.binary(PgBinOper::Contained, self.save_as(Expr::val(v))),
+            DbBackend::MySql => {
+                json_path::func("JSON_CONTAINS", [json_array(v.into()), col.into()])
+            }
+            DbBackend::Sqlite => Expr::exists(
+                json_each_values(col.into())
+                    .and_where(Expr::col(Alias::new("value")).is_not_in(array_elements(v.into())))
+                    .to_owned(),
+            )
+            .not(),
+        }
+    }
+
+    /// Whether the array column and `v` have an element in common, with `&&` on Postgres.
+    /// For arrays stored as JSON on MySQL and SQLite (`json-array` feature), with
+    /// `JSON_OVERLAPS` and `json_each` respectively.
+    #[cfg(feature = "postgres-array")]
+    fn array_overlaps<V>(&self, db_backend: DbBackend, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        let col = Expr::col((self.entity_name(), *self));
+        match db_backend {
+            DbBackend::Postgres => col.binary(PgBinOper::Overlap, self.save_as(Expr::val(v))),
+            DbBackend::MySql => {
+                json_path::func("JSON_OVERLAPS", [col.into(), json_array(v.into())])
+            }
+            DbBackend::Sqlite => Expr::exists(
+                json_each_values(col.into())
+                    .and_where(Expr::col(Alias::new("value")).is_in(array_elements(v.into())))
+                    .to_owned(),
+            ),
+        }
+    }
+
+    /// Whether the array column has an element equal to `v`, with `= ANY(..)` on Postgres.
+    /// For arrays stored as JSON on MySQL and SQLite (`json-array` feature), with
+    /// `JSON_CONTAINS` and `json_each` respectively.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::menu, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     menu::Entity::find()
+    ///         .filter(menu::Column::Items.any_eq(DbBackend::Postgres, "cheese cake"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "menu"."id", "menu"."items" FROM "menu" WHERE 'cheese cake' = ANY("menu"."items")"#
+    /// );
+    /// assert_eq!(
+    ///     menu::Entity::find()
+    ///         .filter(menu::Column::Items.any_eq(DbBackend::Sqlite, "cheese cake"))
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "menu"."id", "menu"."items" FROM "menu""#,
+    ///         r#"WHERE 'cheese cake' IN (SELECT "value" FROM json_each("menu"."items") AS "json_each")"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    #[cfg(feature = "postgres-array")]
+    fn any_eq<V>(&self, db_backend: DbBackend, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        let col = Expr::col((self.entity_name(), *self));
+        match db_backend {
+            DbBackend::Postgres => {
+                let val = match self.def().get_enum_name() {
+                    Some(enum_name) => Expr::val(v).as_enum(enum_name.clone()),
+                    None => Expr::val(v).into(),
+                };
+                Expr::expr(val).eq(PgFunc::any(col))
+            }
+            DbBackend::MySql => json_path::func(
+                "JSON_CONTAINS",
+                [
+                    col.into(),
+                    json_path::func("JSON_ARRAY", [Expr::val(v).into()]),
+                ],
+            ),
+            DbBackend::Sqlite => Expr::val(v).in_subquery(json_each_values(col.into())),
+        }
+    }
+
+    /// The number of elements of the array column, with `cardinality` on Postgres.
+    /// For arrays stored as JSON on MySQL and SQLite (`json-array` feature), with
+    /// `JSON_LENGTH` and `json_array_length` respectively.
+    #[cfg(feature = "postgres-array")]
+    fn array_length(&self, db_backend: DbBackend) -> SimpleExpr {
+        let col = Expr::col((self.entity_name(), *self)).into();
+        match db_backend {
+            DbBackend::Postgres => json_path::func("cardinality", [col]),
+            DbBackend::MySql => json_path::func("JSON_LENGTH", [col]),
+            DbBackend::Sqlite => json_path::func("json_array_length", [col]),
+        }
+    }
+
+    /// Full-text search in this column, see [`FullTextSearch`](crate::FullTextSearch) for the
+    /// query syntax and for searching several columns.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.matches_text(DbBackend::MySql, "cheese -blue"))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     r#"SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE MATCH (`cake`.`name`) AGAINST ('+\"cheese\" -\"blue\"' IN BOOLEAN MODE)"#
+    /// );
+    /// ```
+    fn matches_text(&self, db_backend: DbBackend, query: &str) -> SimpleExpr {
+        FullTextSearch::of_column(*self).matches_expr(db_backend, query)
+    }
+
+    /// The full-text search relevance of this column for `query`, higher is more relevant
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .select_only()
+    ///         .column(cake::Column::Id)
+    ///         .expr_as(cake::Column::Name.text_rank(DbBackend::Postgres, "cheese"), "rank")
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", ts_rank(to_tsvector('english', "cake"."name"), websearch_to_tsquery('english', 'cheese')) AS "rank" FROM "cake""#
+    /// );
+    /// ```
+    fn text_rank(&self, db_backend: DbBackend, query: &str) -> SimpleExpr {
+        FullTextSearch::of_column(*self).rank_expr(db_backend, query)
+    }
+
     /// Construct a [`SimpleExpr::Column`] wrapped in [`Expr`].
     fn into_expr(self) -> Expr {
         Expr::expr(self.into_simple_expr())
@@ -330,6 +675,7 @@ impl ColumnTypeTrait for ColumnType {
             null: false,
             unique: false,
             indexed: false,
+            full_text: false,
             default: None,
             comment: None,
         }
@@ -387,6 +733,12 @@ impl ColumnDef {
         self
     }
 
+    /// Include the column in the full-text index of the Entity, see [`FullTextSearch`](crate::FullTextSearch)
+    pub fn full_text(mut self) -> Self {
+        self.full_text = true;
+        self
+    }
+
     /// Set the default value
     pub fn default_value<T>(mut self, value: T) -> Self
     where
@@ -424,6 +776,11 @@ impl ColumnDef {
     pub fn is_unique(&self) -> bool {
         self.unique
     }
+
+    /// Returns true if the column is part of the full-text index
+    pub fn is_full_text(&self) -> bool {
+        self.full_text
+    }
 }
 
 struct Text;
@@ -486,6 +843,38 @@ where
     }
 }
 
+/// The elements of an array value, or the value itself if it is not an array
+#[cfg(feature = "postgres-array")]
+fn array_elements(v: Value) -> Vec<Value> {
+    match v {
+        Value::Array(_, Some(elems)) => *elems,
+        Value::Array(_, None) => Vec::new(),
+        v => vec![v],
+    }
+}
+
+#[cfg(feature = "postgres-array")]
+fn json_array(v: Value) -> SimpleExpr {
+    json_path::func(
+        "JSON_ARRAY",
+        array_elements(v)
+            .into_iter()
+            .map(|elem| Expr::val(elem).into()),
+    )
+}
+
+/// `SELECT "value" FROM json_each(col)`
+#[cfg(feature = "postgres-array")]
+fn json_each_values(col: SimpleExpr) -> SelectStatement {
+    sea_query::Query::select()
+        .column(Alias::new("value"))
+        .from_function(
+            Func::cust(Alias::new("json_each")).arg(col),
+            Alias::new("json_each"),
+        )
+        .to_owned()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -1420,4 +1809,68 @@ mod tests {
             three: ActiveValue::set(3),
         });
     }
+
+    #[test]
+    #[cfg(feature = "postgres-array")]
+    fn array_operators() {
+        use crate::{QuerySelect, QueryTrait};
+        use sea_query::Expr;
+
+        mod post {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = "post")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub tags: Vec<String>,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        let query = |db: DbBackend| {
+            let tags = vec!["a".to_owned(), "b".to_owned()];
+            post::Entity::find()
+                .select_only()
+                .column(post::Column::Id)
+                .filter(post::Column::Tags.array_contained_by(db, tags.clone()))
+                .filter(post::Column::Tags.array_overlaps(db, tags))
+                .filter(Expr::expr(post::Column::Tags.array_length(db)).gt(1))
+                .build(db)
+                .to_string()
+        };
+
+        assert_eq!(
+            query(DbBackend::Postgres),
+            [
+                r#"SELECT "post"."id" FROM "post" WHERE "post"."tags" <@ ARRAY ['a','b']"#,
+                r#"AND ("post"."tags" && ARRAY ['a','b']) AND cardinality("post"."tags") > 1"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            query(DbBackend::MySql),
+            [
+                "SELECT `post`.`id` FROM `post` WHERE JSON_CONTAINS(JSON_ARRAY('a', 'b'), `post`.`tags`)",
+                "AND JSON_OVERLAPS(`post`.`tags`, JSON_ARRAY('a', 'b')) AND JSON_LENGTH(`post`.`tags`) > 1",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            query(DbBackend::Sqlite),
+            [
+                r#"SELECT "post"."id" FROM "post""#,
+                r#"WHERE (NOT EXISTS(SELECT "value" FROM json_each("post"."tags") AS "json_each" WHERE "value" NOT IN ('a', 'b')))"#,
+                r#"AND EXISTS(SELECT "value" FROM json_each("post"."tags") AS "json_each" WHERE "value" IN ('a', 'b'))"#,
+                r#"AND json_array_length("post"."tags") > 1"#,
+            ]
+            .join(" ")
+        );
+    }
 }
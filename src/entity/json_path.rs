@@ -0,0 +1,520 @@
+use crate::DbBackend;
+use sea_query::{
+    extension::postgres::PgBinOper, Alias, BinOper, ColumnType, Expr, Func, SimpleExpr,
+};
+
+/// A path into a JSON document, made of object keys and array indices.
+/// Used by the JSON operators of [`ColumnTrait`](crate::ColumnTrait).
+///
+/// ```
+/// use sea_orm::JsonPath;
+///
+/// let path = JsonPath::root().key("address").key("lines").index(0);
+/// assert_eq!(path.to_path_string(), "$.address.lines[0]");
+///
+/// assert_eq!(JsonPath::from("name"), JsonPath::root().key("name"));
+/// assert_eq!(JsonPath::from(["a", "b c"]).to_path_string(), r#"$.a."b c""#);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JsonPath {
+    segments: Vec<JsonPathSegment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum JsonPathSegment {
+    Key(String),
+    Index(u32),
+}
+
+impl JsonPath {
+    /// The path of the whole document
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    /// Step into the member `key` of an object
+    pub fn key<K>(mut self, key: K) -> Self
+    where
+        K: Into<String>,
+    {
+        self.segments.push(JsonPathSegment::Key(key.into()));
+        self
+    }
+
+    /// Step into the element at `index` of an array
+    pub fn index(mut self, index: u32) -> Self {
+        self.segments.push(JsonPathSegment::Index(index));
+        self
+    }
+
+    /// Whether this is the path of the whole document
+    pub fn is_root(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Render as a JSON path string as understood by MySQL and SQLite, e.g. `$.a[0]`.
+    /// Keys that are not plain identifiers are double quoted.
+    pub fn to_path_string(&self) -> String {
+        let mut path = String::from("$");
+        for segment in self.segments.iter() {
+            match segment {
+                JsonPathSegment::Key(key) => {
+                    let plain = key.chars().next().is_some_and(|c| !c.is_ascii_digit())
+                        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+                    if plain {
+                        path.push('.');
+                        path.push_str(key);
+                    } else {
+                        path.push_str(".\"");
+                        path.push_str(&key.replace('\\', "\\\\").replace('"', "\\\""));
+                        path.push('"');
+                    }
+                }
+                JsonPathSegment::Index(index) => path.push_str(&format!("[{index}]")),
+            }
+        }
+        path
+    }
+}
+
+impl From<&str> for JsonPath {
+    fn from(key: &str) -> Self {
+        Self::root().key(key)
+    }
+}
+
+impl From<String> for JsonPath {
+    fn from(key: String) -> Self {
+        Self::root().key(key)
+    }
+}
+
+impl<K, const N: usize> From<[K; N]> for JsonPath
+where
+    K: Into<String>,
+{
+    fn from(keys: [K; N]) -> Self {
+        keys.into_iter().fold(Self::root(), Self::key)
+    }
+}
+
+impl<K> From<Vec<K>> for JsonPath
+where
+    K: Into<String>,
+{
+    fn from(keys: Vec<K>) -> Self {
+        keys.into_iter().fold(Self::root(), Self::key)
+    }
+}
+
+impl JsonPathSegment {
+    fn pg_operand(&self) -> SimpleExpr {
+        match self {
+            Self::Key(key) => Expr::val(key.as_str()).into(),
+            Self::Index(index) => Expr::val(i32::try_from(*index).unwrap_or(i32::MAX)).into(),
+        }
+    }
+}
+
+/// Follow `segments` with `->`, using `->>` on the last hop if `text`
+fn pg_chain(expr: SimpleExpr, segments: &[JsonPathSegment], text: bool) -> SimpleExpr {
+    segments
+        .iter()
+        .enumerate()
+        .fold(expr, |expr, (i, segment)| {
+            let oper = if text && i + 1 == segments.len() {
+                PgBinOper::CastJsonField
+            } else {
+                PgBinOper::GetJsonField
+            };
+            expr.binary(oper, segment.pg_operand())
+        })
+}
+
+/// `?` and `@>` are only defined for `jsonb`
+fn pg_jsonb(col: SimpleExpr, col_type: &ColumnType) -> SimpleExpr {
+    match col_type {
+        ColumnType::JsonBinary => col,
+        _ => Expr::expr(col).cast_as(Alias::new("jsonb")),
+    }
+}
+
+pub(crate) fn func<I>(name: &str, args: I) -> SimpleExpr
+where
+    I: IntoIterator<Item = SimpleExpr>,
+{
+    Func::cust(Alias::new(name)).args(args).into()
+}
+
+pub(crate) fn json_get(
+    col: SimpleExpr,
+    db_backend: DbBackend,
+    path: &JsonPath,
+    text: bool,
+) -> SimpleExpr {
+    match db_backend {
+        DbBackend::Postgres if path.is_root() && text => {
+            col.binary(BinOper::Custom("#>>"), Expr::cust("'{}'"))
+        }
+        DbBackend::Postgres => pg_chain(col, &path.segments, text),
+        DbBackend::MySql => {
+            let value = if path.is_root() {
+                col
+            } else {
+                func(
+                    "JSON_EXTRACT",
+                    [col, Expr::val(path.to_path_string()).into()],
+                )
+            };
+            if text {
+                func("JSON_UNQUOTE", [value])
+            } else {
+                value
+            }
+        }
+        DbBackend::Sqlite => func(
+            "json_extract",
+            [col, Expr::val(path.to_path_string()).into()],
+        ),
+    }
+}
+
+pub(crate) fn json_has_key(
+    col: SimpleExpr,
+    col_type: &ColumnType,
+    db_backend: DbBackend,
+    path: &JsonPath,
+) -> SimpleExpr {
+    match db_backend {
+        DbBackend::Postgres => match path.segments.split_last() {
+            Some((JsonPathSegment::Key(key), parent)) => {
+                pg_chain(pg_jsonb(col, col_type), parent, false)
+                    .binary(BinOper::Custom("?"), Expr::val(key.as_str()))
+            }
+            _ => Expr::expr(pg_chain(col, &path.segments, false)).is_not_null(),
+        },
+        DbBackend::MySql => func(
+            "JSON_CONTAINS_PATH",
+            [
+                col,
+                Expr::val("one").into(),
+                Expr::val(path.to_path_string()).into(),
+            ],
+        ),
+        DbBackend::Sqlite => Expr::expr(func(
+            "json_type",
+            [col, Expr::val(path.to_path_string()).into()],
+        ))
+        .is_not_null(),
+    }
+}
+
+#[cfg(feature = "with-json")]
+pub(crate) fn json_contains(
+    col: SimpleExpr,
+    col_type: &ColumnType,
+    db_backend: DbBackend,
+    path: &JsonPath,
+    doc: serde_json::Value,
+) -> SimpleExpr {
+    match db_backend {
+        DbBackend::Postgres => pg_chain(pg_jsonb(col, col_type), &path.segments, false)
+            .binary(PgBinOper::Contains, Expr::val(doc)),
+        DbBackend::MySql => {
+            let mut args = vec![col, Expr::val(doc).into()];
+            if !path.is_root() {
+                args.push(Expr::val(path.to_path_string()).into());
+            }
+            func("JSON_CONTAINS", args)
+        }
+        DbBackend::Sqlite => sqlite::contains(col, path, &doc, 0),
+    }
+}
+
+/// SQLite has no containment operator, so it is spelled out with `json_type`, `json_extract`
+/// and `json_each` following the rules of Postgres' `@>`: objects match if every member is
+/// contained, arrays match if every element is contained in some element, scalars match if equal.
+#[cfg(feature = "with-json")]
+mod sqlite {
+    use super::{func, JsonPath};
+    use sea_query::{Alias, Expr, Query, SimpleExpr};
+    use serde_json::Value as Json;
+
+    pub(super) fn contains(
+        target: SimpleExpr,
+        path: &JsonPath,
+        doc: &Json,
+        depth: usize,
+    ) -> SimpleExpr {
+        let path_str = || -> SimpleExpr { Expr::val(path.to_path_string()).into() };
+        let json_type = func("json_type", [target.clone(), path_str()]);
+        match doc {
+            Json::Object(members) => {
+                members
+                    .iter()
+                    .fold(Expr::expr(json_type).eq("object"), |cond, (key, member)| {
+                        cond.and(contains(
+                            target.clone(),
+                            &path.clone().key(key.as_str()),
+                            member,
+                            depth,
+                        ))
+                    })
+            }
+            Json::Array(elements) => elements
+                .iter()
+                .fold(Expr::expr(json_type).eq("array"), |cond, element| {
+                    cond.and(array_contains(target.clone(), path, element, depth))
+                }),
+            _ => scalar_matches(
+                json_type,
+                func("json_extract", [target.clone(), path_str()]),
+                doc,
+            ),
+        }
+    }
+
+    fn array_contains(
+        target: SimpleExpr,
+        path: &JsonPath,
+        element: &Json,
+        depth: usize,
+    ) -> SimpleExpr {
+        let alias = Alias::new(format!("json_each_{depth}"));
+        let elem_type: SimpleExpr = Expr::col((alias.clone(), Alias::new("type"))).into();
+        let elem_value: SimpleExpr = Expr::col((alias.clone(), Alias::new("value"))).into();
+        let cond = match element {
+            Json::Object(_) | Json::Array(_) => {
+                let kind = if element.is_object() {
+                    "object"
+                } else {
+                    "array"
+                };
+                // guard the nested lookup, the value of a scalar element is not JSON text
+                Expr::case(
+                    Expr::expr(elem_type).eq(kind),
+                    contains(elem_value, &JsonPath::root(), element, depth + 1),
+                )
+                .finally(false)
+                .into()
+            }
+            _ => scalar_matches(elem_type, elem_value, element),
+        };
+        Expr::exists(
+            Query::select()
+                .expr(Expr::val(1))
+                .from_function(
+                    sea_query::Func::cust(Alias::new("json_each"))
+                        .args([target, Expr::val(path.to_path_string()).into()]),
+                    alias,
+                )
+                .and_where(cond)
+                .to_owned(),
+        )
+    }
+
+    fn scalar_matches(json_type: SimpleExpr, value: SimpleExpr, doc: &Json) -> SimpleExpr {
+        match doc {
+            Json::Null => Expr::expr(json_type).eq("null"),
+            Json::Bool(true) => Expr::expr(json_type).eq("true"),
+            Json::Bool(false) => Expr::expr(json_type).eq("false"),
+            Json::Number(number) => {
+                let number: sea_query::Value = match number.as_i64() {
+                    Some(i) => i.into(),
+                    None => number.as_f64().into(),
+                };
+                Expr::expr(json_type)
+                    .is_in(["integer", "real"])
+                    .and(Expr::expr(value).eq(number))
+            }
+            Json::String(s) => Expr::expr(json_type)
+                .eq("text")
+                .and(Expr::expr(value).eq(s.as_str())),
+            Json::Object(_) | Json::Array(_) => Expr::value(false),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-json")]
+mod tests {
+    use crate::{
+        entity::prelude::*, DbBackend, JsonPath, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+    };
+    use pretty_assertions::assert_eq;
+    use sea_query::{Expr, Order};
+    use serde_json::json;
+
+    mod doc {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "doc")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub data: Json,
+            #[sea_orm(column_type = "JsonBinary")]
+            pub bin: Json,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn json_get() {
+        let query = |db: DbBackend| {
+            doc::Entity::find()
+                .select_only()
+                .column_as(
+                    doc::Column::Data.json_get_text(db, ["address", "city"]),
+                    "city",
+                )
+                .filter(
+                    Expr::expr(doc::Column::Data.json_get(db, JsonPath::from("tags").index(0)))
+                        .is_not_null(),
+                )
+                .order_by(doc::Column::Data.json_get_text(db, "name"), Order::Asc)
+                .build(db)
+                .to_string()
+        };
+
+        assert_eq!(
+            query(DbBackend::Postgres),
+            [
+                r#"SELECT ("doc"."data" -> 'address') ->> 'city' AS "city" FROM "doc""#,
+                r#"WHERE (("doc"."data" -> 'tags') -> 0) IS NOT NULL"#,
+                r#"ORDER BY "doc"."data" ->> 'name' ASC"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            query(DbBackend::MySql),
+            [
+                r#"SELECT JSON_UNQUOTE(JSON_EXTRACT(`doc`.`data`, '$.address.city')) AS `city` FROM `doc`"#,
+                r#"WHERE JSON_EXTRACT(`doc`.`data`, '$.tags[0]') IS NOT NULL"#,
+                r#"ORDER BY JSON_UNQUOTE(JSON_EXTRACT(`doc`.`data`, '$.name')) ASC"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            query(DbBackend::Sqlite),
+            [
+                r#"SELECT json_extract("doc"."data", '$.address.city') AS "city" FROM "doc""#,
+                r#"WHERE json_extract("doc"."data", '$.tags[0]') IS NOT NULL"#,
+                r#"ORDER BY json_extract("doc"."data", '$.name') ASC"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn json_has_key() {
+        let query = |db: DbBackend, col: doc::Column, path: JsonPath| {
+            doc::Entity::find()
+                .select_only()
+                .column(doc::Column::Id)
+                .filter(col.json_has_key(db, path))
+                .build(db)
+                .to_string()
+        };
+
+        assert_eq!(
+            query(DbBackend::Postgres, doc::Column::Bin, ["a", "b"].into()),
+            r#"SELECT "doc"."id" FROM "doc" WHERE ("doc"."bin" -> 'a') ? 'b'"#
+        );
+        assert_eq!(
+            query(DbBackend::Postgres, doc::Column::Data, "a".into()),
+            r#"SELECT "doc"."id" FROM "doc" WHERE CAST("doc"."data" AS jsonb) ? 'a'"#
+        );
+        assert_eq!(
+            query(
+                DbBackend::Postgres,
+                doc::Column::Data,
+                JsonPath::root().index(2)
+            ),
+            r#"SELECT "doc"."id" FROM "doc" WHERE ("doc"."data" -> 2) IS NOT NULL"#
+        );
+        assert_eq!(
+            query(DbBackend::MySql, doc::Column::Data, ["a", "b"].into()),
+            "SELECT `doc`.`id` FROM `doc` WHERE JSON_CONTAINS_PATH(`doc`.`data`, 'one', '$.a.b')"
+        );
+        assert_eq!(
+            query(DbBackend::Sqlite, doc::Column::Data, ["a", "b"].into()),
+            r#"SELECT "doc"."id" FROM "doc" WHERE json_type("doc"."data", '$.a.b') IS NOT NULL"#
+        );
+    }
+
+    #[test]
+    fn json_contains() {
+        let query = |db: DbBackend, col: doc::Column, path: JsonPath, value: serde_json::Value| {
+            doc::Entity::find()
+                .select_only()
+                .column(doc::Column::Id)
+                .filter(col.json_contains(db, path, value))
+                .build(db)
+                .to_string()
+        };
+
+        assert_eq!(
+            query(
+                DbBackend::Postgres,
+                doc::Column::Bin,
+                "a".into(),
+                json!({ "b": 1 })
+            ),
+            r#"SELECT "doc"."id" FROM "doc" WHERE ("doc"."bin" -> 'a') @> E'{\"b\":1}'"#
+        );
+        assert_eq!(
+            query(
+                DbBackend::Postgres,
+                doc::Column::Data,
+                JsonPath::root(),
+                json!([1])
+            ),
+            r#"SELECT "doc"."id" FROM "doc" WHERE CAST("doc"."data" AS jsonb) @> '[1]'"#
+        );
+        assert_eq!(
+            query(
+                DbBackend::MySql,
+                doc::Column::Data,
+                "a".into(),
+                json!({ "b": 1 })
+            ),
+            r#"SELECT `doc`.`id` FROM `doc` WHERE JSON_CONTAINS(`doc`.`data`, '{\"b\":1}', '$.a')"#
+        );
+        assert_eq!(
+            query(
+                DbBackend::Sqlite,
+                doc::Column::Data,
+                JsonPath::root(),
+                json!({ "name": "x", "tags": ["y", { "z": true }] })
+            ),
+            [
+                r#"SELECT "doc"."id" FROM "doc" WHERE json_type("doc"."data", '$') = 'object'"#,
+                r#"AND (json_type("doc"."data", '$.name') = 'text' AND json_extract("doc"."data", '$.name') = 'x')"#,
+                r#"AND (json_type("doc"."data", '$.tags') = 'array'"#,
+                r#"AND EXISTS(SELECT 1 FROM json_each("doc"."data", '$.tags') AS "json_each_0""#,
+                r#"WHERE "json_each_0"."type" = 'text' AND "json_each_0"."value" = 'y')"#,
+                r#"AND EXISTS(SELECT 1 FROM json_each("doc"."data", '$.tags') AS "json_each_0""#,
+                r#"WHERE (CASE WHEN ("json_each_0"."type" = 'object')"#,
+                r#"THEN json_type("json_each_0"."value", '$') = 'object'"#,
+                r#"AND json_type("json_each_0"."value", '$.z') = 'true' ELSE FALSE END)))"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn json_path_string() {
+        assert_eq!(JsonPath::root().to_path_string(), "$");
+        assert_eq!(
+            JsonPath::from(vec!["1a", "a\"b"]).index(3).to_path_string(),
+            r#"$."1a"."a\"b"[3]"#
+        );
+    }
+}
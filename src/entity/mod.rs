@@ -101,6 +101,7 @@ mod active_model;
 mod base_entity;
 mod column;
 mod identity;
+mod json_path;
 mod link;
 mod model;
 mod partial_model;
@@ -108,15 +109,18 @@ mod partial_model;
 pub mod prelude;
 mod primary_key;
 mod relation;
+mod typed_column;
 
 pub use active_enum::*;
 pub use active_model::*;
 pub use base_entity::*;
 pub use column::*;
 pub use identity::*;
+pub use json_path::*;
 pub use link::*;
 pub use model::*;
 pub use partial_model::*;
 // pub use prelude::*;
 pub use primary_key::*;
 pub use relation::*;
+pub use typed_column::*;
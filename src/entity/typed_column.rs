@@ -0,0 +1,230 @@
+use crate::{ColumnTrait, IntoSimpleExpr};
+use sea_query::{SimpleExpr, Value};
+use std::{fmt, marker::PhantomData};
+
+/// A column carrying the Rust type `T` of its Model field, so the operands of
+/// comparisons are checked at compile time.
+///
+/// Generated by `DeriveEntityModel` with `#[sea_orm(typed_columns)]` as the fields of the `COLUMN`
+/// constant of the Entity module. The untyped `Column` enum works as before, it can be taken
+/// back with [`TypedColumn::column`].
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::typed_cake, DbBackend};
+///
+/// assert_eq!(
+///     typed_cake::Entity::find()
+///         .filter(typed_cake::COLUMN.id.between(1, 9))
+///         .filter(typed_cake::COLUMN.name.is_in(["apple", "cheese"]))
+///         .build(DbBackend::MySql)
+///         .to_string(),
+///     [
+///         "SELECT `typed_cake`.`id`, `typed_cake`.`name` FROM `typed_cake`",
+///         "WHERE (`typed_cake`.`id` BETWEEN 1 AND 9) AND `typed_cake`.`name` IN ('apple', 'cheese')",
+///     ]
+///     .join(" ")
+/// );
+/// ```
+///
+/// An operand of the wrong type does not compile:
+///
+/// ```compile_fail
+/// use sea_orm::{entity::*, query::*, tests_cfg::typed_cake};
+///
+/// typed_cake::Entity::find().filter(typed_cake::COLUMN.id.eq("abc"));
+/// ```
+pub struct TypedColumn<C, T> {
+    column: C,
+    ty: PhantomData<fn() -> T>,
+}
+
+impl<C, T> TypedColumn<C, T>
+where
+    C: ColumnTrait,
+    T: Into<Value>,
+{
+    /// Tie `column` to the Rust type `T`
+    pub const fn new(column: C) -> Self {
+        Self {
+            column,
+            ty: PhantomData,
+        }
+    }
+
+    /// The untyped column
+    pub fn column(&self) -> C {
+        self.column
+    }
+
+    /// See [`ColumnTrait::eq`]
+    pub fn eq<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<T>,
+    {
+        self.column.eq(v.into())
+    }
+
+    /// See [`ColumnTrait::ne`]
+    pub fn ne<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<T>,
+    {
+        self.column.ne(v.into())
+    }
+
+    /// See [`ColumnTrait::gt`]
+    pub fn gt<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<T>,
+    {
+        self.column.gt(v.into())
+    }
+
+    /// See [`ColumnTrait::gte`]
+    pub fn gte<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<T>,
+    {
+        self.column.gte(v.into())
+    }
+
+    /// See [`ColumnTrait::lt`]
+    pub fn lt<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<T>,
+    {
+        self.column.lt(v.into())
+    }
+
+    /// See [`ColumnTrait::lte`]
+    pub fn lte<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<T>,
+    {
+        self.column.lte(v.into())
+    }
+
+    /// See [`ColumnTrait::between`]
+    pub fn between<V>(&self, a: V, b: V) -> SimpleExpr
+    where
+        V: Into<T>,
+    {
+        self.column.between(a.into(), b.into())
+    }
+
+    /// See [`ColumnTrait::not_between`]
+    pub fn not_between<V>(&self, a: V, b: V) -> SimpleExpr
+    where
+        V: Into<T>,
+    {
+        self.column.not_between(a.into(), b.into())
+    }
+
+    /// See [`ColumnTrait::is_in`]
+    pub fn is_in<V, I>(&self, v: I) -> SimpleExpr
+    where
+        V: Into<T>,
+        I: IntoIterator<Item = V>,
+    {
+        self.column.is_in(v.into_iter().map(Into::into))
+    }
+
+    /// See [`ColumnTrait::is_not_in`]
+    pub fn is_not_in<V, I>(&self, v: I) -> SimpleExpr
+    where
+        V: Into<T>,
+        I: IntoIterator<Item = V>,
+    {
+        self.column.is_not_in(v.into_iter().map(Into::into))
+    }
+
+    /// See [`ColumnTrait::is_null`]
+    pub fn is_null(&self) -> SimpleExpr {
+        self.column.is_null()
+    }
+
+    /// See [`ColumnTrait::is_not_null`]
+    pub fn is_not_null(&self) -> SimpleExpr {
+        self.column.is_not_null()
+    }
+}
+
+impl<C, T> IntoSimpleExpr for TypedColumn<C, T>
+where
+    C: ColumnTrait,
+{
+    fn into_simple_expr(self) -> SimpleExpr {
+        self.column.into_simple_expr()
+    }
+}
+
+impl<C, T> Clone for TypedColumn<C, T>
+where
+    C: Copy,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C, T> Copy for TypedColumn<C, T> where C: Copy {}
+
+impl<C, T> fmt::Debug for TypedColumn<C, T>
+where
+    C: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypedColumn")
+            .field(&self.column)
+            .field(&std::any::type_name::<T>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entity::prelude::*, tests_cfg::*, ActiveModelTrait, ActiveValue, DbBackend, QueryFilter,
+        QueryTrait,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn typed_columns() {
+        assert_eq!(
+            typed_cake::Entity::find()
+                .filter(typed_cake::COLUMN.id.gt(1))
+                .filter(typed_cake::COLUMN.id.is_not_in([2, 3]))
+                .filter(typed_cake::COLUMN.name.ne("apple"))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "typed_cake"."id", "typed_cake"."name" FROM "typed_cake""#,
+                r#"WHERE "typed_cake"."id" > 1 AND "typed_cake"."id" NOT IN (2, 3) AND "typed_cake"."name" <> 'apple'"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            typed_cake::Entity::find()
+                .filter(typed_cake::COLUMN.name.eq(String::from("apple")))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            typed_cake::Entity::find()
+                .filter(typed_cake::Column::Name.eq("apple"))
+                .build(DbBackend::Postgres)
+                .to_string(),
+        );
+        assert!(matches!(
+            typed_cake::COLUMN.name.column(),
+            typed_cake::Column::Name
+        ));
+    }
+
+    #[test]
+    fn set_typed() {
+        let mut cake = typed_cake::ActiveModel::new();
+        cake.set_typed(typed_cake::COLUMN.name, "apple");
+        assert_eq!(cake.name, ActiveValue::set("apple".to_owned()));
+        assert_eq!(cake.id, ActiveValue::NotSet);
+    }
+}
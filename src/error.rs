@@ -120,6 +120,42 @@ impl Eq for DbErr {}
 #[error("Failed to match \"{0}\" as Column")]
 pub struct ColumnFromStrErr(pub String);
 
+/// Error while parsing filters and sorting with [`QueryParams`](crate::QueryParams)
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum QueryParamsErr {
+    /// The parameters are not well-formed
+    #[error("Malformed parameter `{0}`")]
+    Malformed(String),
+    /// There is no such column in the Entity
+    #[error("Unknown column `{0}`")]
+    UnknownColumn(String),
+    /// The column is not in the whitelist of filterable columns
+    #[error("Column `{0}` is not filterable")]
+    NotFilterable(String),
+    /// The column is not in the whitelist of sortable columns
+    #[error("Column `{0}` is not sortable")]
+    NotSortable(String),
+    /// There is no such operator
+    #[error("Unknown operator `{0}`")]
+    UnknownOperator(String),
+    /// The operator does not apply to the type of the column
+    #[error("Operator `{op}` is not supported on column `{column}`")]
+    UnsupportedOperator {
+        /// The column
+        column: String,
+        /// The operator
+        op: String,
+    },
+    /// The value cannot be converted to the type of the column
+    #[error("Invalid value `{value}` for column `{column}`")]
+    InvalidValue {
+        /// The column
+        column: String,
+        /// The value
+        value: String,
+    },
+}
+
 #[allow(dead_code)]
 pub(crate) fn conn_err<T>(s: T) -> DbErr
 where
@@ -160,6 +196,11 @@ where
     DbErr::Json(s.to_string())
 }
 
+#[allow(dead_code)]
+pub(crate) fn backend_not_supported(db: &str, ctx: &str) -> DbErr {
+    DbErr::Custom(format!("Database backend {db} does not support `{ctx}`"))
+}
+
 /// An error from unsuccessful SQL query
 #[derive(Error, Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
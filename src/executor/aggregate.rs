@@ -0,0 +1,524 @@
+use crate::{
+    error::*, ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, QueryResult, Select,
+    TryGetable, TryGetableMany,
+};
+use sea_query::{
+    Alias, Asterisk, ColumnType, Expr, Func, FunctionCall, SelectStatement, SimpleExpr, ValueType,
+};
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Count the rows of each group of the query.
+    ///
+    /// The grouped aggregates compose with [`QuerySelect::group_by`](crate::QuerySelect::group_by): the columns and expressions
+    /// selected by the query are decoded as the key `K`, a single value or a tuple, and the
+    /// aggregate is selected after them. The `GROUP BY` and `ORDER BY` of the query are kept.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[
+    /// #         maplit::btreemap! { "cake_id" => Some(1).into(), "value" => 3i64.into() },
+    /// #         maplit::btreemap! { "cake_id" => Some(2).into(), "value" => 1i64.into() },
+    /// #     ]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let counts: Vec<(Option<i32>, u64)> = fruit::Entity::find()
+    ///     .select_only()
+    ///     .column(fruit::Column::CakeId)
+    ///     .group_by(fruit::Column::CakeId)
+    ///     .order_by_asc(fruit::Column::CakeId)
+    ///     .count_by(&db)
+    ///     .await?;
+    ///
+    /// assert_eq!(counts, [(Some(1), 3), (Some(2), 1)]);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"SELECT "fruit"."cake_id", COUNT(*) AS "value" FROM "fruit" GROUP BY "fruit"."cake_id" ORDER BY "fruit"."cake_id" ASC"#,
+    ///         []
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn count_by<K, D>(self, db: &D) -> Result<Vec<(K, u64)>, DbErr>
+    where
+        K: TryGetableMany,
+        D: ConnectionTrait,
+    {
+        let db_backend = db.get_database_backend();
+        let stmt = grouped_query(self.query, Func::count(Expr::col(Asterisk)).into());
+        let rows = db.query_all(db_backend.build(&stmt)).await?;
+        rows.iter()
+            .map(|row| {
+                Ok((
+                    row.try_get_many_by_index()?,
+                    try_get_count(db_backend, row, VALUE)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Get the `SUM` of a column, or `None` if there are no rows.
+    /// The result is cast to the SQL type of `T` so it decodes the same way on every backend.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::MySql)
+    /// #     .append_query_results([[maplit::btreemap! { "value" => Some(6i64).into() }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let total: Option<i64> = fruit::Entity::find()
+    ///     .filter(fruit::Column::CakeId.eq(1))
+    ///     .sum(fruit::Column::Id, &db)
+    ///     .await?;
+    ///
+    /// assert_eq!(total, Some(6));
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::MySql,
+    ///         "SELECT CAST(SUM(`fruit`.`id`) AS signed) AS `value` FROM `fruit` WHERE `fruit`.`cake_id` = ?",
+    ///         [1.into()]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sum<T, C, D>(self, col: C, db: &D) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable + ValueType,
+        C: ColumnTrait,
+        D: ConnectionTrait,
+    {
+        self.aggregate(Func::sum(col.into_expr()), db).await
+    }
+
+    /// Get the `AVG` of a column, or `None` if there are no rows.
+    /// The result is cast to the SQL type of `T`, e.g. `f64` or `Decimal`.
+    pub async fn avg<T, C, D>(self, col: C, db: &D) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable + ValueType,
+        C: ColumnTrait,
+        D: ConnectionTrait,
+    {
+        self.aggregate(Func::avg(col.into_expr()), db).await
+    }
+
+    /// Get the `MIN` of a column, or `None` if there are no rows
+    pub async fn min<T, C, D>(self, col: C, db: &D) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable + ValueType,
+        C: ColumnTrait,
+        D: ConnectionTrait,
+    {
+        self.aggregate(Func::min(col.into_expr()), db).await
+    }
+
+    /// Get the `MAX` of a column, or `None` if there are no rows
+    pub async fn max<T, C, D>(self, col: C, db: &D) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable + ValueType,
+        C: ColumnTrait,
+        D: ConnectionTrait,
+    {
+        self.aggregate(Func::max(col.into_expr()), db).await
+    }
+
+    /// Get the `SUM` of a column for each group of the query, see [`Select::count_by`]
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Sqlite)
+    /// #     .append_query_results([[
+    /// #         maplit::btreemap! { "cake_id" => Some(1).into(), "name" => "apple".into(), "value" => Some(4i32).into() },
+    /// #         maplit::btreemap! { "cake_id" => Some(2).into(), "name" => "pear".into(), "value" => Some(5i32).into() },
+    /// #     ]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let totals: Vec<((Option<i32>, String), Option<i32>)> = fruit::Entity::find()
+    ///     .select_only()
+    ///     .column(fruit::Column::CakeId)
+    ///     .column(fruit::Column::Name)
+    ///     .group_by(fruit::Column::CakeId)
+    ///     .group_by(fruit::Column::Name)
+    ///     .sum_by(fruit::Column::Id, &db)
+    ///     .await?;
+    ///
+    /// assert_eq!(
+    ///     totals,
+    ///     [
+    ///         ((Some(1), "apple".to_owned()), Some(4)),
+    ///         ((Some(2), "pear".to_owned()), Some(5)),
+    ///     ]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Sqlite,
+    ///         [
+    ///             r#"SELECT "fruit"."cake_id", "fruit"."name", CAST(SUM("fruit"."id") AS integer) AS "value""#,
+    ///             r#"FROM "fruit" GROUP BY "fruit"."cake_id", "fruit"."name""#,
+    ///         ]
+    ///         .join(" ")
+    ///         .as_str(),
+    ///         []
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sum_by<K, T, C, D>(self, col: C, db: &D) -> Result<Vec<(K, Option<T>)>, DbErr>
+    where
+        K: TryGetableMany,
+        T: TryGetable + ValueType,
+        C: ColumnTrait,
+        D: ConnectionTrait,
+    {
+        self.aggregate_by(Func::sum(col.into_expr()), db).await
+    }
+
+    /// Get the `AVG` of a column for each group of the query, see [`Select::count_by`]
+    pub async fn avg_by<K, T, C, D>(self, col: C, db: &D) -> Result<Vec<(K, Option<T>)>, DbErr>
+    where
+        K: TryGetableMany,
+        T: TryGetable + ValueType,
+        C: ColumnTrait,
+        D: ConnectionTrait,
+    {
+        self.aggregate_by(Func::avg(col.into_expr()), db).await
+    }
+
+    /// Get the `MIN` of a column for each group of the query, see [`Select::count_by`]
+    pub async fn min_by<K, T, C, D>(self, col: C, db: &D) -> Result<Vec<(K, Option<T>)>, DbErr>
+    where
+        K: TryGetableMany,
+        T: TryGetable + ValueType,
+        C: ColumnTrait,
+        D: ConnectionTrait,
+    {
+        self.aggregate_by(Func::min(col.into_expr()), db).await
+    }
+
+    /// Get the `MAX` of a column for each group of the query, see [`Select::count_by`]
+    pub async fn max_by<K, T, C, D>(self, col: C, db: &D) -> Result<Vec<(K, Option<T>)>, DbErr>
+    where
+        K: TryGetableMany,
+        T: TryGetable + ValueType,
+        C: ColumnTrait,
+        D: ConnectionTrait,
+    {
+        self.aggregate_by(Func::max(col.into_expr()), db).await
+    }
+
+    /// Check whether any row matches the query
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[maplit::btreemap! { "exists" => 1.into() }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// assert!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.contains("cheese"))
+    ///         .exists(&db)
+    ///         .await?
+    /// );
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"SELECT 1 AS "exists" FROM "cake" WHERE "cake"."name" LIKE $1 LIMIT $2"#,
+    ///         ["%cheese%".into(), 1u64.into()]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn exists<D>(self, db: &D) -> Result<bool, DbErr>
+    where
+        D: ConnectionTrait,
+    {
+        let mut query = self.query;
+        query
+            .clear_selects()
+            .clear_order_by()
+            .expr_as(Expr::cust("1"), Alias::new("exists"))
+            .limit(1);
+        let stmt = db.get_database_backend().build(&query);
+        Ok(db.query_one(stmt).await?.is_some())
+    }
+
+    async fn aggregate<T, D>(self, func: FunctionCall, db: &D) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable + ValueType,
+        D: ConnectionTrait,
+    {
+        let db_backend = db.get_database_backend();
+        let mut query = self.query;
+        query
+            .clear_selects()
+            .clear_order_by()
+            .expr_as(cast_as::<T>(db_backend, func), Alias::new(VALUE));
+        match db.query_one(db_backend.build(&query)).await? {
+            Some(row) => row.try_get("", VALUE),
+            None => Ok(None),
+        }
+    }
+
+    async fn aggregate_by<K, T, D>(
+        self,
+        func: FunctionCall,
+        db: &D,
+    ) -> Result<Vec<(K, Option<T>)>, DbErr>
+    where
+        K: TryGetableMany,
+        T: TryGetable + ValueType,
+        D: ConnectionTrait,
+    {
+        let db_backend = db.get_database_backend();
+        let stmt = grouped_query(self.query, cast_as::<T>(db_backend, func));
+        let rows = db.query_all(db_backend.build(&stmt)).await?;
+        rows.iter()
+            .map(|row| Ok((row.try_get_many_by_index()?, row.try_get("", VALUE)?)))
+            .collect()
+    }
+}
+
+const VALUE: &str = "value";
+
+/// Select the aggregate after the keys selected by the query
+fn grouped_query(mut query: SelectStatement, value: SimpleExpr) -> SelectStatement {
+    query.expr_as(value, Alias::new(VALUE));
+    query
+}
+
+fn try_get_count(db_backend: DbBackend, row: &QueryResult, col: &str) -> Result<u64, DbErr> {
+    let count = match db_backend {
+        DbBackend::Postgres => row.try_get::<i64>("", col)?,
+        _ => row.try_get::<i32>("", col)?.into(),
+    };
+    <u64 as TryFrom<i64>>::try_from(count).map_err(|_| type_err(format!("Invalid count `{count}`")))
+}
+
+/// Cast the result of an aggregate function, whose type varies between backends,
+/// to the SQL type `T` is decoded from
+fn cast_as<T>(db_backend: DbBackend, func: FunctionCall) -> SimpleExpr
+where
+    T: ValueType,
+{
+    let expr = SimpleExpr::from(func);
+    match cast_type(db_backend, &T::column_type()) {
+        Some(ty) => expr.cast_as(Alias::new(ty)),
+        None => expr,
+    }
+}
+
+fn cast_type(db_backend: DbBackend, column_type: &ColumnType) -> Option<String> {
+    use ColumnType::*;
+
+    Some(match (db_backend, column_type) {
+        (DbBackend::Postgres, TinyInteger | SmallInteger) => "smallint".to_owned(),
+        (DbBackend::Postgres, Integer) => "integer".to_owned(),
+        (DbBackend::Postgres, BigInteger) => "bigint".to_owned(),
+        (DbBackend::Postgres, Float) => "real".to_owned(),
+        (DbBackend::Postgres, Double) => "double precision".to_owned(),
+        (DbBackend::Postgres, Decimal(_) | Money(_)) => "numeric".to_owned(),
+        (DbBackend::MySql, TinyInteger | SmallInteger | Integer | BigInteger) => {
+            "signed".to_owned()
+        }
+        (DbBackend::MySql, TinyUnsigned | SmallUnsigned | Unsigned | BigUnsigned) => {
+            "unsigned".to_owned()
+        }
+        (DbBackend::MySql, Float | Double) => "double".to_owned(),
+        (DbBackend::MySql, Decimal(Some((precision, scale)))) => {
+            format!("decimal({precision}, {scale})")
+        }
+        (DbBackend::MySql, Decimal(None)) => "decimal(65, 30)".to_owned(),
+        (
+            DbBackend::Sqlite,
+            TinyInteger | SmallInteger | Integer | BigInteger | TinyUnsigned | SmallUnsigned
+            | Unsigned | BigUnsigned,
+        ) => "integer".to_owned(),
+        (DbBackend::Sqlite, Float | Double) => "real".to_owned(),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, fruit};
+    use crate::{
+        entity::prelude::*, DbBackend, MockDatabase, QueryOrder, QuerySelect, Transaction,
+    };
+    use sea_query::{Expr, Func, SimpleExpr};
+    use std::collections::BTreeMap;
+
+    #[smol_potat::test]
+    async fn avg_postgres() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[BTreeMap::from([("value", Some(2.5f64).into())])]])
+            .append_query_results([Vec::<BTreeMap<&str, Value>>::new()])
+            .into_connection();
+
+        assert_eq!(
+            fruit::Entity::find()
+                .avg::<f64, _, _>(fruit::Column::Id, &db)
+                .await?,
+            Some(2.5)
+        );
+        assert_eq!(
+            fruit::Entity::find()
+                .avg::<f64, _, _>(fruit::Column::Id, &db)
+                .await?,
+            None
+        );
+
+        let stmt = Transaction::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT CAST(AVG("fruit"."id") AS double precision) AS "value" FROM "fruit""#,
+            [],
+        );
+        assert_eq!(db.into_transaction_log(), [stmt.clone(), stmt]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn max_by_string() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([[BTreeMap::from([
+                ("cake_id", Some(1i32).into()),
+                ("value", Some("Pear".to_owned()).into()),
+            ])]])
+            .into_connection();
+
+        assert_eq!(
+            fruit::Entity::find()
+                .select_only()
+                .column(fruit::Column::CakeId)
+                .group_by(fruit::Column::CakeId)
+                .order_by_desc(fruit::Column::CakeId)
+                .max_by::<Option<i32>, String, _, _>(fruit::Column::Name, &db)
+                .await?,
+            [(Some(1), Some("Pear".to_owned()))]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::MySql,
+                [
+                    "SELECT `fruit`.`cake_id`, MAX(`fruit`.`name`) AS `value` FROM `fruit`",
+                    "GROUP BY `fruit`.`cake_id` ORDER BY `fruit`.`cake_id` DESC",
+                ]
+                .join(" ")
+                .as_str(),
+                []
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn count_by_expr() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[BTreeMap::from([
+                ("name_length", 5i32.into()),
+                ("value", 2i64.into()),
+            ])]])
+            .into_connection();
+
+        let name_length = SimpleExpr::from(Func::char_length(Expr::col((
+            fruit::Entity,
+            fruit::Column::Name,
+        ))));
+        assert_eq!(
+            fruit::Entity::find()
+                .select_only()
+                .column_as(name_length.clone(), "name_length")
+                .group_by(name_length)
+                .count_by::<i32, _>(&db)
+                .await?,
+            [(5, 2)]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT CHAR_LENGTH("fruit"."name") AS "name_length", COUNT(*) AS "value""#,
+                    r#"FROM "fruit" GROUP BY CHAR_LENGTH("fruit"."name")"#,
+                ]
+                .join(" ")
+                .as_str(),
+                []
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn not_exists() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([Vec::<BTreeMap<&str, Value>>::new()])
+            .into_connection();
+
+        assert!(!cake::Entity::find_by_id(10).exists(&db).await?);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Sqlite,
+                r#"SELECT 1 AS "exists" FROM "cake" WHERE "cake"."id" = ? LIMIT ?"#,
+                [10i32.into(), 1u64.into()]
+            )]
+        );
+
+        Ok(())
+    }
+}
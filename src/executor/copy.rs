@@ -0,0 +1,603 @@
+use crate::value::{parse_value, value_kinds, value_text};
+use crate::{
+    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection,
+    DatabaseTransaction, DbBackend, EntityTrait, IdenStatic, IntoActiveModel, Iterable,
+    QueryFilter, QueryTrait, Select, TryIntoModel, Value,
+};
+use futures_util::{stream, Stream, StreamExt};
+use sea_query::SelectStatement;
+use std::{marker::PhantomData, pin::Pin};
+
+/// Number of rows sent to the database in a single message
+const ROWS_PER_MESSAGE: usize = 1024;
+
+/// The raw data streamed in by `COPY ... FROM STDIN` or out by `COPY ... TO STDOUT`
+pub type CopyData<'a> = Pin<Box<dyn Stream<Item = Result<Vec<u8>, DbErr>> + Send + 'a>>;
+
+/// A connection which can run Postgres `COPY` statements: a [`DatabaseConnection`],
+/// or a [`DatabaseTransaction`] to copy along with other statements atomically
+#[async_trait::async_trait]
+pub trait CopyTrait: ConnectionTrait {
+    /// Send data to a `COPY ... FROM STDIN` statement, returning the number of rows copied.
+    /// The copy is aborted if the data stream yields an error.
+    async fn copy_in_raw(&self, sql: &str, data: CopyData<'_>) -> Result<u64, DbErr>;
+
+    /// Stream the data of a `COPY ... TO STDOUT` statement
+    async fn copy_out_raw<'a>(&'a self, sql: &str) -> Result<CopyData<'a>, DbErr>;
+}
+
+/// Bulk load Models into a table with Postgres `COPY ... FROM STDIN`,
+/// which is much faster than `INSERT` for large amounts of rows.
+///
+/// The rows are sent as CSV, with the columns in the order of `Column::iter()`.
+/// Like [`Insert::many`](crate::Insert::many), the columns copied are the ones set in the first ActiveModel,
+/// and every other ActiveModel must set the same columns.
+///
+/// ```no_run
+/// use futures::stream;
+/// use sea_orm::{entity::*, error::*, tests_cfg::cake, DatabaseConnection, TransactionTrait};
+///
+/// # async fn function(db: &DatabaseConnection) -> Result<(), DbErr> {
+/// let txn = db.begin().await?;
+///
+/// cake::Entity::delete_many().exec(&txn).await?;
+///
+/// let rows = cake::Entity::copy_in()
+///     .exec(
+///         stream::iter((1..=1_000_000).map(|i| cake::ActiveModel {
+///             name: Set(format!("Cake {i}")),
+///             ..Default::default()
+///         })),
+///         &txn,
+///     )
+///     .await?;
+///
+/// txn.commit().await?;
+///
+/// assert_eq!(rows, 1_000_000);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CopyIn<E>
+where
+    E: EntityTrait,
+{
+    entity: PhantomData<E>,
+}
+
+/// Stream the rows of a table or a filtered Select out as Models with Postgres `COPY ... TO STDOUT`.
+///
+/// The rows are received as CSV, and each field is parsed according to the type of its column.
+/// Array columns are decoded from one-dimensional arrays.
+///
+/// ```no_run
+/// use futures::TryStreamExt;
+/// use sea_orm::{entity::*, error::*, query::*, tests_cfg::cake, DatabaseConnection};
+///
+/// # async fn function(db: &DatabaseConnection) -> Result<(), DbErr> {
+/// let mut cakes = cake::Entity::copy_out()
+///     .filter(cake::Column::Name.contains("chocolate"))
+///     .stream(db)
+///     .await?;
+///
+/// while let Some(cake) = cakes.try_next().await? {
+///     println!("{cake:?}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CopyOut<E>
+where
+    E: EntityTrait,
+{
+    select: Select<E>,
+}
+
+impl<E> Default for CopyIn<E>
+where
+    E: EntityTrait,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> CopyIn<E>
+where
+    E: EntityTrait,
+{
+    /// Copy into the table of the Entity
+    pub fn new() -> Self {
+        Self {
+            entity: PhantomData,
+        }
+    }
+
+    /// The `COPY` statement for the given columns
+    pub fn statement(&self, columns: &[E::Column]) -> String {
+        let columns: Vec<_> = columns.iter().map(|col| quote(col.as_str())).collect();
+        format!(
+            "COPY {} ({}) FROM STDIN (FORMAT csv)",
+            table_name::<E>(),
+            columns.join(", ")
+        )
+    }
+
+    /// Stream the models into the table, returning the number of rows copied.
+    ///
+    /// The rows are sent by a single `COPY` statement, so none of them is copied if the load
+    /// fails half way. Run it on a [`DatabaseTransaction`] to make it atomic with other statements.
+    pub async fn exec<M, S, C>(self, models: S, db: &C) -> Result<u64, DbErr>
+    where
+        M: IntoActiveModel<E::ActiveModel>,
+        S: Stream<Item = M> + Send,
+        C: CopyTrait,
+        E::ActiveModel: Send,
+    {
+        let mut models = Box::pin(models.map(IntoActiveModel::into_active_model));
+        let first = match models.next().await {
+            Some(first) => first,
+            None => return Ok(0),
+        };
+        let columns: Vec<E::Column> = E::Column::iter()
+            .filter(|col| !first.get(*col).is_not_set())
+            .collect();
+        let sql = self.statement(&columns);
+
+        let data = stream::once(async { first })
+            .chain(models)
+            .chunks(ROWS_PER_MESSAGE)
+            .map(move |models| {
+                let mut data = Vec::new();
+                for model in models {
+                    encode_row(&model, &columns, &mut data)?;
+                }
+                Ok::<_, DbErr>(data)
+            });
+
+        db.copy_in_raw(&sql, Box::pin(data)).await
+    }
+}
+
+impl<E> Default for CopyOut<E>
+where
+    E: EntityTrait,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> CopyOut<E>
+where
+    E: EntityTrait,
+{
+    /// Copy all rows of the table of the Entity
+    pub fn new() -> Self {
+        Self { select: E::find() }
+    }
+
+    /// The `COPY` statement, with the values of the filters inlined
+    pub fn statement(&self) -> String {
+        format!(
+            "COPY ({}) TO STDOUT (FORMAT csv)",
+            self.select.build(DbBackend::Postgres)
+        )
+    }
+
+    /// Stream the rows out as Models
+    pub async fn stream<'b, C>(
+        self,
+        db: &'b C,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<E::Model, DbErr>> + 'b + Send>>, DbErr>
+    where
+        C: CopyTrait,
+        E::ActiveModel: TryIntoModel<E::Model> + Send,
+        E::Model: 'b,
+    {
+        let sql = self.statement();
+        let mut data = db.copy_out_raw(&sql).await?;
+
+        let mut decoder = ModelDecoder::<E>::new();
+        Ok(Box::pin(async_stream::try_stream! {
+            let mut reader = CsvReader::default();
+            while let Some(chunk) = data.next().await {
+                reader.extend(&chunk?);
+                while let Some(record) = reader.next_record()? {
+                    yield decoder.decode(record)?;
+                }
+            }
+            reader.finish()?;
+        }))
+    }
+}
+
+impl<E> QueryFilter for CopyOut<E>
+where
+    E: EntityTrait,
+{
+    type QueryStatement = SelectStatement;
+
+    /// Only copy the rows matching the filters
+    fn query(&mut self) -> &mut SelectStatement {
+        QueryFilter::query(&mut self.select)
+    }
+}
+
+#[async_trait::async_trait]
+impl CopyTrait for DatabaseConnection {
+    #[allow(unused_variables)]
+    async fn copy_in_raw(&self, sql: &str, data: CopyData<'_>) -> Result<u64, DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                conn.copy_in_raw(sql, data).await
+            }
+            DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
+            #[allow(unreachable_patterns)]
+            _ => Err(backend_not_supported(
+                backend_name(self.get_database_backend()),
+                "COPY FROM STDIN",
+            )),
+        }
+    }
+
+    #[allow(unused_variables)]
+    async fn copy_out_raw<'a>(&'a self, sql: &str) -> Result<CopyData<'a>, DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.copy_out_raw(sql).await,
+            DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
+            #[allow(unreachable_patterns)]
+            _ => Err(backend_not_supported(
+                backend_name(self.get_database_backend()),
+                "COPY TO STDOUT",
+            )),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CopyTrait for DatabaseTransaction {
+    async fn copy_in_raw(&self, sql: &str, data: CopyData<'_>) -> Result<u64, DbErr> {
+        DatabaseTransaction::copy_in_raw(self, sql, data).await
+    }
+
+    async fn copy_out_raw<'a>(&'a self, sql: &str) -> Result<CopyData<'a>, DbErr> {
+        DatabaseTransaction::copy_out_raw(self, sql).await
+    }
+}
+
+pub(crate) fn backend_name(db: DbBackend) -> &'static str {
+    match db {
+        DbBackend::MySql => "MySQL",
+        DbBackend::Postgres => "PostgreSQL",
+        DbBackend::Sqlite => "SQLite",
+    }
+}
+
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn table_name<E>() -> String
+where
+    E: EntityTrait,
+{
+    let entity = E::default();
+    match entity.schema_name() {
+        Some(schema) => format!("{}.{}", quote(schema), quote(entity.table_name())),
+        None => quote(entity.table_name()),
+    }
+}
+
+/// Append the given columns of an ActiveModel as a CSV record
+fn encode_row<A>(
+    model: &A,
+    columns: &[<A::Entity as EntityTrait>::Column],
+    out: &mut Vec<u8>,
+) -> Result<(), DbErr>
+where
+    A: ActiveModelTrait,
+{
+    for (i, col) in columns.iter().enumerate() {
+        if i > 0 {
+            out.push(b',');
+        }
+        let value = model.get(*col).into_value().ok_or_else(|| {
+            DbErr::Custom(format!(
+                "Column `{}` is not set, but it is set in the first model",
+                col.as_str()
+            ))
+        })?;
+        // NULL is an empty field, while an empty string is quoted
+        if let Some(text) = value_text(&value)? {
+            out.push(b'"');
+            for byte in text.bytes() {
+                if byte == b'"' {
+                    out.push(b'"');
+                }
+                out.push(byte);
+            }
+            out.push(b'"');
+        }
+    }
+    out.push(b'\n');
+    Ok(())
+}
+
+/// Splits CSV data received in chunks into records
+#[derive(Debug, Default)]
+struct CsvReader {
+    buf: Vec<u8>,
+    pos: usize,
+}
+
+impl CsvReader {
+    fn extend(&mut self, chunk: &[u8]) {
+        self.buf.drain(..self.pos);
+        self.pos = 0;
+        self.buf.extend_from_slice(chunk);
+    }
+
+    /// The fields of the next complete record, `None` for NULL
+    fn next_record(&mut self) -> Result<Option<Vec<Option<String>>>, DbErr> {
+        let mut fields = Vec::new();
+        let mut field = Vec::new();
+        let mut quoted = false;
+        let mut in_quotes = false;
+        let mut i = self.pos;
+        while i < self.buf.len() {
+            let byte = self.buf[i];
+            if in_quotes {
+                if byte == b'"' {
+                    match self.buf.get(i + 1) {
+                        Some(b'"') => {
+                            field.push(b'"');
+                            i += 1;
+                        }
+                        Some(_) => in_quotes = false,
+                        // an escaped quote may continue in the next chunk
+                        None => return Ok(None),
+                    }
+                } else {
+                    field.push(byte);
+                }
+            } else {
+                match byte {
+                    b'"' => {
+                        in_quotes = true;
+                        quoted = true;
+                    }
+                    b',' => {
+                        fields.push(csv_field(std::mem::take(&mut field), quoted)?);
+                        quoted = false;
+                    }
+                    b'\n' => {
+                        fields.push(csv_field(field, quoted)?);
+                        self.pos = i + 1;
+                        return Ok(Some(fields));
+                    }
+                    b'\r' => {}
+                    _ => field.push(byte),
+                }
+            }
+            i += 1;
+        }
+        Ok(None)
+    }
+
+    fn finish(&self) -> Result<(), DbErr> {
+        if self.pos < self.buf.len() {
+            return Err(DbErr::Type(
+                "Incomplete CSV record at the end of COPY".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn csv_field(field: Vec<u8>, quoted: bool) -> Result<Option<String>, DbErr> {
+    if field.is_empty() && !quoted {
+        return Ok(None);
+    }
+    String::from_utf8(field)
+        .map(Some)
+        .map_err(|err| DbErr::Type(err.to_string()))
+}
+
+/// Turns CSV records into Models, trying the value types a column may be stored as
+struct ModelDecoder<E>
+where
+    E: EntityTrait,
+{
+    columns: Vec<(E::Column, Vec<Value>)>,
+    /// The index of the value type which worked last, by column
+    hints: Vec<usize>,
+}
+
+impl<E> ModelDecoder<E>
+where
+    E: EntityTrait,
+    E::ActiveModel: TryIntoModel<E::Model>,
+{
+    fn new() -> Self {
+        let columns: Vec<_> = E::Column::iter()
+            .map(|col| (col, value_kinds(col.def().get_column_type())))
+            .collect();
+        Self {
+            hints: vec![0; columns.len()],
+            columns,
+        }
+    }
+
+    fn decode(&mut self, record: Vec<Option<String>>) -> Result<E::Model, DbErr> {
+        if record.len() != self.columns.len() {
+            return Err(DbErr::Type(format!(
+                "Expected {} columns in COPY, found {}",
+                self.columns.len(),
+                record.len()
+            )));
+        }
+        let mut model = <E::ActiveModel as ActiveModelTrait>::default();
+        for (((col, kinds), hint), text) in
+            self.columns.iter().zip(self.hints.iter_mut()).zip(record)
+        {
+            let mut decoded = false;
+            for i in std::iter::once(*hint).chain((0..kinds.len()).filter(|i| i != hint)) {
+                let value = match kinds
+                    .get(i)
+                    .and_then(|kind| parse_value(kind, text.as_deref()))
+                {
+                    Some(value) => value,
+                    None => continue,
+                };
+                if model.try_set(*col, value).is_ok() {
+                    *hint = i;
+                    decoded = true;
+                    break;
+                }
+            }
+            if !decoded {
+                return Err(DbErr::Type(format!(
+                    "Failed to decode column `{}` from {text:?}",
+                    col.as_str()
+                )));
+            }
+        }
+        model.try_into_model()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_row, CsvReader, ModelDecoder};
+    use crate::tests_cfg::{cake, cake_model, fruit};
+    use crate::{entity::*, query::*, DbBackend, DbErr, MockDatabase, TransactionTrait};
+    use futures::stream;
+
+    #[test]
+    fn copy_statements() {
+        assert_eq!(
+            cake::Entity::copy_in().statement(&[cake::Column::Id, cake::Column::Name]),
+            r#"COPY "cake" ("id", "name") FROM STDIN (FORMAT csv)"#
+        );
+        assert_eq!(
+            cake::Entity::copy_out()
+                .filter(cake::Column::Name.contains("it's"))
+                .statement(),
+            r#"COPY (SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE E'%it\'s%') TO STDOUT (FORMAT csv)"#
+        );
+    }
+
+    #[smol_potat::test]
+    async fn copy_in_transaction() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+        let txn = db.begin().await?;
+
+        // the mock connection does not support COPY, but a transaction is accepted
+        assert!(matches!(
+            cake::Entity::copy_in()
+                .exec(stream::iter([cake_model(1)]), &txn)
+                .await,
+            Err(DbErr::Custom(_))
+        ));
+        assert!(matches!(
+            cake::Entity::copy_out().stream(&txn).await,
+            Err(DbErr::Custom(_))
+        ));
+
+        txn.rollback().await
+    }
+
+    #[test]
+    fn copy_csv_round_trip() -> Result<(), DbErr> {
+        let cakes = [
+            cake::Model {
+                id: 1,
+                name: "Chocolate \"Forest\", with cherries\nand cream".to_owned(),
+            },
+            cake::Model {
+                id: 2,
+                name: "".to_owned(),
+            },
+        ];
+
+        let mut data = Vec::new();
+        for cake in cakes.iter() {
+            let columns: Vec<_> = cake::Column::iter().collect();
+            encode_row(&cake.clone().into_active_model(), &columns, &mut data)?;
+        }
+        assert_eq!(
+            String::from_utf8_lossy(&data),
+            "\"1\",\"Chocolate \"\"Forest\"\", with cherries\nand cream\"\n\"2\",\"\"\n"
+        );
+
+        // received in chunks split anywhere
+        let mut reader = CsvReader::default();
+        let mut decoder = ModelDecoder::<cake::Entity>::new();
+        let mut decoded = Vec::new();
+        for chunk in data.chunks(7) {
+            reader.extend(chunk);
+            while let Some(record) = reader.next_record()? {
+                decoded.push(decoder.decode(record)?);
+            }
+        }
+        reader.finish()?;
+
+        assert_eq!(decoded, cakes);
+
+        Ok(())
+    }
+
+    #[test]
+    fn copy_csv_null() -> Result<(), DbErr> {
+        let mut reader = CsvReader::default();
+        reader.extend(b"1,\"Apple\",\n2,,1\n");
+
+        let mut decoder = ModelDecoder::<fruit::Entity>::new();
+        let mut decode = |reader: &mut CsvReader| -> Result<fruit::Model, DbErr> {
+            decoder.decode(reader.next_record()?.expect("a complete record"))
+        };
+        assert_eq!(
+            decode(&mut reader)?,
+            fruit::Model {
+                id: 1,
+                name: "Apple".to_owned(),
+                cake_id: None,
+            }
+        );
+        // an unquoted empty field is NULL, but `name` is not nullable
+        assert!(decode(&mut reader).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "postgres-array")]
+    fn copy_csv_array() -> Result<(), DbErr> {
+        use crate::tests_cfg::menu;
+
+        let menu = menu::Model {
+            id: 1,
+            items: vec!["Apple \"Pie\", {warm}".to_owned(), "NULL".to_owned()],
+        };
+
+        let mut data = Vec::new();
+        let columns: Vec<_> = menu::Column::iter().collect();
+        encode_row(&menu.clone().into_active_model(), &columns, &mut data)?;
+
+        let mut reader = CsvReader::default();
+        reader.extend(&data);
+        let record = reader.next_record()?.expect("a complete record");
+        assert_eq!(
+            record[1].as_deref(),
+            Some(r#"{"Apple \"Pie\", {warm}","NULL"}"#)
+        );
+        assert_eq!(ModelDecoder::<menu::Entity>::new().decode(record)?, menu);
+
+        Ok(())
+    }
+}
@@ -4,12 +4,26 @@ use crate::{
     SelectThree, SelectThreeModel, SelectTwo, SelectTwoModel, SelectorTrait,
 };
 use sea_query::{
-    Condition, DynIden, Expr, IntoValueTuple, Order, SeaRc, SelectStatement, SimpleExpr, Value,
-    ValueTuple,
+    Condition, DynIden, Expr, IntoValueTuple, NullOrdering, Order, SeaRc, SelectStatement,
+    SimpleExpr, Value, ValueTuple,
 };
 use std::marker::PhantomData;
 use strum::IntoEnumIterator as Iterable;
 
+#[cfg(feature = "opaque-cursor")]
+use crate::{
+    value::{parse_value, value_kinds, value_text},
+    ActiveModelTrait, ColumnTrait, ModelTrait,
+};
+#[cfg(feature = "opaque-cursor")]
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+#[cfg(feature = "opaque-cursor")]
+use hmac::{Hmac, Mac};
+#[cfg(feature = "opaque-cursor")]
+use sha2::Sha256;
+#[cfg(feature = "opaque-cursor")]
+use std::str::FromStr;
+
 #[cfg(feature = "with-json")]
 use crate::JsonValue;
 
@@ -29,6 +43,9 @@ where
     after: Option<ValueTuple>,
     sort_asc: bool,
     is_result_reversed: bool,
+    column_orders: Vec<CursorOrder>,
+    #[cfg(feature = "opaque-cursor")]
+    signer: Option<Hmac<Sha256>>,
     phantom: PhantomData<S>,
 }
 
@@ -51,6 +68,9 @@ where
             before: None,
             sort_asc: true,
             is_result_reversed: false,
+            column_orders: Vec::new(),
+            #[cfg(feature = "opaque-cursor")]
+            signer: None,
             phantom: PhantomData,
             secondary_order_by: Default::default(),
         }
@@ -76,132 +96,133 @@ where
 
     fn apply_filters(&mut self) -> &mut Self {
         if let Some(values) = self.after.clone() {
-            let condition = self.apply_filter(values, |c, v| {
-                let exp = Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c)));
-                if self.sort_asc {
-                    exp.gt(v)
-                } else {
-                    exp.lt(v)
-                }
-            });
+            let condition = self.apply_filter(values, true);
             self.query.cond_where(condition);
         }
 
         if let Some(values) = self.before.clone() {
-            let condition = self.apply_filter(values, |c, v| {
-                let exp = Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c)));
-                if self.sort_asc {
-                    exp.lt(v)
-                } else {
-                    exp.gt(v)
-                }
-            });
+            let condition = self.apply_filter(values, false);
             self.query.cond_where(condition);
         }
 
         self
     }
 
-    fn apply_filter<F>(&self, values: ValueTuple, f: F) -> Condition
-    where
-        F: Fn(&DynIden, Value) -> SimpleExpr,
-    {
-        match (&self.order_columns, values) {
-            (Identity::Unary(c1), ValueTuple::One(v1)) => Condition::all().add(f(c1, v1)),
-            (Identity::Binary(c1, c2), ValueTuple::Two(v1, v2)) => Condition::any()
-                .add(
-                    Condition::all()
-                        .add(
-                            Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c1))).eq(v1.clone()),
-                        )
-                        .add(f(c2, v2)),
-                )
-                .add(f(c1, v1)),
-            (Identity::Ternary(c1, c2, c3), ValueTuple::Three(v1, v2, v3)) => Condition::any()
-                .add(
-                    Condition::all()
-                        .add(
-                            Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c1))).eq(v1.clone()),
-                        )
-                        .add(
-                            Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c2))).eq(v2.clone()),
-                        )
-                        .add(f(c3, v3)),
-                )
-                .add(
-                    Condition::all()
-                        .add(
-                            Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c1))).eq(v1.clone()),
-                        )
-                        .add(f(c2, v2)),
-                )
-                .add(f(c1, v1)),
-            (Identity::Many(col_vec), ValueTuple::Many(val_vec))
-                if col_vec.len() == val_vec.len() =>
-            {
-                // The length of `col_vec` and `val_vec` should be equal and is denoted by "n".
-                //
-                // The elements of `col_vec` and `val_vec` are denoted by:
-                //   - `col_vec`: "col_1", "col_2", ..., "col_n-1", "col_n"
-                //   - `val_vec`: "val_1", "val_2", ..., "val_n-1", "val_n"
-                //
-                // The general form of the where condition should have "n" number of inner-AND-condition chained by an outer-OR-condition.
-                // The "n"-th inner-AND-condition should have exactly "n" number of column value expressions,
-                // to construct the expression we take the first "n" number of column and value from the respected vector.
-                //   - if it's not the last element, then we construct a "col_1 = val_1" equal expression
-                //   - otherwise, for the last element, we should construct a "col_n > val_n" greater than or "col_n < val_n" less than expression.
-                // i.e.
-                // WHERE
-                //   (col_1 = val_1 AND col_2 = val_2 AND ... AND col_n > val_n)
-                //   OR (col_1 = val_1 AND col_2 = val_2 AND ... AND col_n-1 > val_n-1)
-                //   OR (col_1 = val_1 AND col_2 = val_2 AND ... AND col_n-2 > val_n-2)
-                //   OR ...
-                //   OR (col_1 = val_1 AND col_2 > val_2)
-                //   OR (col_1 > val_1)
-
-                // Counting from 1 to "n" (inclusive) but in reverse, i.e. n, n-1, ..., 2, 1
-                (1..=col_vec.len())
-                    .rev()
-                    .fold(Condition::any(), |cond_any, n| {
-                        // Construct the inner-AND-condition
-                        let inner_cond_all =
-                            // Take the first "n" elements from the column and value vector respectively
-                            col_vec.iter().zip(val_vec.iter()).enumerate().take(n).fold(
-                                Condition::all(),
-                                |inner_cond_all, (i, (col, val))| {
-                                    let val = val.clone();
-                                    // Construct a equal expression,
-                                    // except for the last one being greater than or less than expression
-                                    let expr = if i != (n - 1) {
-                                        Expr::col((SeaRc::clone(&self.table), SeaRc::clone(col)))
-                                            .eq(val)
-                                    } else {
-                                        f(col, val)
-                                    };
-                                    // Chain it with AND operator
-                                    inner_cond_all.add(expr)
-                                },
-                            );
-                        // Chain inner-AND-condition with OR operator
-                        cond_any.add(inner_cond_all)
-                    })
-            }
-            _ => panic!("column arity mismatch"),
+    fn apply_filter(&self, values: ValueTuple, after: bool) -> Condition {
+        let col_vec: Vec<DynIden> = self.order_columns.clone().into_iter().collect();
+        let val_vec: Vec<Value> = values.into_iter().collect();
+        if col_vec.len() != val_vec.len() {
+            panic!("column arity mismatch");
+        }
+
+        // The length of `col_vec` and `val_vec` should be equal and is denoted by "n".
+        //
+        // The elements of `col_vec` and `val_vec` are denoted by:
+        //   - `col_vec`: "col_1", "col_2", ..., "col_n-1", "col_n"
+        //   - `val_vec`: "val_1", "val_2", ..., "val_n-1", "val_n"
+        //
+        // The general form of the where condition should have "n" number of inner-AND-condition chained by an outer-OR-condition.
+        // The "n"-th inner-AND-condition should have exactly "n" number of column value expressions,
+        // to construct the expression we take the first "n" number of column and value from the respected vector.
+        //   - if it's not the last element, then we construct a "col_1 = val_1" equal expression
+        //   - otherwise, for the last element, we should construct a "col_n > val_n" greater than or "col_n < val_n" less than expression,
+        //     depending on the order of the column.
+        // i.e.
+        // WHERE
+        //   (col_1 = val_1 AND col_2 = val_2 AND ... AND col_n > val_n)
+        //   OR (col_1 = val_1 AND col_2 = val_2 AND ... AND col_n-1 > val_n-1)
+        //   OR (col_1 = val_1 AND col_2 = val_2 AND ... AND col_n-2 > val_n-2)
+        //   OR ...
+        //   OR (col_1 = val_1 AND col_2 > val_2)
+        //   OR (col_1 > val_1)
+        //
+        // For the columns with an explicit NULLS FIRST / LAST, the equal expression becomes "col_i IS NULL"
+        // when "val_i" is NULL, and the greater than expression takes the NULLs into account,
+        // e.g. "col_n > val_n OR col_n IS NULL" for NULLS LAST. An inner-AND-condition which can never hold is left out.
+
+        // Counting from 1 to "n" (inclusive) but in reverse, i.e. n, n-1, ..., 2, 1
+        let condition = (1..=col_vec.len())
+            .rev()
+            .fold(Condition::any(), |cond_any, n| {
+                // Construct the inner-AND-condition
+                let mut inner_cond_all = Condition::all();
+                // Take the first "n" elements from the column and value vector respectively
+                for (i, (col, val)) in col_vec.iter().zip(val_vec.iter()).enumerate().take(n) {
+                    let expr = Expr::col((SeaRc::clone(&self.table), SeaRc::clone(col)));
+                    let order = self.column_order(i);
+                    let val = val.clone();
+                    // Construct a equal expression,
+                    // except for the last one being greater than or less than expression
+                    let expr = if i != (n - 1) {
+                        if order.nulls.is_some() && val == val.as_null() {
+                            expr.is_null()
+                        } else {
+                            expr.eq(val)
+                        }
+                    } else {
+                        let order = if after { order } else { order.reverse() };
+                        match order.beyond(expr, val) {
+                            Some(expr) => expr,
+                            None => return cond_any,
+                        }
+                    };
+                    // Chain it with AND operator
+                    inner_cond_all = inner_cond_all.add(expr);
+                }
+                // Chain inner-AND-condition with OR operator
+                cond_any.add(inner_cond_all)
+            });
+
+        if condition.is_empty() {
+            // no row comes after (or before) the values
+            Condition::all().add(Expr::value(false))
+        } else {
+            condition
+        }
+    }
+
+    /// The order of the `i`-th `cursor_by` column, taking [Cursor::desc] into account
+    fn column_order(&self, i: usize) -> CursorOrder {
+        let order = self.column_orders.get(i).copied().unwrap_or_default();
+        if self.sort_asc {
+            order
+        } else {
+            order.reverse()
         }
     }
 
     /// Use ascending sort order
+    ///
+    /// With [Cursor::column_orders], the columns are sorted in the given orders.
     pub fn asc(&mut self) -> &mut Self {
         self.sort_asc = true;
         self
     }
 
     /// Use descending sort order
+    ///
+    /// With [Cursor::column_orders], the columns are sorted in the reverse of the given orders,
+    /// including where the NULLs go.
     pub fn desc(&mut self) -> &mut Self {
         self.sort_asc = false;
         self
     }
 
+    /// Sort each of the `cursor_by` columns in its own order, instead of all of them in ascending order.
+    /// The columns without an order given are sorted in ascending order.
+    ///
+    /// A NULL in a nullable column cannot be compared with `>` or `<`,
+    /// so the position of the NULLs should be given with [CursorOrder::nulls_first] or
+    /// [CursorOrder::nulls_last] for such a column, and the cursor conditions then take them into account.
+    /// MySQL does not support `NULLS FIRST` / `LAST`, which is emulated by sorting by `IS NULL` first.
+    pub fn column_orders<I>(&mut self, orders: I) -> &mut Self
+    where
+        I: IntoIterator<Item = CursorOrder>,
+    {
+        self.column_orders = orders.into_iter().collect();
+        self
+    }
+
     /// Limit result set to only first N rows in ascending order of the order by column
     pub fn first(&mut self, num_rows: u64) -> &mut Self {
         self.last = None;
@@ -241,30 +262,20 @@ where
         self.query.clear_order_by();
         let ord = self.resolve_sort_order();
 
-        let query = &mut self.query;
-        let order = |query: &mut SelectStatement, col| {
-            query.order_by((SeaRc::clone(&self.table), SeaRc::clone(col)), ord.clone());
-        };
-        match &self.order_columns {
-            Identity::Unary(c1) => {
-                order(query, c1);
-            }
-            Identity::Binary(c1, c2) => {
-                order(query, c1);
-                order(query, c2);
-            }
-            Identity::Ternary(c1, c2, c3) => {
-                order(query, c1);
-                order(query, c2);
-                order(query, c3);
-            }
-            Identity::Many(vec) => {
-                for col in vec.iter() {
-                    order(query, col);
-                }
+        for (i, col) in self.order_columns.clone().into_iter().enumerate() {
+            let mut order = self.column_order(i);
+            if self.is_result_reversed {
+                order = order.reverse();
             }
+            let col = (SeaRc::clone(&self.table), col);
+            let direction = if order.desc { Order::Desc } else { Order::Asc };
+            match order.nulls {
+                Some(nulls) => self.query.order_by_with_nulls(col, direction, nulls),
+                None => self.query.order_by(col, direction),
+            };
         }
 
+        let query = &mut self.query;
         for (tbl, col) in self.secondary_order_by.iter().cloned() {
             if let Identity::Unary(c1) = col {
                 query.order_by((tbl, c1), ord.clone());
@@ -310,6 +321,9 @@ where
             before: self.before,
             sort_asc: self.sort_asc,
             is_result_reversed: self.is_result_reversed,
+            column_orders: self.column_orders,
+            #[cfg(feature = "opaque-cursor")]
+            signer: self.signer,
             phantom: PhantomData,
             secondary_order_by: self.secondary_order_by,
         }
@@ -336,6 +350,9 @@ where
             before: self.before,
             sort_asc: self.sort_asc,
             is_result_reversed: self.is_result_reversed,
+            column_orders: self.column_orders,
+            #[cfg(feature = "opaque-cursor")]
+            signer: self.signer,
             phantom: PhantomData,
             secondary_order_by: self.secondary_order_by,
         }
@@ -346,6 +363,312 @@ where
         self.secondary_order_by = tbl_col;
         self
     }
+
+    /// Sign the opaque cursors with HMAC-SHA256 under this key,
+    /// and reject any cursor which is not signed with it
+    #[cfg(feature = "opaque-cursor")]
+    pub fn sign_cursors<K>(&mut self, key: K) -> &mut Self
+    where
+        K: AsRef<[u8]>,
+    {
+        // HMAC accepts keys of any length, so this never fails
+        self.signer = Hmac::new_from_slice(key.as_ref()).ok();
+        self
+    }
+}
+
+/// The sort order of a `cursor_by` column, see [Cursor::column_orders]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CursorOrder {
+    desc: bool,
+    nulls: Option<NullOrdering>,
+}
+
+impl CursorOrder {
+    /// Ascending order, with the NULLs wherever the database puts them
+    pub fn asc() -> Self {
+        Self::default()
+    }
+
+    /// Descending order, with the NULLs wherever the database puts them
+    pub fn desc() -> Self {
+        Self {
+            desc: true,
+            nulls: None,
+        }
+    }
+
+    /// Put the NULLs before the other values
+    pub fn nulls_first(mut self) -> Self {
+        self.nulls = Some(NullOrdering::First);
+        self
+    }
+
+    /// Put the NULLs after the other values
+    pub fn nulls_last(mut self) -> Self {
+        self.nulls = Some(NullOrdering::Last);
+        self
+    }
+
+    fn reverse(self) -> Self {
+        Self {
+            desc: !self.desc,
+            nulls: self.nulls.map(|nulls| match nulls {
+                NullOrdering::First => NullOrdering::Last,
+                NullOrdering::Last => NullOrdering::First,
+            }),
+        }
+    }
+
+    /// The rows which come after the value in this order, `None` if there cannot be any
+    fn beyond(self, expr: Expr, value: Value) -> Option<SimpleExpr> {
+        let is_null = value == value.as_null();
+        let compare = |expr: Expr, value| {
+            if self.desc {
+                expr.lt(value)
+            } else {
+                expr.gt(value)
+            }
+        };
+        match self.nulls {
+            None => Some(compare(expr, value)),
+            Some(NullOrdering::First) if is_null => Some(expr.is_not_null()),
+            Some(NullOrdering::First) => Some(compare(expr, value)),
+            Some(NullOrdering::Last) if is_null => None,
+            Some(NullOrdering::Last) => Some(compare(expr.clone(), value).or(expr.is_null())),
+        }
+    }
+}
+
+/// One page of a [Cursor] along with the opaque cursors of its rows, like a Relay connection
+#[cfg(feature = "opaque-cursor")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorPage<T> {
+    /// The rows of the page, in order
+    pub edges: Vec<CursorEdge<T>>,
+    /// The cursor of the first row
+    pub start_cursor: Option<String>,
+    /// The cursor of the last row
+    pub end_cursor: Option<String>,
+    /// Whether there are more rows after this page
+    pub has_next_page: bool,
+    /// Whether there are more rows before this page
+    pub has_previous_page: bool,
+}
+
+/// A row of a [CursorPage]
+#[cfg(feature = "opaque-cursor")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorEdge<T> {
+    /// The opaque cursor pointing at this row
+    pub cursor: String,
+    /// The row
+    pub node: T,
+}
+
+#[cfg(feature = "opaque-cursor")]
+impl<T> CursorPage<T> {
+    /// The rows of the page, without their cursors
+    pub fn nodes(self) -> Vec<T> {
+        self.edges.into_iter().map(|edge| edge.node).collect()
+    }
+}
+
+#[cfg(feature = "opaque-cursor")]
+impl<M> Cursor<SelectModel<M>>
+where
+    M: FromQueryResult + ModelTrait,
+{
+    /// Filter paginated result with rows after the one the opaque cursor points at
+    ///
+    /// The cursor must have been made by a [Cursor] ordered by the same columns,
+    /// and signed with the same key if [Cursor::sign_cursors] is used.
+    pub fn after_cursor(&mut self, cursor: &str) -> Result<&mut Self, DbErr> {
+        self.after = Some(self.decode_cursor(cursor)?);
+        Ok(self)
+    }
+
+    /// Filter paginated result with rows before the one the opaque cursor points at
+    ///
+    /// See [Cursor::after_cursor].
+    pub fn before_cursor(&mut self, cursor: &str) -> Result<&mut Self, DbErr> {
+        self.before = Some(self.decode_cursor(cursor)?);
+        Ok(self)
+    }
+
+    /// The opaque cursor pointing at a row, which encodes the values of the `cursor_by` columns
+    pub fn cursor_of(&self, model: &M) -> Result<String, DbErr> {
+        let mut payload = Vec::new();
+        for col in self.order_columns.clone() {
+            // each value is either `~` for NULL or its text prefixed by its length, e.g. `5:Apple`
+            match value_text(&model.get(cursor_column::<M>(&col)?))? {
+                Some(text) => {
+                    payload.extend_from_slice(format!("{}:", text.len()).as_bytes());
+                    payload.extend_from_slice(text.as_bytes());
+                }
+                None => payload.push(b'~'),
+            }
+        }
+        if let Some(signer) = &self.signer {
+            let mut mac = signer.clone();
+            mac.update(&payload);
+            payload.extend_from_slice(&mac.finalize().into_bytes());
+        }
+        Ok(URL_SAFE_NO_PAD.encode(payload))
+    }
+
+    /// Fetch the paginated result along with the cursors of the rows.
+    ///
+    /// One more row than asked for is fetched to tell whether there is another page,
+    /// in the direction of [Cursor::first] or [Cursor::last], so no extra query is made.
+    /// In the opposite direction, there is taken to be another page if the result is
+    /// filtered by [Cursor::after_cursor] (for `first`) or [Cursor::before_cursor] (for `last`).
+    ///
+    /// ```
+    /// # #[cfg(feature = "mock")]
+    /// # async fn function() -> Result<(), sea_orm::DbErr> {
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend, MockDatabase};
+    ///
+    /// let cake = |id: i32| cake::Model {
+    ///     id,
+    ///     name: format!("Cake {id}"),
+    /// };
+    /// let db = MockDatabase::new(DbBackend::Postgres)
+    ///     .append_query_results([[cake(1), cake(2), cake(3)]])
+    ///     .append_query_results([[cake(3)]])
+    ///     .into_connection();
+    ///
+    /// let mut cursor = cake::Entity::find().cursor_by(cake::Column::Id);
+    /// cursor.sign_cursors(b"secret").first(2);
+    ///
+    /// let page = cursor.clone().connection(&db).await?;
+    /// assert!(page.has_next_page && !page.has_previous_page);
+    /// let end_cursor = page.end_cursor.clone().unwrap();
+    /// assert_eq!(page.nodes(), [cake(1), cake(2)]);
+    ///
+    /// let page = cursor.after_cursor(&end_cursor)?.connection(&db).await?;
+    /// assert!(!page.has_next_page && page.has_previous_page);
+    /// assert_eq!(page.nodes(), [cake(3)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn connection<C>(&mut self, db: &C) -> Result<CursorPage<M>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let (first, last) = (self.first, self.last);
+        self.first = first.map(|n| n.saturating_add(1));
+        self.last = last.map(|n| n.saturating_add(1));
+        let result = self.all(db).await;
+        self.first = first;
+        self.last = last;
+
+        let mut models = result?;
+        let has_more = first
+            .or(last)
+            .is_some_and(|num_rows| models.len() as u64 > num_rows);
+        if has_more {
+            // the extra row is the furthest one, which comes first when paginating backwards
+            if last.is_some() {
+                models.remove(0);
+            } else {
+                models.pop();
+            }
+        }
+        let (has_next_page, has_previous_page) = if last.is_some() {
+            (self.before.is_some(), has_more)
+        } else {
+            (has_more, self.after.is_some())
+        };
+
+        let edges = models
+            .into_iter()
+            .map(|node| {
+                Ok(CursorEdge {
+                    cursor: self.cursor_of(&node)?,
+                    node,
+                })
+            })
+            .collect::<Result<Vec<_>, DbErr>>()?;
+        Ok(CursorPage {
+            start_cursor: edges.first().map(|edge| edge.cursor.clone()),
+            end_cursor: edges.last().map(|edge| edge.cursor.clone()),
+            edges,
+            has_next_page,
+            has_previous_page,
+        })
+    }
+
+    fn decode_cursor(&self, cursor: &str) -> Result<ValueTuple, DbErr> {
+        let invalid = || DbErr::Custom(format!("Invalid cursor `{cursor}`"));
+        let mut payload = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+        if let Some(signer) = &self.signer {
+            let tag_len = <Sha256 as sha2::digest::OutputSizeUser>::output_size();
+            let tag = payload.split_off(payload.len().checked_sub(tag_len).ok_or_else(invalid)?);
+            let mut mac = signer.clone();
+            mac.update(&payload);
+            mac.verify_slice(&tag).map_err(|_| invalid())?;
+        }
+
+        let mut rest = payload.as_slice();
+        let mut values = Vec::new();
+        for col in self.order_columns.clone() {
+            let text = match rest.strip_prefix(b"~") {
+                Some(after) => {
+                    rest = after;
+                    None
+                }
+                None => {
+                    let colon = rest.iter().position(|b| *b == b':').ok_or_else(invalid)?;
+                    let len: usize = std::str::from_utf8(&rest[..colon])
+                        .ok()
+                        .and_then(|len| len.parse().ok())
+                        .ok_or_else(invalid)?;
+                    let field = rest
+                        .get(colon + 1..)
+                        .and_then(|field| field.get(..len))
+                        .ok_or_else(invalid)?;
+                    rest = &rest[colon + 1 + len..];
+                    Some(std::str::from_utf8(field).map_err(|_| invalid())?)
+                }
+            };
+            // the first of the types the column may be stored as which its Rust type accepts
+            let column = cursor_column::<M>(&col)?;
+            let mut model =
+                <<M::Entity as EntityTrait>::ActiveModel as ActiveModelTrait>::default();
+            let value = value_kinds(column.def().get_column_type())
+                .iter()
+                .filter_map(|kind| parse_value(kind, text))
+                .find(|value| model.try_set(column, value.clone()).is_ok())
+                .ok_or_else(invalid)?;
+            values.push(value);
+        }
+        if !rest.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(match (&self.order_columns, values.as_slice()) {
+            (Identity::Unary(_), [v1]) => ValueTuple::One(v1.clone()),
+            (Identity::Binary(_, _), [v1, v2]) => ValueTuple::Two(v1.clone(), v2.clone()),
+            (Identity::Ternary(_, _, _), [v1, v2, v3]) => {
+                ValueTuple::Three(v1.clone(), v2.clone(), v3.clone())
+            }
+            _ => ValueTuple::Many(values),
+        })
+    }
+}
+
+#[cfg(feature = "opaque-cursor")]
+fn cursor_column<M>(col: &DynIden) -> Result<<M::Entity as EntityTrait>::Column, DbErr>
+where
+    M: ModelTrait,
+{
+    let name = col.to_string();
+    <M::Entity as EntityTrait>::Column::from_str(&name).map_err(|_| {
+        DbErr::Custom(format!(
+            "Cursor column `{name}` is not a column of the Entity"
+        ))
+    })
 }
 
 impl<S> QuerySelect for Cursor<S>
@@ -522,8 +845,9 @@ where
 mod tests {
     use crate::entity::prelude::*;
     use crate::tests_cfg::*;
-    use crate::{DbBackend, MockDatabase, Statement, Transaction};
+    use crate::{CursorOrder, DbBackend, MockDatabase, Statement, Transaction};
     use pretty_assertions::assert_eq;
+    use sea_query::ValueTuple;
 
     #[smol_potat::test]
     async fn first_2_before_10() -> Result<(), DbErr> {
@@ -2438,4 +2762,315 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn first_2_after_10_with_cte() -> Result<(), DbErr> {
+        use crate::{QueryFilter, QuerySelect};
+        use fruit::*;
+        use sea_query::{CommonTableExpression, Query};
+
+        let models = [Model {
+            id: 11,
+            name: "Blueberry".into(),
+            cake_id: Some(1),
+        }];
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([models.clone()])
+            .into_connection();
+
+        let cheese_cakes = CommonTableExpression::new()
+            .query(
+                Query::select()
+                    .column(cake::Column::Id)
+                    .from(cake::Entity)
+                    .and_where(cake::Column::Name.contains("Cheese"))
+                    .to_owned(),
+            )
+            .table_name("cheese_cakes")
+            .to_owned();
+
+        assert_eq!(
+            Entity::find()
+                .with_cte(cheese_cakes)
+                .filter(
+                    Column::CakeId
+                        .in_subquery(Query::select().column("id").from("cheese_cakes").to_owned())
+                )
+                .cursor_by(Column::Id)
+                .after(10)
+                .first(2)
+                .all(&db)
+                .await?,
+            models
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"WITH "cheese_cakes" AS (SELECT "id" FROM "cake" WHERE "cake"."name" LIKE $1)"#,
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id""#,
+                    r#"FROM "fruit""#,
+                    r#"WHERE "fruit"."cake_id" IN (SELECT "id" FROM "cheese_cakes")"#,
+                    r#"AND "fruit"."id" > $2"#,
+                    r#"ORDER BY "fruit"."id" ASC"#,
+                    r#"LIMIT $3"#,
+                ]
+                .join(" ")
+                .as_str(),
+                ["%Cheese%".into(), 10_i32.into(), 2_u64.into()]
+            ),])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn connection_last_2_before_cursor() -> Result<(), DbErr> {
+        use fruit::*;
+
+        let fruit = |id: i32, name: &str| Model {
+            id,
+            name: name.to_owned(),
+            cake_id: None,
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                fruit(3, "Cherry"),
+                fruit(2, "Banana: ~ripe"),
+                fruit(1, "Apple"),
+            ]])
+            .into_connection();
+
+        let mut cursor = Entity::find().cursor_by((Column::Name, Column::Id));
+        let before = cursor.cursor_of(&fruit(4, "Durian"))?;
+        let page = cursor
+            .before_cursor(&before)?
+            .last(2)
+            .connection(&db)
+            .await?;
+
+        assert!(page.has_next_page && page.has_previous_page);
+        assert_eq!(
+            page.start_cursor.as_deref(),
+            Some(cursor.cursor_of(&fruit(2, "Banana: ~ripe"))?.as_str())
+        );
+        assert_eq!(
+            cursor.decode_cursor(&page.end_cursor.clone().unwrap_or_default())?,
+            ValueTuple::Two("Cherry".into(), 3.into())
+        );
+        assert_eq!(
+            page.nodes(),
+            [fruit(2, "Banana: ~ripe"), fruit(3, "Cherry")]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                    r#"WHERE ("fruit"."name" = $1 AND "fruit"."id" < $2) OR "fruit"."name" < $3"#,
+                    r#"ORDER BY "fruit"."name" DESC, "fruit"."id" DESC LIMIT $4"#,
+                ]
+                .join(" ")
+                .as_str(),
+                ["Durian".into(), 4_i32.into(), "Durian".into(), 3_u64.into()]
+            ),])]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn cursor_tokens() -> Result<(), DbErr> {
+        use fruit::*;
+
+        let model = Model {
+            id: 5,
+            name: "Kiwi".to_owned(),
+            cake_id: None,
+        };
+
+        let cursor = Entity::find().cursor_by((Column::CakeId, Column::Id));
+        let token = cursor.cursor_of(&model)?;
+        assert_eq!(
+            cursor.decode_cursor(&token)?,
+            ValueTuple::Two(Value::Int(None), 5.into())
+        );
+        assert!(cursor.decode_cursor("not a cursor").is_err());
+
+        let mut signed = cursor.clone();
+        signed.sign_cursors("secret");
+        let signed_token = signed.cursor_of(&model)?;
+        assert_eq!(
+            signed.decode_cursor(&signed_token)?,
+            ValueTuple::Two(Value::Int(None), 5.into())
+        );
+        // unsigned, or signed with another key
+        assert!(signed.decode_cursor(&token).is_err());
+        assert!(cursor
+            .clone()
+            .sign_cursors("another secret")
+            .decode_cursor(&signed_token)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn cursor_tokens_rust_type() -> Result<(), DbErr> {
+        mod counter {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "counter")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                #[sea_orm(column_type = "Integer")]
+                pub hits: i64,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+        use counter::*;
+
+        // an `INTEGER` column read into an `i64` is decoded as such
+        let cursor = Entity::find().cursor_by(Column::Hits);
+        let token = cursor.cursor_of(&Model { id: 1, hits: 42 })?;
+        assert_eq!(
+            cursor.decode_cursor(&token)?,
+            ValueTuple::One(Value::BigInt(Some(42)))
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn mixed_orders_nulls_last() -> Result<(), DbErr> {
+        use fruit::*;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([Vec::<Model>::new()])
+            .append_query_results([Vec::<Model>::new()])
+            .append_query_results([Vec::<Model>::new()])
+            .into_connection();
+
+        let mut cursor = Entity::find().cursor_by((Column::CakeId, Column::Id));
+        cursor.column_orders([CursorOrder::desc().nulls_last(), CursorOrder::asc()]);
+
+        cursor.clone().after((3, 5)).first(2).all(&db).await?;
+        cursor
+            .clone()
+            .after((Option::<i32>::None, 5))
+            .first(2)
+            .all(&db)
+            .await?;
+        Entity::find()
+            .cursor_by(Column::CakeId)
+            .column_orders([CursorOrder::asc().nulls_last()])
+            .after(Option::<i32>::None)
+            .first(2)
+            .all(&db)
+            .await?;
+
+        let select = r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#;
+        let order_by = r#"ORDER BY "fruit"."cake_id" DESC NULLS LAST, "fruit"."id" ASC LIMIT"#;
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::many([Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        select,
+                        r#"WHERE ("fruit"."cake_id" = $1 AND "fruit"."id" > $2)"#,
+                        r#"OR ("fruit"."cake_id" < $3 OR "fruit"."cake_id" IS NULL)"#,
+                        order_by,
+                        "$4",
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [3_i32.into(), 5_i32.into(), 3_i32.into(), 2_u64.into()]
+                )]),
+                Transaction::many([Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        select,
+                        r#"WHERE "fruit"."cake_id" IS NULL AND "fruit"."id" > $1"#,
+                        order_by,
+                        "$2",
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [5_i32.into(), 2_u64.into()]
+                )]),
+                Transaction::many([Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        select,
+                        r#"WHERE $1 ORDER BY "fruit"."cake_id" ASC NULLS LAST LIMIT $2"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [false.into(), 2_u64.into()]
+                )]),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn mixed_orders_last_2_before_mysql() -> Result<(), DbErr> {
+        use fruit::*;
+
+        let models = [
+            Model {
+                id: 6,
+                name: "Lemon".into(),
+                cake_id: Some(3),
+            },
+            Model {
+                id: 1,
+                name: "Apple".into(),
+                cake_id: Some(4),
+            },
+        ];
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([models.clone()])
+            .into_connection();
+
+        let page = Entity::find()
+            .cursor_by((Column::CakeId, Column::Id))
+            .column_orders([CursorOrder::desc().nulls_last(), CursorOrder::asc()])
+            .before((3, 7))
+            .last(2)
+            .all(&db)
+            .await?;
+        assert_eq!(page, [models[1].clone(), models[0].clone()]);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([Statement::from_sql_and_values(
+                DbBackend::MySql,
+                [
+                    "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`",
+                    "WHERE (`fruit`.`cake_id` = ? AND `fruit`.`id` < ?) OR `fruit`.`cake_id` > ?",
+                    "ORDER BY `fruit`.`cake_id` IS NULL DESC, `fruit`.`cake_id` ASC, `fruit`.`id` DESC LIMIT ?",
+                ]
+                .join(" ")
+                .as_str(),
+                [3_i32.into(), 7_i32.into(), 3_i32.into(), 2_u64.into()]
+            ),])]
+        );
+
+        Ok(())
+    }
 }
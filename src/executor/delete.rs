@@ -1,6 +1,6 @@
 use crate::{
     error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, DeleteMany, DeleteOne, EntityTrait,
-    Iterable,
+    Iterable, JoinedDeleteMany,
 };
 use sea_query::{DeleteStatement, Query};
 use std::future::Future;
@@ -79,6 +79,35 @@ where
     }
 }
 
+impl<'a, E> JoinedDeleteMany<E>
+where
+    E: EntityTrait,
+{
+    /// Execute a DELETE operation on many ActiveModels, joined to other tables
+    pub fn exec<C>(self, db: &'a C) -> impl Future<Output = Result<DeleteResult, DbErr>> + 'a
+    where
+        C: ConnectionTrait,
+    {
+        // so that self is dropped before entering await
+        exec_delete_only(self.into_query(), db)
+    }
+
+    /// Execute an delete operation and return the deleted model
+    ///
+    /// # Panics
+    ///
+    /// Panics if the database backend does not support `DELETE RETURNING`
+    pub fn exec_with_returning<C>(
+        self,
+        db: &C,
+    ) -> impl Future<Output = Result<Vec<E::Model>, DbErr>> + '_
+    where
+        C: ConnectionTrait,
+    {
+        exec_delete_with_returning_many::<E, _>(self.into_query(), db)
+    }
+}
+
 impl Deleter {
     /// Instantiate a new [Deleter] by passing it a [DeleteStatement]
     pub fn new(query: DeleteStatement) -> Self {
@@ -0,0 +1,557 @@
+use crate::{
+    error::*, ConnectionTrait, DbBackend, DeleteMany, EntityTrait, JoinedDeleteMany,
+    JoinedUpdateMany, QueryResult, QueryTrait, Select, SelectTwo, SelectTwoMany, Statement,
+    UpdateMany,
+};
+use std::collections::BTreeMap;
+
+/// Defines a type to run `EXPLAIN` on a [Statement] and parse the query plan
+#[derive(Clone, Debug)]
+pub struct Explainer {
+    stmt: Statement,
+    analyze: bool,
+}
+
+/// A backend neutral query plan, as returned by [Explainer::exec]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExplainPlan {
+    /// The database backend which produced this plan
+    pub db_backend: DbBackend,
+    /// The top level nodes of the plan tree
+    pub nodes: Vec<ExplainNode>,
+    /// The plan as printed by the database backend
+    pub raw: String,
+}
+
+/// A node in the query plan tree
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExplainNode {
+    /// The operation performed, e.g. `Seq Scan`, `Index lookup` or `SEARCH`
+    pub node_type: String,
+    /// The table being accessed, if any
+    pub relation: Option<String>,
+    /// The index being used, if any
+    pub index: Option<String>,
+    /// The number of rows estimated by the planner
+    pub estimated_rows: Option<f64>,
+    /// The total cost estimated by the planner
+    pub estimated_cost: Option<f64>,
+    /// The number of rows actually produced, only available with `EXPLAIN ANALYZE`
+    pub actual_rows: Option<f64>,
+    /// The time in milliseconds actually spent, only available with `EXPLAIN ANALYZE`
+    pub actual_time: Option<f64>,
+    /// The line describing this node in the raw output
+    pub label: String,
+    /// Additional lines attached to this node, e.g. `Filter: (id = 1)`
+    pub details: Vec<String>,
+    /// The child nodes
+    pub children: Vec<ExplainNode>,
+}
+
+impl Explainer {
+    /// Instantiate a new [Explainer] from a [Statement]
+    pub fn new(stmt: Statement) -> Self {
+        Self {
+            stmt,
+            analyze: false,
+        }
+    }
+
+    /// Execute the statement and report the actual rows and timing in the plan.
+    ///
+    /// Note that the statement is really executed, so an `UPDATE` or `DELETE`
+    /// will modify the database. Run it inside a transaction and roll back if this is not desired.
+    pub fn analyze(mut self) -> Self {
+        self.analyze = true;
+        self
+    }
+
+    /// The `EXPLAIN` statement that will be sent to the database
+    pub fn statement(&self) -> Result<Statement, DbErr> {
+        let prefix = match (self.stmt.db_backend, self.analyze) {
+            (DbBackend::Postgres, false) => "EXPLAIN",
+            (DbBackend::Postgres, true) => "EXPLAIN ANALYZE",
+            (DbBackend::MySql, false) => "EXPLAIN FORMAT=TREE",
+            (DbBackend::MySql, true) => "EXPLAIN ANALYZE",
+            (DbBackend::Sqlite, false) => "EXPLAIN QUERY PLAN",
+            (DbBackend::Sqlite, true) => {
+                return Err(backend_not_supported("SQLite", "EXPLAIN ANALYZE"))
+            }
+        };
+        Ok(Statement {
+            sql: format!("{prefix} {}", self.stmt.sql),
+            values: self.stmt.values.clone(),
+            db_backend: self.stmt.db_backend,
+        })
+    }
+
+    /// Run `EXPLAIN` and parse the output into an [ExplainPlan]
+    pub async fn exec<C>(self, db: &C) -> Result<ExplainPlan, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let db_backend = self.stmt.db_backend;
+        let rows = db.query_all(self.statement()?).await?;
+        match db_backend {
+            DbBackend::Postgres => parse_text_plan(db_backend, &rows, "QUERY PLAN"),
+            DbBackend::MySql => parse_text_plan(db_backend, &rows, "EXPLAIN"),
+            DbBackend::Sqlite => parse_sqlite_plan(&rows),
+        }
+    }
+}
+
+macro_rules! impl_explain {
+    ( $ty: ty, [ $( $generic: ident ),+ ] ) => {
+        impl<$( $generic ),+> $ty
+        where
+            $( $generic: EntityTrait ),+
+        {
+            /// Run `EXPLAIN` on this query and return the parsed plan
+            pub async fn explain<C>(&self, db: &C) -> Result<ExplainPlan, DbErr>
+            where
+                C: ConnectionTrait,
+            {
+                Explainer::new(self.build(db.get_database_backend()))
+                    .exec(db)
+                    .await
+            }
+
+            /// Run `EXPLAIN ANALYZE` on this query and return the parsed plan.
+            /// The query is really executed, see [Explainer::analyze].
+            pub async fn explain_analyze<C>(&self, db: &C) -> Result<ExplainPlan, DbErr>
+            where
+                C: ConnectionTrait,
+            {
+                Explainer::new(self.build(db.get_database_backend()))
+                    .analyze()
+                    .exec(db)
+                    .await
+            }
+        }
+    };
+}
+
+impl_explain!(Select<E>, [E]);
+impl_explain!(SelectTwo<E, F>, [E, F]);
+impl_explain!(SelectTwoMany<E, F>, [E, F]);
+impl_explain!(UpdateMany<E>, [E]);
+impl_explain!(JoinedUpdateMany<E>, [E]);
+impl_explain!(DeleteMany<E>, [E]);
+impl_explain!(JoinedDeleteMany<E>, [E]);
+
+impl ExplainPlan {
+    /// Iterate over every node of the plan tree, depth first
+    pub fn iter(&self) -> impl Iterator<Item = &ExplainNode> {
+        let mut stack: Vec<&ExplainNode> = self.nodes.iter().rev().collect();
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    /// Names of all indexes used by the plan
+    pub fn indexes(&self) -> Vec<&str> {
+        self.iter()
+            .filter_map(|node| node.index.as_deref())
+            .collect()
+    }
+}
+
+/// Parse the indented text output of Postgres `EXPLAIN` and MySQL `EXPLAIN FORMAT=TREE`
+fn parse_text_plan(
+    db_backend: DbBackend,
+    rows: &[QueryResult],
+    col: &str,
+) -> Result<ExplainPlan, DbErr> {
+    let mut lines = Vec::new();
+    for row in rows {
+        let text: String = row.try_get("", col)?;
+        lines.extend(text.lines().map(ToOwned::to_owned));
+    }
+
+    let mut nodes: Vec<ExplainNode> = Vec::new();
+    // the nodes which are not yet closed, paired with their indentation
+    let mut stack: Vec<(usize, ExplainNode)> = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        let content = line.trim_start();
+        if content.is_empty() {
+            continue;
+        }
+        let indent = line.len() - content.len();
+        // Postgres prints the root node without an arrow
+        let is_node = content.starts_with("->") || (i == 0 && db_backend == DbBackend::Postgres);
+        if !is_node {
+            // attach to the innermost node which is less indented than this line,
+            // lines without indentation belong to the root
+            let owner = stack
+                .iter()
+                .rposition(|(node_indent, _)| *node_indent < indent)
+                .unwrap_or(0);
+            if let Some((_, node)) = stack.get_mut(owner) {
+                node.details.push(content.to_owned());
+            }
+            continue;
+        }
+        close_nodes(&mut stack, &mut nodes, indent);
+        let label = content.trim_start_matches("->").trim();
+        stack.push((indent, parse_text_node(label)));
+    }
+    close_nodes(&mut stack, &mut nodes, 0);
+
+    Ok(ExplainPlan {
+        db_backend,
+        nodes,
+        raw: lines.join("\n"),
+    })
+}
+
+/// Pop every node at or deeper than `indent` and attach it to its parent
+fn close_nodes(stack: &mut Vec<(usize, ExplainNode)>, nodes: &mut Vec<ExplainNode>, indent: usize) {
+    while stack.last().is_some_and(|(top, _)| *top >= indent) {
+        let Some((_, node)) = stack.pop() else {
+            break;
+        };
+        match stack.last_mut() {
+            Some((_, parent)) => parent.children.push(node),
+            None => nodes.push(node),
+        }
+    }
+}
+
+fn parse_text_node(line: &str) -> ExplainNode {
+    let cost_at = line.find("(cost=");
+    let actual_at = line.find("(actual ");
+    let label_end = match (cost_at, actual_at) {
+        (Some(a), Some(b)) => a.min(b),
+        (Some(a), None) | (None, Some(a)) => a,
+        (None, None) => line.len(),
+    };
+    let label = line[..label_end].trim();
+    let estimate = cost_at.map(|at| metric_group(&line[at..]));
+    let actual = actual_at.map(|at| metric_group(&line[at..]));
+
+    // `Index Scan using cake_pkey on cake`, `Index lookup on fruit using idx (cake_id=1)`
+    // or `Filter: (fruit.cake_id = 1)`
+    let head = label.split(": ").next().unwrap_or(label);
+    let node_type = [" using ", " on "]
+        .iter()
+        .filter_map(|sep| head.find(sep))
+        .min()
+        .map_or(head, |at| &head[..at]);
+
+    ExplainNode {
+        node_type: node_type.trim().to_owned(),
+        relation: word_after(head, " on "),
+        index: word_after(head, " using "),
+        estimated_rows: estimate.as_ref().and_then(|m| m.get("rows").copied()),
+        estimated_cost: estimate.as_ref().and_then(|m| m.get("cost").copied()),
+        actual_rows: actual.as_ref().and_then(|m| m.get("rows").copied()),
+        actual_time: actual.as_ref().and_then(|m| m.get("time").copied()),
+        label: line.to_owned(),
+        ..Default::default()
+    }
+}
+
+/// Parse `(cost=0.00..22.70 rows=1270 width=36)` into key-value pairs.
+/// For ranges like `startup..total`, the total is taken.
+fn metric_group(s: &str) -> BTreeMap<&str, f64> {
+    let end = s.find(')').unwrap_or(s.len());
+    s[1..end]
+        .split_whitespace()
+        .filter_map(|pair| {
+            let (key, value) = pair.split_once('=')?;
+            let value = value.rsplit("..").next()?;
+            Some((key, value.parse().ok()?))
+        })
+        .collect()
+}
+
+fn word_after(s: &str, sep: &str) -> Option<String> {
+    let at = s.find(sep)?;
+    s[at + sep.len()..]
+        .split_whitespace()
+        .next()
+        .map(|word| word.trim_matches(|c| c == '"' || c == '`').to_owned())
+}
+
+/// Parse the rows of SQLite `EXPLAIN QUERY PLAN`, which are linked by `id` and `parent`
+fn parse_sqlite_plan(rows: &[QueryResult]) -> Result<ExplainPlan, DbErr> {
+    let mut entries = Vec::with_capacity(rows.len());
+    for row in rows {
+        let id: i64 = row.try_get("", "id")?;
+        let parent: i64 = row.try_get("", "parent")?;
+        let detail: String = row.try_get("", "detail")?;
+        entries.push((id, parent, detail));
+    }
+
+    fn build(
+        entries: &[(i64, i64, String)],
+        parent: i64,
+        depth: usize,
+        raw: &mut Vec<String>,
+    ) -> Vec<ExplainNode> {
+        entries
+            .iter()
+            .filter(|(_, p, _)| *p == parent)
+            .map(|(id, _, detail)| {
+                raw.push(format!("{}{detail}", "  ".repeat(depth)));
+                let mut node = parse_sqlite_node(detail);
+                node.children = build(entries, *id, depth + 1, raw);
+                node
+            })
+            .collect()
+    }
+
+    let mut raw = Vec::new();
+    let nodes = build(&entries, 0, 0, &mut raw);
+
+    Ok(ExplainPlan {
+        db_backend: DbBackend::Sqlite,
+        nodes,
+        raw: raw.join("\n"),
+    })
+}
+
+fn parse_sqlite_node(detail: &str) -> ExplainNode {
+    let mut words = detail.split_whitespace();
+    let node_type = words.next().unwrap_or_default();
+    let (node_type, relation) = match node_type {
+        "SCAN" | "SEARCH" => {
+            // SQLite before 3.36 prints `SCAN TABLE cake`
+            let relation = match words.next() {
+                Some("TABLE") => words.next(),
+                relation => relation,
+            };
+            (node_type, relation.map(ToOwned::to_owned))
+        }
+        _ => (detail, None),
+    };
+    let index = if detail.contains(" USING INTEGER PRIMARY KEY") {
+        Some("INTEGER PRIMARY KEY".to_owned())
+    } else {
+        word_after(detail, " USING COVERING INDEX ").or_else(|| word_after(detail, " USING INDEX "))
+    };
+
+    ExplainNode {
+        node_type: node_type.to_owned(),
+        relation,
+        index,
+        label: detail.to_owned(),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, *};
+    use pretty_assertions::assert_eq;
+
+    fn plan_rows<const N: usize>(col: &str, lines: [&str; N]) -> Vec<MockRow> {
+        lines
+            .into_iter()
+            .map(|line| {
+                std::collections::BTreeMap::from([(col.to_owned(), Value::from(line))])
+                    .into_mock_row()
+            })
+            .collect()
+    }
+
+    #[smol_potat::test]
+    async fn explain_postgres() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([plan_rows(
+                "QUERY PLAN",
+                [
+                    "Hash Join  (cost=29.58..67.34 rows=13 width=72)",
+                    "  Hash Cond: (fruit.cake_id = cake.id)",
+                    "  ->  Seq Scan on fruit  (cost=0.00..22.70 rows=1270 width=40)",
+                    "  ->  Hash  (cost=8.17..8.17 rows=1 width=36)",
+                    "        ->  Index Scan using cake_pkey on cake  (cost=0.15..8.17 rows=1 width=36)",
+                    "              Index Cond: (id = 1)",
+                ],
+            )])
+            .into_connection();
+
+        let plan = cake::Entity::find()
+            .find_also_related(fruit::Entity)
+            .filter(cake::Column::Id.eq(1))
+            .explain(&db)
+            .await?;
+
+        assert_eq!(plan.nodes.len(), 1);
+        let root = &plan.nodes[0];
+        assert_eq!(root.node_type, "Hash Join");
+        assert_eq!(root.estimated_rows, Some(13.0));
+        assert_eq!(root.estimated_cost, Some(67.34));
+        assert_eq!(root.details, ["Hash Cond: (fruit.cake_id = cake.id)"]);
+        assert_eq!(root.children.len(), 2);
+
+        let seq_scan = &root.children[0];
+        assert_eq!(seq_scan.node_type, "Seq Scan");
+        assert_eq!(seq_scan.relation.as_deref(), Some("fruit"));
+        assert_eq!(seq_scan.index, None);
+
+        let index_scan = &root.children[1].children[0];
+        assert_eq!(index_scan.node_type, "Index Scan");
+        assert_eq!(index_scan.relation.as_deref(), Some("cake"));
+        assert_eq!(index_scan.index.as_deref(), Some("cake_pkey"));
+        assert_eq!(index_scan.details, ["Index Cond: (id = 1)"]);
+        assert_eq!(plan.indexes(), ["cake_pkey"]);
+        assert_eq!(plan.iter().count(), 4);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"EXPLAIN SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+                    r#""fruit"."id" AS "B_id", "fruit"."name" AS "B_name", "fruit"."cake_id" AS "B_cake_id""#,
+                    r#"FROM "cake" LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+                    r#"WHERE "cake"."id" = $1"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn explain_analyze_postgres() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([plan_rows(
+                "QUERY PLAN",
+                [
+                    "Update on fruit  (cost=0.00..25.88 rows=6 width=46) (actual time=0.032..0.033 rows=0 loops=1)",
+                    "  ->  Seq Scan on fruit  (cost=0.00..25.88 rows=6 width=46) (actual time=0.010..0.011 rows=2 loops=1)",
+                    "        Filter: (name ~~ '%Apple%'::text)",
+                    "Planning Time: 0.070 ms",
+                    "Execution Time: 0.061 ms",
+                ],
+            )])
+            .into_connection();
+
+        let plan = fruit::Entity::update_many()
+            .col_expr(fruit::Column::CakeId, Expr::value(Value::Int(None)))
+            .filter(fruit::Column::Name.contains("Apple"))
+            .explain_analyze(&db)
+            .await?;
+
+        let root = &plan.nodes[0];
+        assert_eq!(root.node_type, "Update");
+        assert_eq!(root.relation.as_deref(), Some("fruit"));
+        assert_eq!(root.actual_rows, Some(0.0));
+        assert_eq!(root.actual_time, Some(0.033));
+        assert_eq!(
+            root.details,
+            ["Planning Time: 0.070 ms", "Execution Time: 0.061 ms"]
+        );
+        assert_eq!(root.children[0].actual_rows, Some(2.0));
+        assert_eq!(
+            root.children[0].details,
+            ["Filter: (name ~~ '%Apple%'::text)"]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"EXPLAIN ANALYZE UPDATE "fruit" SET "cake_id" = $1 WHERE "fruit"."name" LIKE $2"#,
+                [Value::Int(None), "%Apple%".into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn explain_mysql() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([plan_rows(
+                "EXPLAIN",
+                [[
+                    "-> Nested loop left join  (cost=0.70 rows=1)",
+                    "    -> Table scan on cake  (cost=0.35 rows=1)",
+                    "    -> Index lookup on fruit using fk-fruit-cake (cake_id=cake.id)  (cost=0.35 rows=1)",
+                ]
+                .join("\n")
+                .as_str()],
+            )])
+            .into_connection();
+
+        let plan = cake::Entity::find()
+            .find_with_related(fruit::Entity)
+            .explain(&db)
+            .await?;
+
+        assert_eq!(plan.nodes.len(), 1);
+        let root = &plan.nodes[0];
+        assert_eq!(root.node_type, "Nested loop left join");
+        assert_eq!(root.estimated_cost, Some(0.70));
+        assert_eq!(root.children[0].node_type, "Table scan");
+        assert_eq!(root.children[0].relation.as_deref(), Some("cake"));
+        assert_eq!(root.children[1].node_type, "Index lookup");
+        assert_eq!(root.children[1].relation.as_deref(), Some("fruit"));
+        assert_eq!(root.children[1].index.as_deref(), Some("fk-fruit-cake"));
+
+        assert!(db.into_transaction_log()[0].statements()[0]
+            .sql
+            .starts_with("EXPLAIN FORMAT=TREE SELECT"));
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn explain_sqlite() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([[
+                maplit::btreemap! {
+                    "id" => Into::<Value>::into(2i64),
+                    "parent" => Into::<Value>::into(0i64),
+                    "notused" => Into::<Value>::into(0i64),
+                    "detail" => Into::<Value>::into("SEARCH fruit USING INDEX idx-fruit-cake (cake_id=?)"),
+                },
+                maplit::btreemap! {
+                    "id" => Into::<Value>::into(5i64),
+                    "parent" => Into::<Value>::into(0i64),
+                    "notused" => Into::<Value>::into(0i64),
+                    "detail" => Into::<Value>::into("USE TEMP B-TREE FOR ORDER BY"),
+                },
+            ]])
+            .into_connection();
+
+        let plan = fruit::Entity::delete_many()
+            .filter(fruit::Column::CakeId.eq(1))
+            .explain(&db)
+            .await?;
+
+        assert_eq!(plan.nodes.len(), 2);
+        assert_eq!(plan.nodes[0].node_type, "SEARCH");
+        assert_eq!(plan.nodes[0].relation.as_deref(), Some("fruit"));
+        assert_eq!(plan.nodes[0].index.as_deref(), Some("idx-fruit-cake"));
+        assert_eq!(plan.nodes[1].node_type, "USE TEMP B-TREE FOR ORDER BY");
+        assert_eq!(plan.nodes[1].relation, None);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Sqlite,
+                r#"EXPLAIN QUERY PLAN DELETE FROM "fruit" WHERE "fruit"."cake_id" = ?"#,
+                [1i32.into()]
+            )]
+        );
+
+        let db = MockDatabase::new(DbBackend::Sqlite).into_connection();
+        assert_eq!(
+            cake::Entity::find().explain_analyze(&db).await,
+            Err(backend_not_supported("SQLite", "EXPLAIN ANALYZE"))
+        );
+
+        Ok(())
+    }
+}
@@ -1,9 +1,9 @@
 use crate::{
     error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, Insert,
     IntoActiveModel, Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, SelectModel, SelectorRaw,
-    TryFromU64, TryInsert,
+    TransactionTrait, TryFromU64, TryInsert,
 };
-use sea_query::{FromValueTuple, Iden, InsertStatement, Query, ValueTuple};
+use sea_query::{FromValueTuple, Iden, InsertStatement, Query, SimpleExpr, ValueTuple};
 use std::{future::Future, marker::PhantomData};
 
 type PrimaryKey<A> = <<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey;
@@ -16,6 +16,7 @@ where
 {
     primary_key: Option<ValueTuple>,
     query: InsertStatement,
+    rows: Vec<Vec<SimpleExpr>>,
     model: PhantomData<A>,
 }
 
@@ -156,6 +157,53 @@ where
             Err(err) => Err(err),
         }
     }
+
+    /// Execute an insert operation inside a transaction, see [`Insert::exec_in_transaction`]
+    pub async fn exec_in_transaction<C>(
+        self,
+        db: &C,
+    ) -> Result<TryInsertResult<InsertResult<A>>, DbErr>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        if self.insert_struct.columns.is_empty() {
+            return Ok(TryInsertResult::Empty);
+        }
+        let res = self.insert_struct.exec_in_transaction(db).await;
+        match res {
+            Ok(res) => Ok(TryInsertResult::Inserted(res)),
+            Err(DbErr::RecordNotInserted) => Ok(TryInsertResult::Conflicted),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Execute an insert operation inside a transaction and return all inserted models,
+    /// see [`Insert::exec_in_transaction`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the database backend does not support `INSERT RETURNING`.
+    pub async fn exec_with_returning_many_in_transaction<C>(
+        self,
+        db: &C,
+    ) -> Result<TryInsertResult<Vec<<A::Entity as EntityTrait>::Model>>, DbErr>
+    where
+        <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+        C: ConnectionTrait + TransactionTrait,
+    {
+        if self.insert_struct.columns.is_empty() {
+            return Ok(TryInsertResult::Empty);
+        }
+        let res = self
+            .insert_struct
+            .exec_with_returning_many_in_transaction(db)
+            .await;
+        match res {
+            Ok(res) => Ok(TryInsertResult::Inserted(res)),
+            Err(DbErr::RecordNotInserted) => Ok(TryInsertResult::Conflicted),
+            Err(err) => Err(err),
+        }
+    }
 }
 
 impl<A> Insert<A>
@@ -170,7 +218,8 @@ where
         A: 'a,
     {
         // so that self is dropped before entering await
-        let mut query = self.query;
+        let primary_key = self.primary_key.clone();
+        let (mut query, rows) = self.into_statement_and_rows();
         if db.support_returning() {
             let db_backend = db.get_database_backend();
             let returning =
@@ -180,7 +229,9 @@ where
                 }));
             query.returning(returning);
         }
-        Inserter::<A>::new(self.primary_key, query).exec(db)
+        Inserter::<A>::new(primary_key, query)
+            .with_rows(rows)
+            .exec(db)
     }
 
     /// Execute an insert operation without returning (don't use `RETURNING` syntax)
@@ -194,7 +245,11 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        Inserter::<A>::new(self.primary_key, self.query).exec_without_returning(db)
+        let primary_key = self.primary_key.clone();
+        let (query, rows) = self.into_statement_and_rows();
+        Inserter::<A>::new(primary_key, query)
+            .with_rows(rows)
+            .exec_without_returning(db)
     }
 
     /// Execute an insert operation and return the inserted model (use `RETURNING` syntax if supported)
@@ -210,7 +265,11 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        Inserter::<A>::new(self.primary_key, self.query).exec_with_returning(db)
+        let primary_key = self.primary_key.clone();
+        let (query, rows) = self.into_statement_and_rows();
+        Inserter::<A>::new(primary_key, query)
+            .with_rows(rows)
+            .exec_with_returning(db)
     }
 
     /// Execute an insert operation and return primary keys of inserted models
@@ -227,7 +286,11 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        Inserter::<A>::new(self.primary_key, self.query).exec_with_returning_keys(db)
+        let primary_key = self.primary_key.clone();
+        let (query, rows) = self.into_statement_and_rows();
+        Inserter::<A>::new(primary_key, query)
+            .with_rows(rows)
+            .exec_with_returning_keys(db)
     }
 
     /// Execute an insert operation and return all inserted models
@@ -244,7 +307,43 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        Inserter::<A>::new(self.primary_key, self.query).exec_with_returning_many(db)
+        let primary_key = self.primary_key.clone();
+        let (query, rows) = self.into_statement_and_rows();
+        Inserter::<A>::new(primary_key, query)
+            .with_rows(rows)
+            .exec_with_returning_many(db)
+    }
+
+    /// Execute an insert operation inside a transaction, so that either all or none of the rows
+    /// are inserted when they are split up into several statements by the bind parameter limit
+    pub async fn exec_in_transaction<C>(self, db: &C) -> Result<InsertResult<A>, DbErr>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let txn = db.begin().await?;
+        let res = self.exec(&txn).await?;
+        txn.commit().await?;
+        Ok(res)
+    }
+
+    /// Execute an insert operation inside a transaction and return all inserted models,
+    /// see [`Insert::exec_in_transaction`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the database backend does not support `INSERT RETURNING`.
+    pub async fn exec_with_returning_many_in_transaction<C>(
+        self,
+        db: &C,
+    ) -> Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>
+    where
+        <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let txn = db.begin().await?;
+        let res = self.exec_with_returning_many(&txn).await?;
+        txn.commit().await?;
+        Ok(res)
     }
 }
 
@@ -257,42 +356,72 @@ where
         Self {
             primary_key,
             query,
+            rows: Vec::new(),
             model: PhantomData,
         }
     }
 
+    /// The rows of values to add to the query, split up by the bind parameter limit
+    /// of the backend into as many statements as needed.
+    pub(crate) fn with_rows(mut self, rows: Vec<Vec<SimpleExpr>>) -> Self {
+        self.rows = rows;
+        self
+    }
+
     /// Execute an insert operation, returning the last inserted id
-    pub fn exec<'a, C>(self, db: &'a C) -> impl Future<Output = Result<InsertResult<A>, DbErr>> + 'a
+    pub async fn exec<'a, C>(self, db: &'a C) -> Result<InsertResult<A>, DbErr>
     where
         C: ConnectionTrait,
         A: 'a,
     {
-        exec_insert(self.primary_key, self.query, db)
+        let (primary_key, statements) = self.into_statements(db.get_database_backend());
+        let mut res = Err(DbErr::RecordNotInserted);
+        for statement in statements {
+            // fails only if none of the statements inserted anything
+            match exec_insert(primary_key.clone(), statement, db).await {
+                Ok(inserted) => res = Ok(inserted),
+                Err(DbErr::RecordNotInserted) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        res
     }
 
     /// Execute an insert operation
-    pub fn exec_without_returning<'a, C>(
-        self,
-        db: &'a C,
-    ) -> impl Future<Output = Result<u64, DbErr>> + 'a
+    pub async fn exec_without_returning<'a, C>(self, db: &'a C) -> Result<u64, DbErr>
     where
         C: ConnectionTrait,
         A: 'a,
     {
-        exec_insert_without_returning(self.query, db)
+        let (_, statements) = self.into_statements(db.get_database_backend());
+        let mut rows_affected = 0;
+        for statement in statements {
+            rows_affected += exec_insert_without_returning(statement, db).await?;
+        }
+        Ok(rows_affected)
     }
 
     /// Execute an insert operation and return the inserted model (use `RETURNING` syntax if supported)
-    pub fn exec_with_returning<'a, C>(
+    pub async fn exec_with_returning<'a, C>(
         self,
         db: &'a C,
-    ) -> impl Future<Output = Result<<A::Entity as EntityTrait>::Model, DbErr>> + 'a
+    ) -> Result<<A::Entity as EntityTrait>::Model, DbErr>
     where
         <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
         C: ConnectionTrait,
         A: 'a,
     {
-        exec_insert_with_returning::<A, _>(self.primary_key, self.query, db)
+        let (primary_key, statements) = self.into_statements(db.get_database_backend());
+        let mut res = Err(DbErr::RecordNotInserted);
+        for statement in statements {
+            // the model of the first statement which inserted anything
+            match exec_insert_with_returning::<A, _>(primary_key.clone(), statement, db).await {
+                Ok(model) if res.is_err() => res = Ok(model),
+                Ok(_) | Err(DbErr::RecordNotInserted) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        res
     }
 
     /// Execute an insert operation and return primary keys of inserted models
@@ -300,16 +429,21 @@ where
     /// # Panics
     ///
     /// Panics if the database backend does not support `INSERT RETURNING`.
-    pub fn exec_with_returning_keys<'a, C>(
+    pub async fn exec_with_returning_keys<'a, C>(
         self,
         db: &'a C,
-    ) -> impl Future<Output = Result<Vec<<PrimaryKey<A> as PrimaryKeyTrait>::ValueType>, DbErr>> + 'a
+    ) -> Result<Vec<<PrimaryKey<A> as PrimaryKeyTrait>::ValueType>, DbErr>
     where
         <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
         C: ConnectionTrait,
         A: 'a,
     {
-        exec_insert_with_returning_keys::<A, _>(self.query, db)
+        let (_, statements) = self.into_statements(db.get_database_backend());
+        let mut keys = Vec::new();
+        for statement in statements {
+            keys.extend(exec_insert_with_returning_keys::<A, _>(statement, db).await?);
+        }
+        Ok(keys)
     }
 
     /// Execute an insert operation and return all inserted models
@@ -317,16 +451,66 @@ where
     /// # Panics
     ///
     /// Panics if the database backend does not support `INSERT RETURNING`.
-    pub fn exec_with_returning_many<'a, C>(
+    pub async fn exec_with_returning_many<'a, C>(
         self,
         db: &'a C,
-    ) -> impl Future<Output = Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>> + 'a
+    ) -> Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>
     where
         <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
         C: ConnectionTrait,
         A: 'a,
     {
-        exec_insert_with_returning_many::<A, _>(self.query, db)
+        let (_, statements) = self.into_statements(db.get_database_backend());
+        let mut models = Vec::new();
+        for statement in statements {
+            models.extend(exec_insert_with_returning_many::<A, _>(statement, db).await?);
+        }
+        Ok(models)
+    }
+
+    /// Split the rows up into statements of as many rows as the bind parameter limit allows
+    pub(crate) fn into_statements(
+        self,
+        db_backend: DbBackend,
+    ) -> (Option<ValueTuple>, Vec<InsertStatement>) {
+        let mut query = self.query;
+        let Some(first_row) = self.rows.first() else {
+            return (self.primary_key, vec![query]);
+        };
+        let bind_params = |query: &InsertStatement| {
+            db_backend
+                .build(query)
+                .values
+                .map(|values| values.0.len())
+                .unwrap_or_default()
+        };
+
+        // the values of ON CONFLICT and RETURNING are repeated in every statement,
+        // and a row may bind more or fewer values than it has expressions
+        let reserved = bind_params(&query);
+        let params_per_row = bind_params(query.clone().values_panic(first_row.clone()))
+            .saturating_sub(reserved)
+            .max(1);
+        let max_bind_params = db_backend.max_bind_params();
+        if reserved + self.rows.len() * params_per_row <= max_bind_params {
+            query.values_from_panic(self.rows);
+            return (self.primary_key, vec![query]);
+        }
+
+        let rows_per_chunk = (max_bind_params.saturating_sub(reserved) / params_per_row).max(1);
+
+        let mut rows = self.rows.into_iter().peekable();
+        let mut statements = Vec::new();
+        while rows.peek().is_some() {
+            statements.push(
+                query
+                    .clone()
+                    .values_from_panic(rows.by_ref().take(rows_per_chunk))
+                    .to_owned(),
+            );
+        }
+
+        (self.primary_key, statements)
     }
 }
 
@@ -1,17 +1,27 @@
+mod aggregate;
+mod copy;
 mod cursor;
 mod delete;
 mod execute;
+mod explain;
 mod insert;
 mod paginator;
 mod query;
 mod select;
+mod tree;
 mod update;
+mod upsert;
 
+pub(crate) use copy::backend_name;
+pub use copy::*;
 pub use cursor::*;
 pub use delete::*;
 pub use execute::*;
+pub use explain::*;
 pub use insert::*;
 pub use paginator::*;
 pub use query::*;
 pub use select::*;
+pub use tree::*;
 pub use update::*;
+pub use upsert::*;
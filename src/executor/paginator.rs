@@ -1,10 +1,11 @@
 use crate::{
-    error::*, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, Select, SelectModel,
-    SelectThree, SelectThreeModel, SelectTwo, SelectTwoModel, Selector, SelectorRaw, SelectorTrait,
+    error::*, ConnectionTrait, DbBackend, EntityTrait, Explainer, FromQueryResult, QueryResult,
+    Select, SelectModel, SelectThree, SelectThreeModel, SelectTwo, SelectTwoModel, Selector,
+    SelectorRaw, SelectorTrait, Statement,
 };
 use async_stream::stream;
 use futures_util::Stream;
-use sea_query::{Expr, SelectStatement};
+use sea_query::{Alias, Expr, SelectStatement};
 use std::{marker::PhantomData, pin::Pin};
 
 /// Pin a Model so that stream operations can be performed on the model
@@ -21,9 +22,44 @@ where
     pub(crate) page: u64,
     pub(crate) page_size: u64,
     pub(crate) db: &'db C,
+    pub(crate) count_mode: CountMode,
+    pub(crate) raw: bool,
     pub(crate) selector: PhantomData<S>,
 }
 
+/// How a [Paginator] counts the items, see [Paginator::count_mode]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum CountMode {
+    /// `COUNT(*)` over the query, which is exact but may be slow on large tables
+    #[default]
+    Exact,
+    /// The number of rows estimated by the query planner with `EXPLAIN`, on Postgres and MySQL.
+    /// The filters and joins of the query are taken into account, but the estimate may be far off.
+    Explain,
+    /// The number of rows of a table according to the statistics of the database,
+    /// i.e. `pg_class.reltuples` on Postgres and `information_schema.TABLES.TABLE_ROWS` on MySQL.
+    /// The filters of the query are ignored, so this only makes sense for a whole table.
+    TableStats {
+        /// The schema of the table, the current one if `None`
+        schema: Option<String>,
+        /// The name of the table
+        table: String,
+    },
+}
+
+impl CountMode {
+    /// Count the rows of the table of an Entity by its statistics, see [CountMode::TableStats]
+    pub fn table_stats<E>(entity: E) -> Self
+    where
+        E: EntityTrait,
+    {
+        Self::TableStats {
+            schema: entity.schema_name().map(ToOwned::to_owned),
+            table: entity.table_name().to_owned(),
+        }
+    }
+}
+
 /// Define a structure containing the numbers of items and pages of a Paginator
 #[derive(Clone, Debug)]
 pub struct ItemsAndPagesNumber {
@@ -64,31 +100,170 @@ where
         self.fetch_page(self.page).await
     }
 
-    /// Get the total number of items
+    /// Fetch a specific page along with the exact total number of items in a single query,
+    /// by selecting `COUNT(*) OVER()` alongside the rows. Page index starts from zero.
+    ///
+    /// Only a page past the end, which has no row to carry the total, takes a second query with [Paginator::num_items].
+    /// This is not supported for raw SQL nor for a query with `DISTINCT`, as the window is counted before the rows are deduplicated.
+    pub async fn fetch_page_and_count(&self, page: u64) -> Result<(Vec<S::Item>, u64), DbErr> {
+        if self.raw {
+            return Err(DbErr::Custom(
+                "Paginator: fetch_page_and_count is not supported for raw SQL".to_owned(),
+            ));
+        }
+        let query = self
+            .query
+            .clone()
+            .expr_as(Expr::cust("COUNT(*) OVER()"), Alias::new("num_items"))
+            .limit(self.page_size)
+            .offset(self.page_size * page)
+            .to_owned();
+        let builder = self.db.get_database_backend();
+        let rows = self.db.query_all(builder.build(&query)).await?;
+        let num_items = match rows.first() {
+            Some(row) => num_items_of(builder, row)?,
+            None if page == 0 => 0,
+            None => self.num_items().await?,
+        };
+        let mut buffer = Vec::with_capacity(rows.len());
+        for row in rows.into_iter() {
+            buffer.push(S::from_raw_query_result(row)?);
+        }
+        Ok((buffer, num_items))
+    }
+
+    /// Count the items with this mode instead of an exact `COUNT(*)`, in [Paginator::num_items],
+    /// [Paginator::num_pages] and [Paginator::num_items_and_pages].
+    ///
+    /// The count is exact when the database has no estimate, e.g. on SQLite, or for a table never analyzed on Postgres.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let owned_db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[maplit::btreemap! {
+    /// #         "num_items" => Into::<Value>::into(1_000_000_i64),
+    /// #     }]])
+    /// #     .into_connection();
+    /// # let db = &owned_db;
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, CountMode};
+    ///
+    /// let num_pages = cake::Entity::find()
+    ///     .paginate(db, 50)
+    ///     .count_mode(CountMode::table_stats(cake::Entity))
+    ///     .num_pages()
+    ///     .await?;
+    /// assert_eq!(num_pages, 20_000);
+    ///
+    /// assert_eq!(
+    ///     owned_db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"SELECT reltuples::bigint AS num_items FROM pg_class WHERE oid = to_regclass($1)"#,
+    ///         [r#""cake""#.into()]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn count_mode(mut self, count_mode: CountMode) -> Self {
+        self.count_mode = count_mode;
+        self
+    }
+
+    /// Get the total number of items, counted as set by [Paginator::count_mode]
     pub async fn num_items(&self) -> Result<u64, DbErr> {
+        let builder = self.db.get_database_backend();
+        let estimate = match (&self.count_mode, builder) {
+            (CountMode::Exact, _) | (_, DbBackend::Sqlite) => None,
+            (CountMode::Explain, _) => self.explain_estimate().await?,
+            (CountMode::TableStats { schema, table }, _) => {
+                self.table_stats(schema.as_deref(), table).await?
+            }
+        };
+        match estimate {
+            Some(num_items) => Ok(num_items),
+            None => self.count_exact().await,
+        }
+    }
+
+    async fn count_exact(&self) -> Result<u64, DbErr> {
         let builder = self.db.get_database_backend();
         let stmt = SelectStatement::new()
             .expr(Expr::cust("COUNT(*) AS num_items"))
-            .from_subquery(
-                self.query
-                    .clone()
-                    .reset_limit()
-                    .reset_offset()
-                    .clear_order_by()
-                    .to_owned(),
-                "sub_query",
-            )
+            .from_subquery(self.unordered_query(), "sub_query")
             .to_owned();
         let stmt = builder.build(&stmt);
         let result = match self.db.query_one(stmt).await? {
             Some(res) => res,
             None => return Ok(0),
         };
-        let num_items = match builder {
-            DbBackend::Postgres => result.try_get::<i64>("", "num_items")? as u64,
-            _ => result.try_get::<i32>("", "num_items")? as u64,
+        num_items_of(builder, &result)
+    }
+
+    async fn explain_estimate(&self) -> Result<Option<u64>, DbErr> {
+        let builder = self.db.get_database_backend();
+        let plan = Explainer::new(builder.build(&self.unordered_query()))
+            .exec(self.db)
+            .await?;
+        Ok(plan
+            .nodes
+            .first()
+            .and_then(|node| node.estimated_rows)
+            .map(|rows| rows.round() as u64))
+    }
+
+    async fn table_stats(&self, schema: Option<&str>, table: &str) -> Result<Option<u64>, DbErr> {
+        let builder = self.db.get_database_backend();
+        let stmt = match builder {
+            DbBackend::Postgres => {
+                let quote = |name: &str| format!("\"{}\"", name.replace('"', "\"\""));
+                let name = match schema {
+                    Some(schema) => format!("{}.{}", quote(schema), quote(table)),
+                    None => quote(table),
+                };
+                Statement::from_sql_and_values(
+                    builder,
+                    "SELECT reltuples::bigint AS num_items FROM pg_class WHERE oid = to_regclass($1)",
+                    [name.into()],
+                )
+            }
+            DbBackend::MySql => Statement::from_sql_and_values(
+                builder,
+                [
+                    "SELECT TABLE_ROWS AS num_items FROM information_schema.TABLES",
+                    "WHERE TABLE_SCHEMA = COALESCE(?, DATABASE()) AND TABLE_NAME = ?",
+                ]
+                .join(" "),
+                [schema.map(ToOwned::to_owned).into(), table.into()],
+            ),
+            DbBackend::Sqlite => return Ok(None),
         };
-        Ok(num_items)
+        let result = match self.db.query_one(stmt).await? {
+            Some(res) => res,
+            None => return Ok(None),
+        };
+        Ok(match builder {
+            // -1 if the table has never been analyzed
+            DbBackend::Postgres => u64::try_from(result.try_get::<i64>("", "num_items")?).ok(),
+            _ => result.try_get::<Option<u64>>("", "num_items")?,
+        })
+    }
+
+    /// The query without the limit, offset and order by
+    fn unordered_query(&self) -> SelectStatement {
+        self.query
+            .clone()
+            .reset_limit()
+            .reset_offset()
+            .clear_order_by()
+            .to_owned()
     }
 
     /// Get the total number of pages
@@ -206,6 +381,14 @@ where
     }
 }
 
+fn num_items_of(builder: DbBackend, result: &QueryResult) -> Result<u64, DbErr> {
+    let num_items = match builder {
+        DbBackend::Postgres => result.try_get::<i64>("", "num_items")? as u64,
+        _ => result.try_get::<i32>("", "num_items")? as u64,
+    };
+    Ok(num_items)
+}
+
 #[async_trait::async_trait]
 /// A Trait for any type that can paginate results
 pub trait PaginatorTrait<'db, C>
@@ -241,6 +424,8 @@ where
             page: 0,
             page_size,
             db,
+            count_mode: CountMode::Exact,
+            raw: false,
             selector: PhantomData,
         }
     }
@@ -254,7 +439,12 @@ where
     type Selector = S;
     fn paginate(self, db: &'db C, page_size: u64) -> Paginator<'db, C, S> {
         assert!(page_size != 0, "page_size should not be zero");
-        let sql = self.stmt.sql.trim()[6..].trim();
+        let sql = self.stmt.sql.trim();
+        let sql = match sql.get(..6) {
+            Some(select) if select.eq_ignore_ascii_case("SELECT") => sql[6..].trim().to_owned(),
+            // e.g. a statement starting with a `WITH` clause
+            _ => format!("* FROM ({sql}) AS raw_query"),
+        };
         let mut query = SelectStatement::new();
         query.expr(if let Some(values) = self.stmt.values {
             Expr::cust_with_values(sql, values.0)
@@ -267,6 +457,8 @@ where
             page: 0,
             page_size,
             db,
+            count_mode: CountMode::Exact,
+            raw: true,
             selector: PhantomData,
         }
     }
@@ -322,7 +514,7 @@ where
 mod tests {
     use super::*;
     use crate::entity::prelude::*;
-    use crate::{tests_cfg::*, ConnectionTrait, Statement};
+    use crate::{tests_cfg::*, ConnectionTrait, QueryOrder, Statement};
     use crate::{DatabaseConnection, DbBackend, MockDatabase, Transaction};
     use futures_util::TryStreamExt;
     use pretty_assertions::assert_eq;
@@ -789,6 +981,223 @@ mod tests {
         Ok(())
     }
 
+    #[smol_potat::test]
+    async fn num_pages_with_cte() -> Result<(), DbErr> {
+        use crate::{QueryFilter, QuerySelect};
+        use sea_query::{CommonTableExpression, Query};
+
+        let (db, num_items) = setup_num_items();
+
+        let apple_cakes = CommonTableExpression::new()
+            .query(
+                Query::select()
+                    .column(cake::Column::Id)
+                    .from(cake::Entity)
+                    .and_where(cake::Column::Name.contains("Apple"))
+                    .to_owned(),
+            )
+            .table_name("apple_cakes")
+            .to_owned();
+
+        let paginator = fruit::Entity::find()
+            .with_cte(apple_cakes)
+            .filter(
+                fruit::Column::CakeId
+                    .in_subquery(Query::select().column("id").from("apple_cakes").to_owned()),
+            )
+            .paginate(&db, 2);
+
+        assert_eq!(paginator.num_items().await?, num_items as u64);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT COUNT(*) AS num_items FROM"#,
+                    r#"(WITH "apple_cakes" AS (SELECT "id" FROM "cake" WHERE "cake"."name" LIKE $1)"#,
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                    r#"WHERE "fruit"."cake_id" IN (SELECT "id" FROM "apple_cakes")) AS "sub_query""#,
+                ]
+                .join(" ")
+                .as_str(),
+                ["%Apple%".into()]
+            )]
+        );
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn fetch_page_raw_with_cte() -> Result<(), DbErr> {
+        use sea_query::{CommonTableExpression, Query};
+
+        let (db, pages) = setup();
+
+        let apple_cakes = CommonTableExpression::new()
+            .query(
+                Query::select()
+                    .column(cake::Column::Id)
+                    .from(cake::Entity)
+                    .and_where(cake::Column::Name.contains("Apple"))
+                    .to_owned(),
+            )
+            .table_name("apple_cakes")
+            .to_owned();
+
+        let paginator = fruit::Entity::find()
+            .from_raw_sql(RAW_STMT.clone())
+            .with_cte(apple_cakes)
+            .paginate(&db, 2);
+
+        assert_eq!(paginator.fetch_page(0).await?, pages[0].clone());
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT * FROM (WITH "apple_cakes" AS (SELECT "id" FROM "cake" WHERE "cake"."name" LIKE $1)"#,
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit") AS raw_query"#,
+                    r#"LIMIT $2 OFFSET $3"#,
+                ]
+                .join(" ")
+                .as_str(),
+                ["%Apple%".into(), 2u64.into(), 0u64.into()]
+            )]
+        );
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn num_items_estimated() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[maplit::btreemap! {
+                "QUERY PLAN" => Into::<Value>::into("Seq Scan on fruit  (cost=0.00..22.70 rows=1270 width=40)"),
+            }]])
+            .into_connection();
+
+        let num_items = fruit::Entity::find()
+            .filter(fruit::Column::CakeId.is_not_null())
+            .order_by_asc(fruit::Column::Id)
+            .paginate(&db, 2)
+            .count_mode(CountMode::Explain)
+            .num_items()
+            .await?;
+        assert_eq!(num_items, 1270);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"EXPLAIN SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id""#,
+                    r#"FROM "fruit" WHERE "fruit"."cake_id" IS NOT NULL"#,
+                ]
+                .join(" ")
+                .as_str(),
+                []
+            )]
+        );
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn num_items_table_stats_fallback() -> Result<(), DbErr> {
+        // no statistics, e.g. for a view
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([[maplit::btreemap! {
+                "num_items" => Value::BigUnsigned(None),
+            }]])
+            .append_query_results([[maplit::btreemap! {
+                "num_items" => Into::<Value>::into(3),
+            }]])
+            .into_connection();
+
+        let num_items = fruit::Entity::find()
+            .paginate(&db, 2)
+            .count_mode(CountMode::table_stats(fruit::Entity))
+            .num_items_and_pages()
+            .await?;
+        assert_eq!(
+            (num_items.number_of_items, num_items.number_of_pages),
+            (3, 2)
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    [
+                        "SELECT TABLE_ROWS AS num_items FROM information_schema.TABLES",
+                        "WHERE TABLE_SCHEMA = COALESCE(?, DATABASE()) AND TABLE_NAME = ?",
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [Option::<String>::None.into(), "fruit".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    [
+                        "SELECT COUNT(*) AS num_items FROM",
+                        "(SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`) AS `sub_query`",
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    []
+                ),
+            ]
+        );
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn fetch_page_and_count() -> Result<(), DbErr> {
+        let (_, pages) = setup();
+        let row = |fruit: &fruit::Model| {
+            maplit::btreemap! {
+                "id" => Into::<Value>::into(fruit.id),
+                "name" => fruit.name.clone().into(),
+                "cake_id" => fruit.cake_id.into(),
+                "num_items" => 3_i64.into(),
+            }
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([pages[1].iter().map(row).collect::<Vec<_>>()])
+            .into_connection();
+
+        let paginator = fruit::Entity::find()
+            .order_by_asc(fruit::Column::Id)
+            .paginate(&db, 2);
+        assert_eq!(
+            paginator.fetch_page_and_count(1).await?,
+            (pages[1].clone(), 3)
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id", COUNT(*) OVER() AS "num_items""#,
+                    r#"FROM "fruit" ORDER BY "fruit"."id" ASC LIMIT $1 OFFSET $2"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [2u64.into(), 2u64.into()]
+            )]
+        );
+
+        let (db, _) = setup();
+        assert!(fruit::Entity::find()
+            .from_raw_sql(RAW_STMT.clone())
+            .paginate(&db, 2)
+            .fetch_page_and_count(0)
+            .await
+            .is_err());
+        Ok(())
+    }
+
     #[smol_potat::test]
     #[should_panic]
     async fn error() {
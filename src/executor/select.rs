@@ -5,7 +5,7 @@ use crate::{
     SelectTwoMany, Statement, StreamTrait, TryGetableMany,
 };
 use futures_util::{Stream, TryStreamExt};
-use sea_query::{SelectStatement, Value};
+use sea_query::{Expr, SelectStatement, Value, WithClause};
 use std::collections::HashMap;
 use std::{hash::Hash, marker::PhantomData, pin::Pin};
 
@@ -63,7 +63,7 @@ where
 }
 
 /// Helper class to handle query result for 1 Model
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct SelectModel<M>
 where
     M: FromQueryResult,
@@ -986,6 +986,63 @@ where
         }
     }
 
+    /// Attach a `WITH` clause to the raw statement, replacing the leading `SELECT`.
+    /// Values bound by the clause are placed before the ones of the raw statement.
+    ///
+    /// ```
+    /// use sea_orm::sea_query::{CommonTableExpression, Expr, Query};
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend, Statement};
+    ///
+    /// let old_cakes = CommonTableExpression::new()
+    ///     .query(
+    ///         Query::select()
+    ///             .column(cake::Column::Id)
+    ///             .from(cake::Entity)
+    ///             .and_where(Expr::col(cake::Column::Id).lt(10))
+    ///             .to_owned(),
+    ///     )
+    ///     .table_name("old_cakes")
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .from_raw_sql(Statement::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "id" IN (SELECT "id" FROM "old_cakes") AND "name" = $1"#,
+    ///             ["Cheese".into()]
+    ///         ))
+    ///         .with_cte(old_cakes)
+    ///         .into_statement(),
+    ///     Statement::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         [
+    ///             r#"WITH "old_cakes" AS (SELECT "id" FROM "cake" WHERE "id" < $1)"#,
+    ///             r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "id" IN (SELECT "id" FROM "old_cakes") AND "name" = $2"#,
+    ///         ]
+    ///         .join(" "),
+    ///         [10.into(), "Cheese".into()]
+    ///     )
+    /// );
+    /// ```
+    pub fn with_cte<C>(mut self, clause: C) -> Self
+    where
+        C: Into<WithClause>,
+    {
+        self.stmt = prepend_with_clause(self.stmt, clause.into());
+        self
+    }
+
+    /// Attach a `WITH RECURSIVE` clause to the raw statement, see [SelectorRaw::with_cte]
+    pub fn with_recursive<C>(mut self, clause: C) -> Self
+    where
+        C: Into<WithClause>,
+    {
+        let mut clause = clause.into();
+        clause.recursive(true);
+        self.stmt = prepend_with_clause(self.stmt, clause);
+        self
+    }
+
     /// Get the SQL statement
     pub fn into_statement(self) -> Statement {
         self.stmt
@@ -1111,6 +1168,24 @@ where
     }
 }
 
+fn prepend_with_clause(stmt: Statement, clause: WithClause) -> Statement {
+    let db_backend = stmt.db_backend;
+    let sql = stmt.sql.trim();
+    let sql = match sql.get(..6) {
+        Some(select) if select.eq_ignore_ascii_case("SELECT") => sql[6..].trim().to_owned(),
+        // the statement cannot be spliced, e.g. it already has a `WITH` clause
+        _ => format!("* FROM ({sql}) AS raw_query"),
+    };
+    let mut query = SelectStatement::new();
+    query.expr(if let Some(values) = stmt.values {
+        Expr::cust_with_values(sql, values.0)
+    } else {
+        Expr::cust(sql)
+    });
+    query.with_cte(clause);
+    db_backend.build(&query)
+}
+
 #[allow(clippy::unwrap_used)]
 fn consolidate_query_result<L, R>(
     rows: Vec<(L::Model, Option<R::Model>)>,
@@ -0,0 +1,356 @@
+use crate::{
+    error::*, query::extract_key, ConnectionTrait, EntityTrait, FromQueryResult, Identity,
+    ModelTrait, QueryResult, RelationDef, SelectModel, SelectTree, SelectorRaw,
+};
+use sea_query::ValueTuple;
+use std::collections::HashMap;
+
+/// A row returned by a recursive tree query
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeItem<M> {
+    /// The model
+    pub model: M,
+    /// The number of levels away from the starting row
+    pub depth: u32,
+    /// The keys visited from the starting row, joined by `/`
+    pub path: String,
+}
+
+/// A model nested together with its children
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeNode<M> {
+    /// The model
+    pub model: M,
+    /// The number of levels away from the starting row
+    pub depth: u32,
+    /// The child nodes, in the order they were returned
+    pub children: Vec<TreeNode<M>>,
+}
+
+impl<M> FromQueryResult for TreeItem<M>
+where
+    M: FromQueryResult,
+{
+    fn from_query_result(res: &QueryResult, pre: &str) -> Result<Self, DbErr> {
+        // the type of the depth column differs between backends
+        let depth = match res.try_get::<i64>(pre, "depth") {
+            Ok(depth) => depth,
+            Err(_) => res.try_get::<i32>(pre, "depth")?.into(),
+        };
+        Ok(Self {
+            model: M::from_query_result(res, pre)?,
+            depth: u32::try_from(depth)
+                .map_err(|_| type_err(format!("Invalid tree depth `{depth}`")))?,
+            path: res.try_get(pre, "path")?,
+        })
+    }
+}
+
+impl<E> SelectTree<E>
+where
+    E: EntityTrait,
+{
+    /// Get a [SelectorRaw] yielding [TreeItem]s
+    pub fn into_items(
+        self,
+        db_backend: crate::DbBackend,
+    ) -> SelectorRaw<SelectModel<TreeItem<E::Model>>> {
+        SelectorRaw::<SelectModel<TreeItem<E::Model>>>::from_statement(self.build(db_backend))
+    }
+
+    /// Get all rows of the tree as a flat list, each with its depth and path
+    pub async fn all<C>(self, db: &C) -> Result<Vec<TreeItem<E::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_items(db.get_database_backend()).all(db).await
+    }
+
+    /// Get all rows of the tree nested under their parents
+    pub async fn all_nested<C>(self, db: &C) -> Result<Vec<TreeNode<E::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let parent_col = self.parent_col.clone();
+        let key_col = self.key_col.clone();
+        let items = self.all(db).await?;
+        assemble(items, &parent_col, &key_col)
+    }
+}
+
+impl<M> TreeNode<M>
+where
+    M: ModelTrait,
+{
+    /// Assemble flat rows into nested nodes using a self-referencing relation.
+    /// Rows whose parent is not among the given rows become roots.
+    /// Rows with a duplicated key are only kept once.
+    pub fn assemble(items: Vec<TreeItem<M>>, rel: &RelationDef) -> Result<Vec<Self>, DbErr> {
+        if rel.is_owner {
+            assemble(items, &rel.to_col, &rel.from_col)
+        } else {
+            assemble(items, &rel.from_col, &rel.to_col)
+        }
+    }
+}
+
+fn assemble<M>(
+    items: Vec<TreeItem<M>>,
+    parent_col: &Identity,
+    key_col: &Identity,
+) -> Result<Vec<TreeNode<M>>, DbErr>
+where
+    M: ModelTrait,
+{
+    let mut nodes: Vec<Option<TreeItem<M>>> = Vec::with_capacity(items.len());
+    let mut parents: Vec<ValueTuple> = Vec::with_capacity(items.len());
+    let mut index: HashMap<ValueTuple, usize> = HashMap::new();
+
+    for item in items {
+        let key = extract_key(key_col, &item.model)?;
+        if index.contains_key(&key) {
+            continue;
+        }
+        index.insert(key, nodes.len());
+        parents.push(extract_key(parent_col, &item.model)?);
+        nodes.push(Some(item));
+    }
+
+    let mut roots = Vec::new();
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+    for (i, parent) in parents.iter().enumerate() {
+        match index.get(parent) {
+            Some(&p) if p != i => children[p].push(i),
+            _ => roots.push(i),
+        }
+    }
+
+    fn build<M>(
+        i: usize,
+        nodes: &mut [Option<TreeItem<M>>],
+        children: &[Vec<usize>],
+    ) -> Option<TreeNode<M>> {
+        let item = nodes[i].take()?;
+        Some(TreeNode {
+            model: item.model,
+            depth: item.depth,
+            children: children[i]
+                .iter()
+                .filter_map(|&c| build(c, nodes, children))
+                .collect(),
+        })
+    }
+
+    Ok(roots
+        .into_iter()
+        .filter_map(|i| build(i, &mut nodes, &children))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::category;
+    use crate::{
+        entity::prelude::*, DbBackend, MockDatabase, Statement, Transaction, TreeItem, TreeNode,
+    };
+    use std::collections::BTreeMap;
+
+    fn row(
+        id: i32,
+        parent_id: Option<i32>,
+        depth: i32,
+        path: &str,
+    ) -> BTreeMap<&'static str, Value> {
+        BTreeMap::from([
+            ("id", id.into()),
+            ("parent_id", parent_id.into()),
+            ("name", format!("Category {id}").into()),
+            ("depth", depth.into()),
+            ("path", path.into()),
+        ])
+    }
+
+    fn model(id: i32, parent_id: Option<i32>) -> category::Model {
+        category::Model {
+            id,
+            parent_id,
+            name: format!("Category {id}"),
+        }
+    }
+
+    #[smol_potat::test]
+    async fn find_descendants() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                row(1, None, 0, "1"),
+                row(2, Some(1), 1, "1/2"),
+                row(3, Some(2), 2, "1/2/3"),
+            ]])
+            .into_connection();
+
+        assert_eq!(
+            category::Entity::find_by_id(1)
+                .find_descendants(category::Relation::Parent.def())
+                .max_depth(5)
+                .all(&db)
+                .await?,
+            [
+                TreeItem {
+                    model: model(1, None),
+                    depth: 0,
+                    path: "1".to_owned(),
+                },
+                TreeItem {
+                    model: model(2, Some(1)),
+                    depth: 1,
+                    path: "1/2".to_owned(),
+                },
+                TreeItem {
+                    model: model(3, Some(2)),
+                    depth: 2,
+                    path: "1/2/3".to_owned(),
+                },
+            ]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"WITH RECURSIVE "tree" ("id", "parent_id", "name", "depth", "path", "sort") AS"#,
+                    r#"(SELECT "category"."id", "category"."parent_id", "category"."name", 0 AS "depth","#,
+                    r#"CAST("category"."id" AS TEXT) AS "path", LPAD(CAST("category"."id" AS TEXT), 20, '0') AS "sort""#,
+                    r#"FROM "category" WHERE "category"."id" = $1"#,
+                    r#"UNION ALL (SELECT "category"."id", "category"."parent_id", "category"."name", "tree"."depth" + 1,"#,
+                    r#"("tree"."path" || '/') || CAST("category"."id" AS TEXT),"#,
+                    r#"("tree"."sort" || '/') || LPAD(CAST("category"."id" AS TEXT), 20, '0') FROM "category""#,
+                    r#"INNER JOIN "tree" ON "category"."parent_id" = "tree"."id""#,
+                    r#"WHERE STRPOS(('/' || "tree"."path") || '/', ('/' || CAST("category"."id" AS TEXT)) || '/') = 0"#,
+                    r#"AND "tree"."depth" < $2))"#,
+                    r#"SELECT "tree"."id", "tree"."parent_id", "tree"."name", "tree"."depth", "tree"."path""#,
+                    r#"FROM "tree" ORDER BY "tree"."sort" ASC"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into(), 5u32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn find_tree_nested() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([[
+                row(1, None, 0, "1"),
+                row(2, Some(1), 1, "1/2"),
+                row(4, Some(2), 2, "1/2/4"),
+                row(3, Some(1), 1, "1/3"),
+                row(5, None, 0, "5"),
+            ]])
+            .into_connection();
+
+        assert_eq!(
+            category::Entity::find()
+                .find_tree(category::Relation::Parent.def())
+                .all_nested(&db)
+                .await?,
+            [
+                TreeNode {
+                    model: model(1, None),
+                    depth: 0,
+                    children: vec![
+                        TreeNode {
+                            model: model(2, Some(1)),
+                            depth: 1,
+                            children: vec![TreeNode {
+                                model: model(4, Some(2)),
+                                depth: 2,
+                                children: vec![],
+                            }],
+                        },
+                        TreeNode {
+                            model: model(3, Some(1)),
+                            depth: 1,
+                            children: vec![],
+                        },
+                    ],
+                },
+                TreeNode {
+                    model: model(5, None),
+                    depth: 0,
+                    children: vec![],
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn assemble_ancestors() -> Result<(), DbErr> {
+        let items = vec![
+            TreeItem {
+                model: model(3, Some(2)),
+                depth: 0,
+                path: "3".to_owned(),
+            },
+            TreeItem {
+                model: model(2, Some(1)),
+                depth: 1,
+                path: "3/2".to_owned(),
+            },
+            TreeItem {
+                model: model(1, None),
+                depth: 2,
+                path: "3/2/1".to_owned(),
+            },
+        ];
+
+        assert_eq!(
+            TreeNode::assemble(items, &category::Relation::Parent.def())?,
+            [TreeNode {
+                model: model(1, None),
+                depth: 2,
+                children: vec![TreeNode {
+                    model: model(2, Some(1)),
+                    depth: 1,
+                    children: vec![TreeNode {
+                        model: model(3, Some(2)),
+                        depth: 0,
+                        children: vec![],
+                    }],
+                }],
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_ancestors_sqlite() {
+        assert_eq!(
+            category::Entity::find_by_id(3)
+                .find_ancestors(category::Relation::Parent.def())
+                .build(DbBackend::Sqlite),
+            Statement::from_sql_and_values(
+                DbBackend::Sqlite,
+                [
+                    r#"WITH RECURSIVE "tree" ("id", "parent_id", "name", "depth", "path") AS"#,
+                    r#"(SELECT "category"."id", "category"."parent_id", "category"."name", 0 AS "depth","#,
+                    r#"CAST("category"."id" AS TEXT) AS "path" FROM "category" WHERE "category"."id" = ?"#,
+                    r#"UNION ALL SELECT "category"."id", "category"."parent_id", "category"."name", "tree"."depth" + 1,"#,
+                    r#"("tree"."path" || '/') || CAST("category"."id" AS TEXT) FROM "category""#,
+                    r#"INNER JOIN "tree" ON "category"."id" = "tree"."parent_id""#,
+                    r#"WHERE INSTR(('/' || "tree"."path") || '/', ('/' || CAST("category"."id" AS TEXT)) || '/') = 0)"#,
+                    r#"SELECT "tree"."id", "tree"."parent_id", "tree"."name", "tree"."depth", "tree"."path""#,
+                    r#"FROM "tree" ORDER BY "tree"."depth" ASC"#,
+                ]
+                .join(" "),
+                [3i32.into()]
+            )
+        );
+    }
+}
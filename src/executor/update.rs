@@ -1,6 +1,7 @@
 use crate::{
     error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, IntoActiveModel,
-    Iterable, PrimaryKeyTrait, SelectModel, SelectorRaw, UpdateMany, UpdateOne,
+    Iterable, JoinedUpdateMany, PrimaryKeyTrait, SelectModel, SelectorRaw, TransactionTrait,
+    UpdateMany, UpdateManyModels, UpdateOne,
 };
 use sea_query::{FromValueTuple, Query, UpdateStatement};
 
@@ -61,6 +62,108 @@ where
     }
 }
 
+impl<'a, E> JoinedUpdateMany<E>
+where
+    E: EntityTrait,
+{
+    /// Execute an update operation on multiple ActiveModels, joined to other tables
+    pub async fn exec<C>(self, db: &'a C) -> Result<UpdateResult, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        Updater::new(self.into_query(db.get_database_backend()))
+            .exec(db)
+            .await
+    }
+
+    /// Execute an update operation and return the updated model (use `RETURNING` syntax if supported)
+    ///
+    /// # Panics
+    ///
+    /// Panics if the database backend does not support `UPDATE RETURNING`.
+    pub async fn exec_with_returning<C>(self, db: &'a C) -> Result<Vec<E::Model>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        Updater::new(self.into_query(db.get_database_backend()))
+            .exec_update_with_returning::<E, _>(db)
+            .await
+    }
+}
+
+impl<A> UpdateManyModels<A>
+where
+    A: ActiveModelTrait,
+{
+    /// Execute the update operations on the ActiveModels, one statement after the other.
+    /// Use [`UpdateManyModels::exec_in_transaction`] to apply all of them or none.
+    pub async fn exec<C>(self, db: &C) -> Result<UpdateResult, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let mut rows_affected = 0;
+        for query in self.queries(db.get_database_backend())? {
+            rows_affected += Updater::new(query).exec(db).await?.rows_affected;
+        }
+        Ok(UpdateResult { rows_affected })
+    }
+
+    /// Execute the update operations on the ActiveModels and return the updated models
+    /// (use `RETURNING` syntax if supported)
+    ///
+    /// # Panics
+    ///
+    /// Panics if the database backend does not support `UPDATE RETURNING`.
+    pub async fn exec_with_returning<C>(
+        self,
+        db: &C,
+    ) -> Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let mut models = Vec::new();
+        for query in self.queries(db.get_database_backend())? {
+            models.extend(
+                Updater::new(query)
+                    .exec_update_with_returning::<A::Entity, _>(db)
+                    .await?,
+            );
+        }
+        Ok(models)
+    }
+
+    /// Execute the update operations on the ActiveModels inside a transaction, so that either
+    /// all or none of the models are updated when they are split up into several statements
+    pub async fn exec_in_transaction<C>(self, db: &C) -> Result<UpdateResult, DbErr>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let txn = db.begin().await?;
+        let res = self.exec(&txn).await?;
+        txn.commit().await?;
+        Ok(res)
+    }
+
+    /// Execute the update operations on the ActiveModels inside a transaction and return the
+    /// updated models, see [`UpdateManyModels::exec_in_transaction`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the database backend does not support `UPDATE RETURNING`.
+    pub async fn exec_with_returning_in_transaction<C>(
+        self,
+        db: &C,
+    ) -> Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let txn = db.begin().await?;
+        let res = self.exec_with_returning(&txn).await?;
+        txn.commit().await?;
+        Ok(res)
+    }
+}
+
 impl Updater {
     /// Instantiate an update using an [UpdateStatement]
     pub fn new(query: UpdateStatement) -> Self {
@@ -356,4 +459,105 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn update_many_models_with_returning() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                cake::Model {
+                    id: 1,
+                    name: "Apple Pie".to_owned(),
+                },
+                cake::Model {
+                    id: 2,
+                    name: "Orange Cake".to_owned(),
+                },
+            ]])
+            .into_connection();
+
+        let updated = Update::many_models([
+            cake::ActiveModel {
+                id: Unchanged(1),
+                name: Set("Apple Pie".to_owned()),
+            },
+            cake::ActiveModel {
+                id: Unchanged(2),
+                name: Set("Orange Cake".to_owned()),
+            },
+            cake::ActiveModel {
+                id: Unchanged(3),
+                name: Unchanged("Chocolate Cake".to_owned()),
+            },
+        ])
+        .exec_with_returning(&db)
+        .await?;
+
+        assert_eq!(updated.len(), 2);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"UPDATE "cake" SET "name" = "v"."column2""#,
+                    r#"FROM (VALUES ($1, $2), ($3, $4)) AS "v""#,
+                    r#"WHERE "cake"."id" = "v"."column1" RETURNING "id", "name""#,
+                ]
+                .join(" ")
+                .as_str(),
+                [
+                    1i32.into(),
+                    "Apple Pie".into(),
+                    2i32.into(),
+                    "Orange Cake".into()
+                ]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn update_many_models_in_transaction() -> Result<(), DbErr> {
+        // 3 bind parameters per model, one more model than fits into a statement
+        let models: Vec<_> = (0..10923)
+            .map(|id| cake::ActiveModel {
+                id: Unchanged(id),
+                name: Set(format!("Cake {id}")),
+            })
+            .collect();
+
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 10922,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+
+        let res = Update::many_models(models).exec_in_transaction(&db).await?;
+        assert_eq!(res.rows_affected, 10923);
+
+        // both statements in a single transaction
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 1);
+        let statements = log[0].statements();
+        assert_eq!(statements.len(), 4);
+        assert_eq!(statements[0].sql, "BEGIN");
+        assert_eq!(
+            statements[1..3]
+                .iter()
+                .map(|stmt| stmt.values.as_ref().map(|v| v.0.len()))
+                .collect::<Vec<_>>(),
+            [Some(32766), Some(3)]
+        );
+        assert_eq!(statements[3].sql, "COMMIT");
+
+        Ok(())
+    }
 }
@@ -0,0 +1,273 @@
+use crate::{
+    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, EntityTrait,
+    FromQueryResult, Inserter, IntoActiveModel, Iterable, ModelTrait, Upsert,
+};
+use sea_query::{Expr, Query, Value};
+use std::collections::{HashMap, HashSet};
+
+/// The alias of the flag returned along with each row on Postgres
+const INSERTED: &str = "upsert_inserted";
+
+/// Whether a row was inserted or updated by an [`Upsert`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UpsertStatus {
+    /// No row had the same key, so it was inserted
+    Inserted,
+    /// A row with the same key existed, so it was updated
+    Updated,
+    /// The row was inserted or updated, but the backend does not tell which, see [`Upsert::exec`]
+    Upserted,
+}
+
+/// The result of an [`Upsert`]
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct UpsertResult {
+    /// Whether each model was inserted or updated, in the order of the models
+    pub statuses: Vec<UpsertStatus>,
+}
+
+impl UpsertResult {
+    /// The number of rows known to be inserted
+    pub fn inserted(&self) -> usize {
+        self.count(UpsertStatus::Inserted)
+    }
+
+    /// The number of rows known to be updated
+    pub fn updated(&self) -> usize {
+        self.count(UpsertStatus::Updated)
+    }
+
+    fn count(&self, status: UpsertStatus) -> usize {
+        self.statuses.iter().filter(|s| **s == status).count()
+    }
+}
+
+impl<A> Upsert<A>
+where
+    A: ActiveModelTrait,
+{
+    /// Execute the upsert, telling from its result which models were inserted and which were updated.
+    ///
+    /// How exact the statuses are depends on the backend:
+    /// + Postgres returns `xmax = 0` of each row, which only holds for the rows inserted, so the statuses are exact.
+    /// + MySQL reports 1 affected row for an insert and 2 for an update, so the status of a single model is exact,
+    ///   except that a row set to the values it already had is reported as inserted if the connection
+    ///   counts the found rows rather than the changed ones. This holds whichever unique key the row conflicts on.
+    ///   The statuses of many models cannot be told apart, so they are [`UpsertStatus::Upserted`].
+    /// + SQLite does not tell an insert from an update, so the statuses are [`UpsertStatus::Upserted`].
+    ///
+    /// Of the models with the same key, only the last one is upserted, and all but the first are reported as updated.
+    /// When there is no column to update, the existing rows are left as they are but reported as updated all the same.
+    pub async fn exec<C>(self, db: &C) -> Result<UpsertResult, DbErr>
+    where
+        <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+        C: ConnectionTrait,
+    {
+        if self.models.is_empty() {
+            return Ok(UpsertResult::default());
+        }
+        let db_backend = db.get_database_backend();
+        let (insert, target) = self.prepare()?;
+        let keys = self.keys(&target);
+        let mut seen = HashSet::new();
+        let unique = keys.iter().filter(|key| seen.insert(*key)).count();
+
+        let (mut query, rows) = insert.into_statement_and_rows();
+        if db_backend == DbBackend::Postgres {
+            let returning = <A::Entity as EntityTrait>::Column::iter()
+                .map(|c| c.select_as(c.into_returning_expr(db_backend)))
+                .chain([Expr::cust(format!(r#"xmax = 0 AS "{INSERTED}""#))]);
+            query.returning(Query::returning().exprs(returning));
+        }
+        let statements = Inserter::<A>::new(None, query)
+            .with_rows(rows)
+            .into_statements(db_backend)
+            .1;
+
+        let mut inserted = HashMap::new();
+        let mut rows_affected = 0;
+        for statement in statements {
+            let statement = db_backend.build(&statement);
+            if db_backend != DbBackend::Postgres {
+                rows_affected += db.execute(statement).await?.rows_affected();
+                continue;
+            }
+            for row in db.query_all(statement).await? {
+                let model = <A::Entity as EntityTrait>::Model::from_query_result(&row, "")?;
+                let key: Vec<Value> = target.iter().map(|col| model.get(*col)).collect();
+                inserted.insert(key, row.try_get::<bool>("", INSERTED)?);
+            }
+        }
+        let status = |key: &Vec<Value>| match db_backend {
+            // the rows left as they are by `DO NOTHING` are not returned
+            DbBackend::Postgres if inserted.get(key) == Some(&true) => UpsertStatus::Inserted,
+            DbBackend::Postgres => UpsertStatus::Updated,
+            DbBackend::MySql if unique == 1 && rows_affected == 1 => UpsertStatus::Inserted,
+            DbBackend::MySql if unique == 1 => UpsertStatus::Updated,
+            _ => UpsertStatus::Upserted,
+        };
+
+        let mut seen = HashSet::new();
+        let statuses = keys
+            .iter()
+            .map(|key| {
+                if seen.insert(key) {
+                    status(key)
+                } else {
+                    UpsertStatus::Updated
+                }
+            })
+            .collect();
+        Ok(UpsertResult { statuses })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::cake;
+    use crate::{
+        DbBackend, DbErr, MockDatabase, MockExecResult, Set, Transaction, Upsert, UpsertStatus,
+        Value,
+    };
+    use std::collections::BTreeMap;
+
+    fn returned(id: i32, name: &str, inserted: bool) -> BTreeMap<&'static str, Value> {
+        BTreeMap::from([
+            ("id", id.into()),
+            ("name", name.into()),
+            ("upsert_inserted", inserted.into()),
+        ])
+    }
+
+    #[smol_potat::test]
+    async fn upsert_statuses() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[returned(2, "Cake 2", false), returned(1, "Cake 1", true)]])
+            .into_connection();
+
+        let res = Upsert::many([1, 2, 1].map(|id| cake::ActiveModel {
+            id: Set(id),
+            name: Set(format!("Cake {id}")),
+        }))
+        .exec(&db)
+        .await?;
+
+        assert_eq!(
+            res.statuses,
+            [
+                UpsertStatus::Inserted,
+                UpsertStatus::Updated,
+                UpsertStatus::Updated
+            ]
+        );
+        assert_eq!((res.inserted(), res.updated()), (1, 2));
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"INSERT INTO "cake" ("id", "name") VALUES ($1, $2), ($3, $4)"#,
+                    r#"ON CONFLICT ("id") DO UPDATE SET "name" = "excluded"."name""#,
+                    r#"RETURNING "id", "name", xmax = 0 AS "upsert_inserted""#,
+                ]
+                .join(" ")
+                .as_str(),
+                [2.into(), "Cake 2".into(), 1.into(), "Cake 1".into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn upsert_one_mysql() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 1,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 1,
+                    rows_affected: 2,
+                },
+            ])
+            .into_connection();
+
+        let model = cake::ActiveModel {
+            id: Set(1),
+            name: Set("Apple Pie".to_owned()),
+        };
+        for status in [UpsertStatus::Inserted, UpsertStatus::Updated] {
+            let res = Upsert::one(model.clone()).exec(&db).await?;
+            assert_eq!(res.statuses, [status]);
+        }
+
+        let statement = Transaction::from_sql_and_values(
+            DbBackend::MySql,
+            [
+                "INSERT INTO `cake` (`id`, `name`) VALUES (?, ?)",
+                "ON DUPLICATE KEY UPDATE `name` = VALUES(`name`)",
+            ]
+            .join(" ")
+            .as_str(),
+            [1.into(), "Apple Pie".into()],
+        );
+        assert_eq!(db.into_transaction_log(), [statement.clone(), statement]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn upsert_many_sqlite() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 2,
+                rows_affected: 2,
+            }])
+            .into_connection();
+
+        let res = Upsert::many(
+            [(1, "Apple Pie"), (2, "Orange Cake"), (1, "Cheese Cake")].map(|(id, name)| {
+                cake::ActiveModel {
+                    id: Set(id),
+                    name: Set(name.to_owned()),
+                }
+            }),
+        )
+        .exec(&db)
+        .await?;
+
+        assert_eq!(
+            res.statuses,
+            [
+                UpsertStatus::Upserted,
+                UpsertStatus::Upserted,
+                UpsertStatus::Updated
+            ]
+        );
+        assert_eq!((res.inserted(), res.updated()), (0, 1));
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Sqlite,
+                [
+                    r#"INSERT INTO "cake" ("id", "name") VALUES (?, ?), (?, ?)"#,
+                    r#"ON CONFLICT ("id") DO UPDATE SET "name" = "excluded"."name""#,
+                ]
+                .join(" ")
+                .as_str(),
+                [
+                    2.into(),
+                    "Orange Cake".into(),
+                    1.into(),
+                    "Cheese Cake".into()
+                ]
+            )]
+        );
+
+        Ok(())
+    }
+}
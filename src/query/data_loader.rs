@@ -0,0 +1,410 @@
+use crate::{
+    error::*, query::loader::extract_key, ConnectionTrait, DbErr, EntityTrait, LoaderTrait,
+    ModelTrait, Related,
+};
+use futures_util::future::{BoxFuture, FutureExt, Shared};
+use sea_query::ValueTuple;
+use std::{
+    any::{Any, TypeId},
+    collections::{HashMap, HashSet},
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+/// A per-request loader of related models, for resolvers such as the ones of a GraphQL server.
+///
+/// Loading the related models of a single parent is deferred until the current tick ends,
+/// so that concurrent calls for the same relation are coalesced into one `IN (...)` query
+/// through [`LoaderTrait`].
+/// The loaded models are cached by relation and by the key of the parent,
+/// so a `DataLoader` should not outlive the request it serves.
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// #
+/// # let db = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[
+/// #         fruit::Model {
+/// #             id: 1,
+/// #             name: "Apple".to_owned(),
+/// #             cake_id: Some(1),
+/// #         },
+/// #         fruit::Model {
+/// #             id: 2,
+/// #             name: "Orange".to_owned(),
+/// #             cake_id: Some(2),
+/// #         },
+/// #     ]])
+/// #     .into_connection();
+/// #
+/// let loader = DataLoader::new(db);
+///
+/// let cake_1 = cake::Model {
+///     id: 1,
+///     name: "Apple Pie".to_owned(),
+/// };
+/// let cake_2 = cake::Model {
+///     id: 2,
+///     name: "Orange Cake".to_owned(),
+/// };
+///
+/// // a single query is executed
+/// let (fruits_1, fruits_2) = futures::join!(
+///     loader.load_many::<_, fruit::Entity>(&cake_1),
+///     loader.load_many::<_, fruit::Entity>(&cake_2),
+/// );
+///
+/// assert_eq!(fruits_1?[0].name, "Apple");
+/// assert_eq!(fruits_2?[0].name, "Orange");
+///
+/// // served from the cache
+/// assert_eq!(loader.load_many::<_, fruit::Entity>(&cake_1).await?.len(), 1);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DataLoader<C> {
+    db: Arc<C>,
+    state: Arc<Mutex<LoaderState>>,
+}
+
+#[derive(Debug, Default)]
+struct LoaderState {
+    /// Batches not yet executed, by relation
+    pending: HashMap<TypeId, Box<dyn Any + Send>>,
+    /// Related models by relation, then by the key of the parent
+    cache: HashMap<TypeId, Box<dyn Any + Send>>,
+}
+
+type BatchFuture = Shared<BoxFuture<'static, Result<(), Arc<DbErr>>>>;
+
+/// The parents whose related models are to be loaded together
+struct Batch<M> {
+    /// Number of loads which joined the batch
+    joined: usize,
+    keys: Vec<ValueTuple>,
+    seen: HashSet<ValueTuple>,
+    parents: Vec<M>,
+    future: BatchFuture,
+}
+
+/// Marks a relation loaded with [`LoaderTrait::load_one`]
+struct One<M, R>(PhantomData<(M, R)>);
+
+/// Marks a relation loaded with [`LoaderTrait::load_many`]
+struct Many<M, R>(PhantomData<(M, R)>);
+
+impl<C> DataLoader<C>
+where
+    C: ConnectionTrait + Send + 'static,
+{
+    /// Create a loader with an empty cache
+    pub fn new(db: C) -> Self {
+        Self {
+            db: Arc::new(db),
+            state: Default::default(),
+        }
+    }
+
+    /// Load the has_one (or belongs_to) related model of a parent,
+    /// batched with the concurrent calls for the same relation
+    pub async fn load_one<M, R>(&self, model: &M) -> Result<Option<R::Model>, DbErr>
+    where
+        M: ModelTrait + Sync + 'static,
+        M::Entity: Related<R>,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+    {
+        let models = self
+            .load::<One<M::Entity, R>, M, R, _, _>(model, |parents, db| async move {
+                let loaded = parents.load_one(R::find(), db.as_ref()).await?;
+                Ok(loaded.into_iter().map(Vec::from_iter).collect())
+            })
+            .await?;
+        Ok(models.into_iter().next())
+    }
+
+    /// Load the has_many related models of a parent,
+    /// batched with the concurrent calls for the same relation
+    pub async fn load_many<M, R>(&self, model: &M) -> Result<Vec<R::Model>, DbErr>
+    where
+        M: ModelTrait + Sync + 'static,
+        M::Entity: Related<R>,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+    {
+        self.load::<Many<M::Entity, R>, M, R, _, _>(model, |parents, db| async move {
+            parents.load_many(R::find(), db.as_ref()).await
+        })
+        .await
+    }
+
+    /// Empty the cache, so that the related models are loaded again
+    pub fn clear(&self) -> Result<(), DbErr> {
+        self.state.lock().map_err(query_err)?.cache.clear();
+        Ok(())
+    }
+
+    async fn load<K, M, R, F, Fut>(&self, model: &M, exec: F) -> Result<Vec<R::Model>, DbErr>
+    where
+        K: 'static,
+        M: ModelTrait + Sync + 'static,
+        M::Entity: Related<R>,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        F: FnOnce(Vec<M>, Arc<C>) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Vec<Vec<R::Model>>, DbErr>> + Send + 'static,
+    {
+        let key = extract_key(&<M::Entity as Related<R>>::to().from_col, model)?;
+        let relation = TypeId::of::<K>();
+
+        let future = {
+            let mut state = self.state.lock().map_err(query_err)?;
+            if let Some(models) = cached::<R>(&state, relation, &key)? {
+                return Ok(models);
+            }
+            let batch = state
+                .pending
+                .entry(relation)
+                .or_insert_with(|| Box::new(self.new_batch::<M, R, F, Fut>(relation, exec)))
+                .downcast_mut::<Batch<M>>()
+                .ok_or_else(|| query_err("DataLoader: batch type mismatch"))?;
+            batch.joined += 1;
+            if batch.seen.insert(key.clone()) {
+                batch.keys.push(key.clone());
+                batch.parents.push(model.clone());
+            }
+            batch.future.clone()
+        };
+
+        future.await.map_err(unshare_err)?;
+
+        let state = self.state.lock().map_err(query_err)?;
+        Ok(cached::<R>(&state, relation, &key)?.unwrap_or_default())
+    }
+
+    fn new_batch<M, R, F, Fut>(&self, relation: TypeId, exec: F) -> Batch<M>
+    where
+        M: ModelTrait + 'static,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        F: FnOnce(Vec<M>, Arc<C>) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Vec<Vec<R::Model>>, DbErr>> + Send + 'static,
+    {
+        let db = Arc::clone(&self.db);
+        let state = Arc::clone(&self.state);
+        let future = async move {
+            // let the other loads of this tick join the batch, until no more do
+            let mut joined = 0;
+            loop {
+                YieldNow(false).await;
+                let now = state
+                    .lock()
+                    .map_err(query_err)?
+                    .pending
+                    .get(&relation)
+                    .and_then(|batch| batch.downcast_ref::<Batch<M>>())
+                    .map(|batch| batch.joined)
+                    .unwrap_or_default();
+                if now == joined {
+                    break;
+                }
+                joined = now;
+            }
+
+            let batch = state
+                .lock()
+                .map_err(query_err)?
+                .pending
+                .remove(&relation)
+                .and_then(|batch| batch.downcast::<Batch<M>>().ok())
+                .ok_or_else(|| query_err("DataLoader: batch not found"))?;
+
+            let loaded = exec(batch.parents, db).await?;
+
+            let mut state = state.lock().map_err(query_err)?;
+            let cache = state
+                .cache
+                .entry(relation)
+                .or_insert_with(|| Box::new(HashMap::<ValueTuple, Vec<R::Model>>::new()))
+                .downcast_mut::<HashMap<ValueTuple, Vec<R::Model>>>()
+                .ok_or_else(|| query_err("DataLoader: cache type mismatch"))?;
+            cache.extend(batch.keys.into_iter().zip(loaded));
+
+            Ok(())
+        }
+        .map(|res: Result<(), DbErr>| res.map_err(Arc::new))
+        .boxed()
+        .shared();
+
+        Batch {
+            joined: 0,
+            keys: Vec::new(),
+            seen: HashSet::new(),
+            parents: Vec::new(),
+            future,
+        }
+    }
+}
+
+fn cached<R>(
+    state: &LoaderState,
+    relation: TypeId,
+    key: &ValueTuple,
+) -> Result<Option<Vec<R::Model>>, DbErr>
+where
+    R: EntityTrait,
+{
+    match state.cache.get(&relation) {
+        Some(cache) => Ok(cache
+            .downcast_ref::<HashMap<ValueTuple, Vec<R::Model>>>()
+            .ok_or_else(|| query_err("DataLoader: cache type mismatch"))?
+            .get(key)
+            .cloned()),
+        None => Ok(None),
+    }
+}
+
+/// Take back the error of a batch, shared by all the loads which joined it.
+/// The variant is kept, only the details which cannot be cloned are turned into text.
+fn unshare_err(err: Arc<DbErr>) -> DbErr {
+    let err = match Arc::try_unwrap(err) {
+        Ok(err) => return err,
+        Err(err) => err,
+    };
+    let runtime_err = |e: &RuntimeErr| RuntimeErr::Internal(e.to_string());
+    match &*err {
+        DbErr::ConnectionAcquire(ConnAcquireErr::Timeout) => {
+            DbErr::ConnectionAcquire(ConnAcquireErr::Timeout)
+        }
+        DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed) => {
+            DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed)
+        }
+        DbErr::TryIntoErr { from, into, .. } => DbErr::TryIntoErr {
+            from,
+            into,
+            source: Box::new(Arc::clone(&err)),
+        },
+        DbErr::Conn(e) => DbErr::Conn(runtime_err(e)),
+        DbErr::Exec(e) => DbErr::Exec(runtime_err(e)),
+        DbErr::Query(e) => DbErr::Query(runtime_err(e)),
+        DbErr::ConvertFromU64(ty) => DbErr::ConvertFromU64(ty),
+        DbErr::UnpackInsertId => DbErr::UnpackInsertId,
+        DbErr::UpdateGetPrimaryKey => DbErr::UpdateGetPrimaryKey,
+        DbErr::RecordNotFound(s) => DbErr::RecordNotFound(s.clone()),
+        DbErr::AttrNotSet(s) => DbErr::AttrNotSet(s.clone()),
+        DbErr::Custom(s) => DbErr::Custom(s.clone()),
+        DbErr::Type(s) => DbErr::Type(s.clone()),
+        DbErr::Json(s) => DbErr::Json(s.clone()),
+        DbErr::Migration(s) => DbErr::Migration(s.clone()),
+        DbErr::RecordNotInserted => DbErr::RecordNotInserted,
+        DbErr::RecordNotUpdated => DbErr::RecordNotUpdated,
+    }
+}
+
+/// Pending once, so that the other futures polled in the same tick get to run
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, cake_model, fruit_model};
+    use crate::{DataLoader, DbBackend, DbErr, MockDatabase, RuntimeErr};
+
+    #[smol_potat::test]
+    async fn coalesce_concurrent_loads() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake_model(1), cake_model(2)]])
+            .append_query_results([[cake_model(3)]])
+            .into_connection();
+
+        let loader = DataLoader::new(db);
+
+        let fruits = [
+            fruit_model(1, Some(1)),
+            fruit_model(2, Some(2)),
+            fruit_model(3, Some(1)),
+            fruit_model(4, None),
+        ];
+
+        let cakes = futures::future::join_all(
+            fruits
+                .iter()
+                .map(|fruit| loader.load_one::<_, cake::Entity>(fruit)),
+        )
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(
+            cakes,
+            [
+                Some(cake_model(1)),
+                Some(cake_model(2)),
+                Some(cake_model(1)),
+                None
+            ]
+        );
+
+        // cached
+        assert_eq!(
+            loader.load_one::<_, cake::Entity>(&fruits[1]).await?,
+            Some(cake_model(2))
+        );
+        // a new batch
+        assert_eq!(
+            loader
+                .load_one::<_, cake::Entity>(&fruit_model(5, Some(3)))
+                .await?,
+            Some(cake_model(3))
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn share_batch_error() {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_errors([DbErr::Query(RuntimeErr::Internal("boom".to_owned()))])
+            .into_connection();
+
+        let loader = DataLoader::new(db);
+
+        let fruits = [fruit_model(1, Some(1)), fruit_model(2, Some(2))];
+
+        let cakes = futures::future::join_all(
+            fruits
+                .iter()
+                .map(|fruit| loader.load_one::<_, cake::Entity>(fruit)),
+        )
+        .await;
+
+        for cake in cakes {
+            assert!(matches!(
+                cake,
+                Err(DbErr::Query(RuntimeErr::Internal(msg))) if msg == "boom"
+            ));
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use crate::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, Iterable,
-    PrimaryKeyToColumn, QueryFilter, QueryTrait,
+    query::helper::joined_query, ActiveModelTrait, ActiveValue, ColumnTrait, DbBackend,
+    EntityTrait, IntoActiveModel, Iterable, PrimaryKeyToColumn, QueryFilter, QueryTrait,
+    RelationDef, Statement,
 };
 use core::marker::PhantomData;
-use sea_query::DeleteStatement;
+use sea_query::{Condition, DeleteStatement, Expr, IntoCondition, TableRef};
 
 /// Defines the structure for a delete operation
 #[derive(Clone, Debug)]
@@ -29,6 +30,22 @@ where
     pub(crate) entity: PhantomData<E>,
 }
 
+/// Perform a delete operation on multiple models, joined to the tables of other Entities,
+/// see [`DeleteMany::join`]
+#[derive(Clone, Debug)]
+pub struct JoinedDeleteMany<E>
+where
+    E: EntityTrait,
+{
+    /// The statement before the joins, with the filters on the Entity alone
+    pub(crate) query: DeleteStatement,
+    /// The joined tables and their join conditions
+    pub(crate) joins: Vec<(TableRef, Condition)>,
+    /// The filters added after the joins, which may refer to the joined tables
+    pub(crate) condition: Condition,
+    pub(crate) entity: PhantomData<E>,
+}
+
 impl Delete {
     /// Delete one Model or ActiveModel
     ///
@@ -180,10 +197,84 @@ where
     }
 }
 
+impl<E> DeleteMany<E>
+where
+    E: EntityTrait,
+{
+    /// Join the table of another Entity through a relation, so that the filters added
+    /// on the returned [`JoinedDeleteMany`] can refer to its columns.
+    ///
+    /// As there is no portable `DELETE ... JOIN`, the filters added after the join are moved
+    /// into a correlated `EXISTS` subquery over the joined tables on every backend.
+    /// The filters added before the join are on the Entity alone, and are kept as they are.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::delete_many()
+    ///         .join(fruit::Relation::Cake.def())
+    ///         .filter(cake::Column::Name.contains("Cheese"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"DELETE FROM "fruit" WHERE EXISTS(SELECT 1 FROM "cake""#,
+    ///         r#"WHERE "fruit"."cake_id" = "cake"."id" AND "cake"."name" LIKE '%Cheese%')"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn join(self, rel: RelationDef) -> JoinedDeleteMany<E> {
+        JoinedDeleteMany {
+            query: self.query,
+            joins: Vec::new(),
+            condition: Condition::all(),
+            entity: PhantomData,
+        }
+        .join(rel)
+    }
+}
+
+impl<E> JoinedDeleteMany<E>
+where
+    E: EntityTrait,
+{
+    /// Join the table of one more Entity through a relation, see [`DeleteMany::join`]
+    pub fn join(mut self, rel: RelationDef) -> Self {
+        self.joins.push((rel.to_tbl.clone(), rel.into_condition()));
+        self
+    }
+
+    /// Add a filter, which can refer to the joined tables
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: IntoCondition,
+    {
+        self.condition = self.condition.add(filter.into_condition());
+        self
+    }
+
+    /// Build the statement as [`Statement`], see [`DeleteMany::join`]
+    pub fn build(&self, db_backend: DbBackend) -> Statement {
+        db_backend.build(&self.clone().into_query())
+    }
+
+    /// The statement, see [`DeleteMany::join`]
+    pub fn into_query(self) -> DeleteStatement {
+        let mut query = self.query;
+        query.and_where(Expr::exists(joined_query(
+            &self.joins,
+            self.condition,
+            Expr::cust("1"),
+        )));
+        query
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tests_cfg::{cake, fruit};
-    use crate::{entity::*, query::*, DbBackend};
+    use crate::{entity::*, query::*, DbBackend, DbErr, MockDatabase, MockExecResult, Transaction};
 
     #[test]
     fn delete_1() {
@@ -217,4 +308,39 @@ mod tests {
             r#"DELETE FROM "fruit" WHERE "fruit"."name" LIKE '%Cheese%'"#,
         );
     }
+
+    #[smol_potat::test]
+    async fn delete_many_join() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 2,
+            }])
+            .into_connection();
+
+        let res = fruit::Entity::delete_many()
+            .filter(fruit::Column::Name.contains("Apple"))
+            .join(fruit::Relation::Cake.def())
+            .filter(cake::Column::Name.eq("Cheese Cake"))
+            .exec(&db)
+            .await?;
+        assert_eq!(res.rows_affected, 2);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::MySql,
+                [
+                    "DELETE FROM `fruit` WHERE `fruit`.`name` LIKE ?",
+                    "AND EXISTS(SELECT 1 FROM `cake`",
+                    "WHERE `fruit`.`cake_id` = `cake`.`id` AND `cake`.`name` = ?)",
+                ]
+                .join(" ")
+                .as_str(),
+                ["%Apple%".into(), "Cheese Cake".into()]
+            )]
+        );
+
+        Ok(())
+    }
 }
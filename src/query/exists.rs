@@ -0,0 +1,465 @@
+use crate::query::helper::{unpack_table_alias, unpack_table_ref};
+use crate::{
+    DeleteMany, EntityTrait, Linked, QueryFilter, Related, RelationDef, Select, SelectTwo,
+    SelectTwoMany, UpdateMany,
+};
+use sea_query::{
+    Alias, Asterisk, BinOper, DynIden, Expr, Func, IntoCondition, IntoIden, JoinType, Query,
+    SelectStatement, SimpleExpr, SubQueryStatement, TableRef,
+};
+
+/// Filter by the existence or the number of related rows, using correlated subqueries.
+/// Implemented for every [`QueryFilter`] of an Entity, so that generic code can use it.
+pub trait RelatedFilter: QueryFilter + Sized {
+    /// The Entity the rows belong to
+    type Entity: EntityTrait;
+
+    /// Whether the statement updates or deletes the rows of the Entity. If so, a subquery
+    /// reads the table of the Entity through a derived table, as MySQL cannot read the table
+    /// being modified in a subquery otherwise.
+    const MODIFIES_ENTITY: bool = false;
+
+    /// Keep the rows having at least one related row which satisfies the condition,
+    /// using a correlated `EXISTS` subquery.
+    /// The condition can refer to the columns of the related Entity.
+    ///
+    /// When the related Entity is the Entity itself, its columns in the condition would refer
+    /// to the outer row, use [`RelatedFilter::filter_has_related_with`] instead.
+    fn filter_has_related<R, C>(self, condition: C) -> Self
+    where
+        R: EntityTrait,
+        Self::Entity: Related<R>,
+        C: IntoCondition,
+    {
+        self.filter_has_related_with::<R, _, _>(|_| condition)
+    }
+
+    /// Keep the rows having at least one related row which satisfies the condition,
+    /// using a correlated `EXISTS` subquery.
+    /// The condition is given the name of the related table in the subquery, to refer to
+    /// its columns with.
+    ///
+    /// When the related Entity is the Entity itself, the tables in the subquery are aliased
+    /// like [`RelatedFilter::filter_has_linked`], so the related table is named `r0`
+    /// (or `r1` through a `via` Entity).
+    fn filter_has_related_with<R, F, C>(self, f: F) -> Self
+    where
+        R: EntityTrait,
+        Self::Entity: Related<R>,
+        F: FnOnce(DynIden) -> C,
+        C: IntoCondition,
+    {
+        let (mut query, related) = related_query::<Self::Entity, R>(Self::MODIFIES_ENTITY);
+        query.cond_where(f(related));
+        self.filter(Expr::exists(query))
+    }
+
+    /// Keep the rows not having any related row, using a correlated `NOT EXISTS` subquery.
+    fn filter_doesnt_have_related<R>(self) -> Self
+    where
+        R: EntityTrait,
+        Self::Entity: Related<R>,
+    {
+        let (query, _) = related_query::<Self::Entity, R>(Self::MODIFIES_ENTITY);
+        self.filter(Expr::exists(query).not())
+    }
+
+    /// Keep the rows whose number of related rows compares to `n` with the given operator,
+    /// e.g. `BinOper::GreaterThanOrEqual`.
+    fn filter_related_count<R, N>(self, op: BinOper, n: N) -> Self
+    where
+        R: EntityTrait,
+        Self::Entity: Related<R>,
+        N: Into<SimpleExpr>,
+    {
+        let (mut query, _) = related_query::<Self::Entity, R>(Self::MODIFIES_ENTITY);
+        query.clear_selects().expr(Func::count(Expr::col(Asterisk)));
+        self.filter(
+            SimpleExpr::SubQuery(None, Box::new(SubQueryStatement::SelectStatement(query)))
+                .binary(op, n),
+        )
+    }
+
+    /// Keep the rows having at least one row at the end of the [`Linked`] chain
+    /// which satisfies the condition, using a correlated `EXISTS` subquery.
+    ///
+    /// Like [`Select::find_also_linked`], the `n` tables along the chain are aliased
+    /// as `r0` to `r{n-1}`, so the condition should refer to the linked Entity as `r{n-1}`.
+    ///
+    /// An empty chain links to no row, so every row is filtered out.
+    fn filter_has_linked<L, C>(self, l: L, condition: C) -> Self
+    where
+        L: Linked<FromEntity = Self::Entity>,
+        C: IntoCondition,
+    {
+        match linked_query::<Self::Entity>(l.link(), Self::MODIFIES_ENTITY) {
+            Some((mut query, _)) => {
+                query.cond_where(condition);
+                self.filter(Expr::exists(query))
+            }
+            None => self.filter(Expr::value(false)),
+        }
+    }
+
+    /// Keep the rows not having any row at the end of the [`Linked`] chain,
+    /// using a correlated `NOT EXISTS` subquery.
+    ///
+    /// An empty chain links to no row, so no row is filtered out.
+    fn filter_doesnt_have_linked<L>(self, l: L) -> Self
+    where
+        L: Linked<FromEntity = Self::Entity>,
+    {
+        match linked_query::<Self::Entity>(l.link(), Self::MODIFIES_ENTITY) {
+            Some((query, _)) => self.filter(Expr::exists(query).not()),
+            None => self,
+        }
+    }
+}
+
+impl<E> RelatedFilter for Select<E>
+where
+    E: EntityTrait,
+{
+    type Entity = E;
+}
+
+impl<E, F> RelatedFilter for SelectTwo<E, F>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    type Entity = E;
+}
+
+impl<E, F> RelatedFilter for SelectTwoMany<E, F>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    type Entity = E;
+}
+
+impl<E> RelatedFilter for UpdateMany<E>
+where
+    E: EntityTrait,
+{
+    type Entity = E;
+
+    const MODIFIES_ENTITY: bool = true;
+}
+
+impl<E> RelatedFilter for DeleteMany<E>
+where
+    E: EntityTrait,
+{
+    type Entity = E;
+
+    const MODIFIES_ENTITY: bool = true;
+}
+
+/// `SELECT 1 FROM <related> [INNER JOIN <via>] WHERE <correlated with the outer table>`,
+/// along with the name of the related table in the subquery
+fn related_query<E, R>(modifies_entity: bool) -> (SelectStatement, DynIden)
+where
+    E: EntityTrait + Related<R>,
+    R: EntityTrait,
+{
+    let mut rels = match E::via() {
+        Some(via) => vec![via, E::to()],
+        None => vec![E::to()],
+    };
+    // the outer table would be ambiguous in the subquery of a self-referencing relation
+    let outer = E::default().table_ref();
+    if rels.iter().any(|rel| rel.to_tbl == outer) {
+        alias_path(&mut rels, modifies_entity.then_some(&outer));
+    }
+    relation_path_query(rels)
+}
+
+fn linked_query<E>(
+    mut rels: Vec<RelationDef>,
+    modifies_entity: bool,
+) -> Option<(SelectStatement, DynIden)>
+where
+    E: EntityTrait,
+{
+    if rels.is_empty() {
+        return None;
+    }
+    let outer = E::default().table_ref();
+    alias_path(&mut rels, modifies_entity.then_some(&outer));
+    Some(relation_path_query(rels))
+}
+
+/// Alias the tables along the path as `r0` to `r{n-1}`, but not the table it starts from.
+/// The `modified` table is read through a derived table, `(SELECT * FROM <table>) AS r{i}`.
+fn alias_path(rels: &mut [RelationDef], modified: Option<&TableRef>) {
+    for i in 0..rels.len() {
+        let alias = Alias::new(format!("r{i}")).into_iden();
+        let tbl = match modified {
+            Some(modified) if *modified == rels[i].to_tbl => TableRef::SubQuery(
+                Query::select()
+                    .column(Asterisk)
+                    .from(modified.clone())
+                    .to_owned(),
+                alias,
+            ),
+            _ => rels[i].to_tbl.clone().alias(alias),
+        };
+        rels[i].to_tbl = tbl.clone();
+        if let Some(next) = rels.get_mut(i + 1) {
+            next.from_tbl = tbl;
+        }
+    }
+}
+
+/// Select from the last table of the path, joining back to the second table of the path,
+/// and correlate the first relation of the path with the outer query.
+/// Returns the name of the last table in the subquery along with it.
+fn relation_path_query(rels: Vec<RelationDef>) -> (SelectStatement, DynIden) {
+    let mut query = SelectStatement::new();
+    query.expr(Expr::cust("1"));
+
+    let mut rels = rels.into_iter().rev();
+    let mut pending = rels.next().expect("the path has at least one relation");
+    query.from(pending.to_tbl.clone());
+    let related =
+        unpack_table_alias(&pending.to_tbl).unwrap_or_else(|| unpack_table_ref(&pending.to_tbl));
+    for rel in rels {
+        query.join(JoinType::InnerJoin, pending.from_tbl.clone(), pending);
+        pending = rel;
+    }
+    query.cond_where(pending);
+    (query, related)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RelatedFilter;
+    use crate::tests_cfg::{cake, category, entity_linked, filling, fruit, vendor};
+    use crate::{ColumnTrait, DbBackend, EntityTrait, QueryFilter, QueryTrait};
+    use sea_query::{BinOper, Expr};
+
+    #[test]
+    fn filter_has_related() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter_has_related::<fruit::Entity, _>(fruit::Column::Name.contains("apple"))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"WHERE EXISTS(SELECT 1 FROM "fruit" WHERE "cake"."id" = "fruit"."cake_id""#,
+                r#"AND "fruit"."name" LIKE '%apple%')"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn filter_has_related_via() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter_has_related::<filling::Entity, _>(filling::Column::Name.eq("Lemon"))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE EXISTS(SELECT 1 FROM `filling`",
+                "INNER JOIN `cake_filling` ON `cake_filling`.`filling_id` = `filling`.`id`",
+                "WHERE `cake`.`id` = `cake_filling`.`cake_id` AND `filling`.`name` = 'Lemon')",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn filter_doesnt_have_related() {
+        assert_eq!(
+            fruit::Entity::delete_many()
+                .filter_doesnt_have_related::<cake::Entity>()
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"DELETE FROM "fruit""#,
+                r#"WHERE NOT EXISTS(SELECT 1 FROM "cake" WHERE "fruit"."cake_id" = "cake"."id")"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn filter_related_count() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter_related_count::<fruit::Entity, _>(BinOper::GreaterThanOrEqual, 3)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"WHERE (SELECT COUNT(*) FROM "fruit" WHERE "cake"."id" = "fruit"."cake_id") >= 3"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn filter_has_linked() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter_has_linked(
+                    entity_linked::CakeToFillingVendor,
+                    Expr::col(("r2", vendor::Column::Name)).eq("Bakery"),
+                )
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"WHERE EXISTS(SELECT 1 FROM "vendor" AS "r2""#,
+                r#"INNER JOIN "filling" AS "r1" ON "r1"."vendor_id" = "r2"."id""#,
+                r#"INNER JOIN "cake_filling" AS "r0" ON "r0"."filling_id" = "r1"."id""#,
+                r#"WHERE "cake"."id" = "r0"."cake_id" AND "r2"."name" = 'Bakery')"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn filter_doesnt_have_linked() {
+        assert_eq!(
+            cake::Entity::update_many()
+                .col_expr(cake::Column::Name, Expr::value("Plain"))
+                .filter_doesnt_have_linked(entity_linked::CheeseCakeToFillingVendor)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"UPDATE "cake" SET "name" = 'Plain'"#,
+                r#"WHERE NOT EXISTS(SELECT 1 FROM "vendor" AS "r2""#,
+                r#"INNER JOIN "filling" AS "r1" ON "r1"."vendor_id" = "r2"."id""#,
+                r#"INNER JOIN "cake_filling" AS "r0" ON "r0"."filling_id" = "r1"."id""#,
+                r#"WHERE "cake"."id" = "r0"."cake_id" AND "cake"."name" LIKE '%cheese%')"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn filter_empty_linked() {
+        struct CakeToCake;
+
+        impl crate::Linked for CakeToCake {
+            type FromEntity = cake::Entity;
+            type ToEntity = cake::Entity;
+
+            fn link(&self) -> Vec<crate::LinkDef> {
+                vec![]
+            }
+        }
+
+        assert_eq!(
+            cake::Entity::find()
+                .filter_has_linked(CakeToCake, Expr::value(true))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE FALSE"#,
+        );
+        assert_eq!(
+            cake::Entity::find()
+                .filter_doesnt_have_linked(CakeToCake)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+        );
+    }
+
+    #[test]
+    fn filter_has_related_with() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter_has_related_with::<fruit::Entity, _, _>(|fruit| {
+                    Expr::col((fruit, fruit::Column::Name)).like("%apple%")
+                })
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"WHERE EXISTS(SELECT 1 FROM "fruit" WHERE "cake"."id" = "fruit"."cake_id""#,
+                r#"AND "fruit"."name" LIKE '%apple%')"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn filter_has_related_self() {
+        assert_eq!(
+            category::Entity::find()
+                .filter_has_related_with::<category::Entity, _, _>(|parent| {
+                    Expr::col((parent, category::Column::Name)).eq("Cakes")
+                })
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "category"."id", "category"."parent_id", "category"."name" FROM "category""#,
+                r#"WHERE EXISTS(SELECT 1 FROM "category" AS "r0""#,
+                r#"WHERE "category"."parent_id" = "r0"."id" AND "r0"."name" = 'Cakes')"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn filter_has_related_self_update() {
+        // MySQL cannot read the table being updated in a subquery, but through a derived table
+        assert_eq!(
+            category::Entity::update_many()
+                .col_expr(category::Column::Name, Expr::value("Cakes"))
+                .filter_has_related_with::<category::Entity, _, _>(|parent| {
+                    Expr::col((parent, category::Column::Name)).eq("Pastries")
+                })
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "UPDATE `category` SET `name` = 'Cakes'",
+                "WHERE EXISTS(SELECT 1 FROM (SELECT * FROM `category`) AS `r0`",
+                "WHERE `category`.`parent_id` = `r0`.`id` AND `r0`.`name` = 'Pastries')",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            category::Entity::delete_many()
+                .filter_doesnt_have_related::<category::Entity>()
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "DELETE FROM `category`",
+                "WHERE NOT EXISTS(SELECT 1 FROM (SELECT * FROM `category`) AS `r0`",
+                "WHERE `category`.`parent_id` = `r0`.`id`)",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn filter_related_generic() {
+        fn with_fruits<Q>(query: Q) -> Q
+        where
+            Q: RelatedFilter<Entity = cake::Entity>,
+        {
+            query.filter_has_related::<fruit::Entity, _>(Expr::value(true))
+        }
+
+        assert_eq!(
+            with_fruits(cake::Entity::delete_many())
+                .filter(cake::Column::Id.gt(1))
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"DELETE FROM "cake""#,
+                r#"WHERE EXISTS(SELECT 1 FROM "fruit" WHERE "cake"."id" = "fruit"."cake_id" AND TRUE)"#,
+                r#"AND "cake"."id" > 1"#,
+            ]
+            .join(" ")
+        );
+    }
+}
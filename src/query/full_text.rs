@@ -0,0 +1,778 @@
+use crate::{ColumnTrait, DbBackend, DbErr, EntityName, EntityTrait, Iterable, Statement};
+use sea_query::{
+    Alias, BinOper, DynIden, Expr, Func, IntoTableRef, Query, SeaRc, SimpleExpr, TableRef, Value,
+};
+
+/// Full-text search over one or more text columns of an Entity, taking web search style queries:
+/// unquoted words must all match, `"quoted text"` matches a phrase, `or` between two words
+/// matches either of them and a leading `-` excludes a word or phrase.
+///
+/// | | Postgres | MySQL | SQLite |
+/// |---|---|---|---|
+/// | [`matches`](Self::matches) | `to_tsvector(..) @@ websearch_to_tsquery(..)` | `MATCH (..) AGAINST (.. IN BOOLEAN MODE)` | `MATCH` on an FTS5 table |
+/// | [`rank`](Self::rank) | `ts_rank` | `MATCH (..) AGAINST (..)` | `rank` of the FTS5 table |
+/// | [`create_index`](Self::create_index) | `GIN` expression index | `FULLTEXT` index | FTS5 table kept in sync by triggers |
+///
+/// The searched columns default to those marked `#[sea_orm(full_text)]`. The search
+/// needs the index from [`create_index`](Self::create_index) over the same columns on MySQL and
+/// SQLite; on Postgres it works without, but the index is only used if the columns and language agree.
+/// On SQLite the FTS5 table is named `{table}_fts`, in the schema of the Entity, and joined on `rowid`.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+///
+/// # fn main() -> Result<(), sea_orm::DbErr> {
+/// let search = FullTextSearch::new(cake::Entity).column(cake::Column::Name);
+///
+/// assert_eq!(
+///     cake::Entity::find()
+///         .filter(search.matches(DbBackend::Postgres, "cheese -blue")?)
+///         .order_by_desc(search.rank(DbBackend::Postgres, "cheese -blue")?)
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     [
+///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+///         r#"WHERE to_tsvector('english', "cake"."name") @@ websearch_to_tsquery('english', 'cheese -blue')"#,
+///         r#"ORDER BY ts_rank(to_tsvector('english', "cake"."name"), websearch_to_tsquery('english', 'cheese -blue')) DESC"#,
+///     ]
+///     .join(" ")
+/// );
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FullTextSearch {
+    schema: Option<DynIden>,
+    table: DynIden,
+    columns: Vec<DynIden>,
+    custom_columns: bool,
+    language: String,
+}
+
+impl FullTextSearch {
+    /// Search the columns of `entity` marked `#[sea_orm(full_text)]`.
+    ///
+    /// If there is none, the columns must be given with [`column`](Self::column),
+    /// otherwise searching and creating the index return an error.
+    pub fn new<E>(entity: E) -> Self
+    where
+        E: EntityTrait,
+    {
+        Self {
+            schema: schema_name(&entity),
+            table: SeaRc::new(entity),
+            columns: E::Column::iter()
+                .filter(|col| col.def().full_text)
+                .map(|col| SeaRc::new(col) as DynIden)
+                .collect(),
+            custom_columns: false,
+            language: "english".to_owned(),
+        }
+    }
+
+    pub(crate) fn of_column<C>(column: C) -> Self
+    where
+        C: ColumnTrait,
+    {
+        Self {
+            schema: schema_name(&C::EntityName::default()),
+            table: column.entity_name(),
+            columns: vec![SeaRc::new(column)],
+            custom_columns: true,
+            language: "english".to_owned(),
+        }
+    }
+
+    /// Search in `column` instead of the columns marked `#[sea_orm(full_text)]`.
+    /// Call repeatedly to search in several columns.
+    pub fn column<C>(mut self, column: C) -> Self
+    where
+        C: ColumnTrait,
+    {
+        if !self.custom_columns {
+            self.columns.clear();
+            self.custom_columns = true;
+        }
+        self.columns.push(SeaRc::new(column));
+        self
+    }
+
+    /// The Postgres text search configuration, `english` by default
+    pub fn language<T>(mut self, language: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.language = language.into();
+        self
+    }
+
+    /// Whether a row matches `query`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no column to search, see [`FullTextSearch::new`].
+    pub fn matches(&self, db_backend: DbBackend, query: &str) -> Result<SimpleExpr, DbErr> {
+        self.check_columns()?;
+        Ok(self.matches_expr(db_backend, query))
+    }
+
+    /// [`matches`](Self::matches), with at least one column to search
+    pub(crate) fn matches_expr(&self, db_backend: DbBackend, query: &str) -> SimpleExpr {
+        match db_backend {
+            DbBackend::Postgres => self
+                .pg_tsvector(true)
+                .binary(BinOper::Custom("@@"), self.pg_tsquery(query)),
+            DbBackend::MySql => match WebSearch::parse(query).to_mysql() {
+                Some(query) => self.mysql_match(query, true),
+                None => Expr::value(false),
+            },
+            DbBackend::Sqlite => match self.sqlite_query(query) {
+                Some(query) => Expr::col((self.table.clone(), Alias::new("rowid"))).in_subquery(
+                    Query::select()
+                        .column(Alias::new("rowid"))
+                        .from(self.qualified(self.fts_table()))
+                        .and_where(
+                            Expr::col(self.fts_table())
+                                .binary(BinOper::Custom("MATCH"), Expr::val(query)),
+                        )
+                        .to_owned(),
+                ),
+                None => Expr::value(false),
+            },
+        }
+    }
+
+    /// The relevance of a row for `query`, higher is more relevant.
+    /// Rows that do not match rank at zero or below any matching row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no column to search, see [`FullTextSearch::new`].
+    pub fn rank(&self, db_backend: DbBackend, query: &str) -> Result<SimpleExpr, DbErr> {
+        self.check_columns()?;
+        Ok(self.rank_expr(db_backend, query))
+    }
+
+    /// [`rank`](Self::rank), with at least one column to search
+    pub(crate) fn rank_expr(&self, db_backend: DbBackend, query: &str) -> SimpleExpr {
+        match db_backend {
+            DbBackend::Postgres => Func::cust(Alias::new("ts_rank"))
+                .args([self.pg_tsvector(true), self.pg_tsquery(query)])
+                .into(),
+            DbBackend::MySql => match WebSearch::parse(query).to_mysql_terms() {
+                Some(query) => self.mysql_match(query, false),
+                None => Expr::value(0),
+            },
+            DbBackend::Sqlite => match self.sqlite_query(query) {
+                Some(query) => Func::coalesce([
+                    SimpleExpr::SubQuery(
+                        None,
+                        Box::new(
+                            Query::select()
+                                .expr(Expr::col((self.fts_table(), Alias::new("rank"))).mul(-1))
+                                .from(self.qualified(self.fts_table()))
+                                .and_where(
+                                    Expr::col(self.fts_table())
+                                        .binary(BinOper::Custom("MATCH"), Expr::val(query)),
+                                )
+                                .and_where(
+                                    Expr::col((self.fts_table(), Alias::new("rowid")))
+                                        .equals((self.table.clone(), Alias::new("rowid"))),
+                                )
+                                .to_owned()
+                                .into_sub_query_statement(),
+                        ),
+                    ),
+                    Expr::val(0).into(),
+                ])
+                .into(),
+                None => Expr::value(0),
+            },
+        }
+    }
+
+    /// The statements creating the full-text index over the searched columns,
+    /// run them in order in a migration. Like other `CREATE INDEX` statements,
+    /// they fail if the index already exists; MySQL has no `IF NOT EXISTS` for indexes.
+    ///
+    /// ```
+    /// use sea_orm::{query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// # fn main() -> Result<(), sea_orm::DbErr> {
+    /// let search = FullTextSearch::new(cake::Entity).column(cake::Column::Name);
+    ///
+    /// assert_eq!(
+    ///     search.create_index(DbBackend::Postgres)?[0].to_string(),
+    ///     r#"CREATE INDEX "idx-cake-fts" ON "cake" USING GIN (to_tsvector('english', "name"))"#
+    /// );
+    /// assert_eq!(
+    ///     search.create_index(DbBackend::MySql)?[0].to_string(),
+    ///     "CREATE FULLTEXT INDEX `idx-cake-fts` ON `cake` (`name`)"
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no column to search, see [`FullTextSearch::new`].
+    pub fn create_index(&self, db_backend: DbBackend) -> Result<Vec<Statement>, DbErr> {
+        self.check_columns()?;
+        let table = self.table.to_string();
+        let schema = self
+            .schema
+            .as_ref()
+            .map(|schema| format!("{}.", quote(db_backend, &schema.to_string())))
+            .unwrap_or_default();
+        let qualified_table = format!("{schema}{}", quote(db_backend, &table));
+        let index = format!("idx-{table}-fts");
+        let stmts = match db_backend {
+            DbBackend::Postgres => {
+                let mut tsvector = String::new();
+                db_backend
+                    .get_query_builder()
+                    .prepare_simple_expr(&self.pg_tsvector(false), &mut tsvector);
+                vec![format!(
+                    "CREATE INDEX {} ON {qualified_table} USING GIN ({tsvector})",
+                    quote(db_backend, &index),
+                )]
+            }
+            DbBackend::MySql => vec![format!(
+                "CREATE FULLTEXT INDEX {} ON {qualified_table} ({})",
+                quote(db_backend, &index),
+                self.quoted_columns(db_backend, "").join(", "),
+            )],
+            DbBackend::Sqlite => {
+                // the tables of a trigger are in its schema, and unqualified in its statements
+                let fts = quote(db_backend, &format!("{table}_fts"));
+                let trigger = |suffix: &str| {
+                    schema.clone() + &quote(db_backend, &format!("{table}_fts_{suffix}"))
+                };
+                let table = quote(db_backend, &table);
+                let columns = self.quoted_columns(db_backend, "").join(", ");
+                let new = self.quoted_columns(db_backend, "new.").join(", ");
+                let old = self.quoted_columns(db_backend, "old.").join(", ");
+                let insert =
+                    format!("INSERT INTO {fts}(rowid, {columns}) VALUES (new.rowid, {new});");
+                let delete = format!(
+                    "INSERT INTO {fts}({fts}, rowid, {columns}) VALUES ('delete', old.rowid, {old});"
+                );
+                vec![
+                    format!(
+                        "CREATE VIRTUAL TABLE {schema}{fts} USING fts5({columns}, content={})",
+                        quote_str(&self.table.to_string()),
+                    ),
+                    format!(
+                        "CREATE TRIGGER {} AFTER INSERT ON {table} BEGIN {insert} END",
+                        trigger("ai"),
+                    ),
+                    format!(
+                        "CREATE TRIGGER {} AFTER DELETE ON {table} BEGIN {delete} END",
+                        trigger("ad"),
+                    ),
+                    format!(
+                        "CREATE TRIGGER {} AFTER UPDATE ON {table} BEGIN {delete} {insert} END",
+                        trigger("au"),
+                    ),
+                    format!("INSERT INTO {schema}{fts}({fts}) VALUES ('rebuild')"),
+                ]
+            }
+        };
+        Ok(stmts
+            .into_iter()
+            .map(|stmt| Statement::from_string(db_backend, stmt))
+            .collect())
+    }
+
+    fn column_exprs(&self, qualified: bool) -> Vec<SimpleExpr> {
+        self.columns
+            .iter()
+            .map(|col| match qualified {
+                true => Expr::col((self.table.clone(), col.clone())).into(),
+                false => Expr::col(col.clone()).into(),
+            })
+            .collect()
+    }
+
+    fn quoted_columns(&self, db_backend: DbBackend, prefix: &str) -> Vec<String> {
+        self.columns
+            .iter()
+            .map(|col| format!("{prefix}{}", quote(db_backend, &col.to_string())))
+            .collect()
+    }
+
+    /// The language is inlined, an index expression only matches a query with the same constants
+    fn pg_language(&self) -> SimpleExpr {
+        SimpleExpr::Constant(Value::from(self.language.as_str()))
+    }
+
+    fn pg_tsvector(&self, qualified: bool) -> SimpleExpr {
+        let mut columns = self.column_exprs(qualified);
+        let document = if columns.len() == 1 {
+            columns.remove(0)
+        } else {
+            columns
+                .into_iter()
+                .map(|col| -> SimpleExpr {
+                    Func::coalesce([col, SimpleExpr::Constant(Value::from(""))]).into()
+                })
+                .reduce(|doc, col| {
+                    doc.binary(
+                        BinOper::Custom("||"),
+                        SimpleExpr::Constant(Value::from(" ")),
+                    )
+                    .binary(BinOper::Custom("||"), col)
+                })
+                .unwrap_or_else(|| SimpleExpr::Constant(Value::from("")))
+        };
+        Func::cust(Alias::new("to_tsvector"))
+            .args([self.pg_language(), document])
+            .into()
+    }
+
+    fn pg_tsquery(&self, query: &str) -> SimpleExpr {
+        Func::cust(Alias::new("websearch_to_tsquery"))
+            .args([self.pg_language(), Expr::val(query).into()])
+            .into()
+    }
+
+    fn mysql_match(&self, query: String, boolean_mode: bool) -> SimpleExpr {
+        let placeholders = vec!["?"; self.columns.len()].join(", ");
+        let mode = if boolean_mode { " IN BOOLEAN MODE" } else { "" };
+        let mut exprs = self.column_exprs(true);
+        exprs.push(Expr::val(query).into());
+        Expr::cust_with_exprs(format!("MATCH ({placeholders}) AGAINST (?{mode})"), exprs)
+    }
+
+    fn check_columns(&self) -> Result<(), DbErr> {
+        if self.columns.is_empty() {
+            return Err(DbErr::Custom(format!(
+                "FullTextSearch: `{}` has no column marked `#[sea_orm(full_text)]`, give the columns to search with `column`",
+                self.table.to_string()
+            )));
+        }
+        Ok(())
+    }
+
+    /// The table in the schema of the Entity
+    fn qualified(&self, table: Alias) -> TableRef {
+        match &self.schema {
+            Some(schema) => (schema.clone(), table).into_table_ref(),
+            None => table.into_table_ref(),
+        }
+    }
+
+    fn fts_table(&self) -> Alias {
+        Alias::new(format!("{}_fts", self.table.to_string()))
+    }
+
+    /// The query restricted to the searched columns with an FTS5 column filter
+    fn sqlite_query(&self, query: &str) -> Option<String> {
+        let query = WebSearch::parse(query).to_fts5()?;
+        let columns: Vec<String> = self
+            .columns
+            .iter()
+            .map(|col| format!("\"{}\"", col.to_string().replace('"', "\"\"")))
+            .collect();
+        Some(format!("{{{}}} : ({query})", columns.join(" ")))
+    }
+}
+
+fn schema_name<E>(entity: &E) -> Option<DynIden>
+where
+    E: EntityName,
+{
+    entity
+        .schema_name()
+        .map(|schema| SeaRc::new(Alias::new(schema)) as DynIden)
+}
+
+fn quote(db_backend: DbBackend, iden: &str) -> String {
+    let q = match db_backend {
+        DbBackend::MySql => '`',
+        DbBackend::Postgres | DbBackend::Sqlite => '"',
+    };
+    format!("{q}{}{q}", iden.replace(q, &format!("{q}{q}")))
+}
+
+fn quote_str(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+/// A web search style query: every group must match one of its terms, no excluded term may match.
+/// Terms are reduced to words separated by single spaces, so they can be quoted as phrases.
+#[derive(Debug, Default, PartialEq)]
+struct WebSearch {
+    groups: Vec<Vec<String>>,
+    excluded: Vec<String>,
+}
+
+impl WebSearch {
+    fn parse(query: &str) -> Self {
+        let mut search = Self::default();
+        let mut chars = query.chars().peekable();
+        let mut or = false;
+        while let Some(c) = chars.next() {
+            if c.is_whitespace() {
+                continue;
+            }
+            let negated = c == '-';
+            let quoted = if negated {
+                chars.next_if_eq(&'"').is_some()
+            } else {
+                c == '"'
+            };
+            let mut term = String::new();
+            if !negated && !quoted {
+                term.push(c);
+            }
+            while let Some(c) =
+                chars.next_if(|&c| if quoted { c != '"' } else { !c.is_whitespace() })
+            {
+                term.push(c);
+            }
+            if quoted {
+                chars.next();
+            }
+            if !quoted && !negated && term.eq_ignore_ascii_case("or") {
+                or = !search.groups.is_empty();
+                continue;
+            }
+            let term = term
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .filter(|word| !word.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ");
+            if term.is_empty() {
+                continue;
+            }
+            if negated {
+                search.excluded.push(term);
+            } else {
+                match search.groups.last_mut() {
+                    Some(group) if or => group.push(term),
+                    _ => search.groups.push(vec![term]),
+                }
+            }
+            or = false;
+        }
+        search
+    }
+
+    /// `+"a" +("b" "c") -"d"`, `None` if nothing is required
+    fn to_mysql(&self) -> Option<String> {
+        if self.groups.is_empty() {
+            return None;
+        }
+        let required = self.groups.iter().map(|group| match group.as_slice() {
+            [term] => format!("+\"{term}\""),
+            terms => format!(
+                "+({})",
+                terms
+                    .iter()
+                    .map(|t| format!("\"{t}\""))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ),
+        });
+        let excluded = self.excluded.iter().map(|term| format!("-\"{term}\""));
+        Some(required.chain(excluded).collect::<Vec<_>>().join(" "))
+    }
+
+    /// `a b c` for natural language mode, which has no operators
+    fn to_mysql_terms(&self) -> Option<String> {
+        if self.groups.is_empty() {
+            return None;
+        }
+        Some(self.groups.concat().join(" "))
+    }
+
+    /// `("a" AND ("b" OR "c")) NOT "d"`, or without parentheses if nothing is excluded, `None` if nothing is required
+    fn to_fts5(&self) -> Option<String> {
+        if self.groups.is_empty() {
+            return None;
+        }
+        let required = self
+            .groups
+            .iter()
+            .map(|group| match group.as_slice() {
+                [term] => format!("\"{term}\""),
+                terms => format!(
+                    "({})",
+                    terms
+                        .iter()
+                        .map(|t| format!("\"{t}\""))
+                        .collect::<Vec<_>>()
+                        .join(" OR ")
+                ),
+            })
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        if self.excluded.is_empty() {
+            return Some(required);
+        }
+        let excluded: String = self
+            .excluded
+            .iter()
+            .map(|term| format!(" NOT \"{term}\""))
+            .collect();
+        Some(format!("({required}){excluded}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebSearch;
+    use crate::tests_cfg::cake;
+    use crate::{
+        entity::prelude::*, DbBackend, FullTextSearch, QueryFilter, QueryOrder, QueryTrait,
+    };
+    use pretty_assertions::assert_eq;
+
+    mod post {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "post")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            #[sea_orm(full_text)]
+            pub title: String,
+            #[sea_orm(full_text)]
+            pub body: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    mod note {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(schema_name = "blog", table_name = "note")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            #[sea_orm(full_text)]
+            pub text: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn web_search() {
+        let search = WebSearch::parse(r#"apple or "cheese cake" -blue e-mail -"no way" or"#);
+        assert_eq!(
+            search,
+            WebSearch {
+                groups: vec![
+                    vec!["apple".to_owned(), "cheese cake".to_owned()],
+                    vec!["e mail".to_owned()],
+                ],
+                excluded: vec!["blue".to_owned(), "no way".to_owned()],
+            }
+        );
+        assert_eq!(
+            search.to_mysql().as_deref(),
+            Some(r#"+("apple" "cheese cake") +"e mail" -"blue" -"no way""#)
+        );
+        assert_eq!(
+            search.to_fts5().as_deref(),
+            Some(r#"(("apple" OR "cheese cake") AND "e mail") NOT "blue" NOT "no way""#)
+        );
+        assert_eq!(WebSearch::parse("-blue").to_fts5(), None);
+    }
+
+    #[test]
+    fn search_entity() {
+        let query = |db: DbBackend| {
+            let search = FullTextSearch::new(post::Entity);
+            post::Entity::find()
+                .filter(search.matches(db, "rust -java").unwrap())
+                .order_by_desc(search.rank(db, "rust -java").unwrap())
+                .build(db)
+                .to_string()
+        };
+
+        assert_eq!(
+            query(DbBackend::Postgres),
+            [
+                r#"SELECT "post"."id", "post"."title", "post"."body" FROM "post""#,
+                r#"WHERE to_tsvector('english', (COALESCE("post"."title", '') || ' ') || COALESCE("post"."body", ''))"#,
+                r#"@@ websearch_to_tsquery('english', 'rust -java')"#,
+                r#"ORDER BY ts_rank(to_tsvector('english', (COALESCE("post"."title", '') || ' ') || COALESCE("post"."body", '')),"#,
+                r#"websearch_to_tsquery('english', 'rust -java')) DESC"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            query(DbBackend::MySql),
+            [
+                r#"SELECT `post`.`id`, `post`.`title`, `post`.`body` FROM `post`"#,
+                r#"WHERE MATCH (`post`.`title`, `post`.`body`) AGAINST ('+\"rust\" -\"java\"' IN BOOLEAN MODE)"#,
+                r#"ORDER BY MATCH (`post`.`title`, `post`.`body`) AGAINST ('rust') DESC"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            query(DbBackend::Sqlite),
+            [
+                r#"SELECT "post"."id", "post"."title", "post"."body" FROM "post""#,
+                r#"WHERE "post"."rowid" IN (SELECT "rowid" FROM "post_fts" WHERE "post_fts" MATCH '{"title" "body"} : (("rust") NOT "java")')"#,
+                r#"ORDER BY COALESCE((SELECT "post_fts"."rank" * -1 FROM "post_fts" WHERE ("post_fts" MATCH '{"title" "body"} : (("rust") NOT "java")')"#,
+                r#"AND "post_fts"."rowid" = "post"."rowid"), 0) DESC"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn search_column() {
+        assert_eq!(
+            post::Entity::find()
+                .filter(post::Column::Title.matches_text(DbBackend::Sqlite, "rust"))
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"SELECT "post"."id", "post"."title", "post"."body" FROM "post""#,
+                r#"WHERE "post"."rowid" IN (SELECT "rowid" FROM "post_fts" WHERE "post_fts" MATCH '{"title"} : ("rust")')"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            post::Entity::find()
+                .filter(post::Column::Title.matches_text(DbBackend::MySql, ""))
+                .build(DbBackend::MySql)
+                .to_string(),
+            "SELECT `post`.`id`, `post`.`title`, `post`.`body` FROM `post` WHERE FALSE"
+        );
+    }
+
+    #[test]
+    fn create_index() {
+        let stmts = |db: DbBackend| -> Vec<String> {
+            FullTextSearch::new(post::Entity)
+                .create_index(db)
+                .unwrap()
+                .into_iter()
+                .map(|stmt| stmt.to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            stmts(DbBackend::Postgres),
+            [concat!(
+                r#"CREATE INDEX "idx-post-fts" ON "post" USING GIN "#,
+                r#"(to_tsvector('english', (COALESCE("title", '') || ' ') || COALESCE("body", '')))"#
+            )]
+        );
+        assert_eq!(
+            stmts(DbBackend::MySql),
+            ["CREATE FULLTEXT INDEX `idx-post-fts` ON `post` (`title`, `body`)"]
+        );
+        assert_eq!(
+            stmts(DbBackend::Sqlite),
+            [
+                r#"CREATE VIRTUAL TABLE "post_fts" USING fts5("title", "body", content='post')"#,
+                concat!(
+                    r#"CREATE TRIGGER "post_fts_ai" AFTER INSERT ON "post" BEGIN "#,
+                    r#"INSERT INTO "post_fts"(rowid, "title", "body") VALUES (new.rowid, new."title", new."body"); END"#
+                ),
+                concat!(
+                    r#"CREATE TRIGGER "post_fts_ad" AFTER DELETE ON "post" BEGIN "#,
+                    r#"INSERT INTO "post_fts"("post_fts", rowid, "title", "body") VALUES ('delete', old.rowid, old."title", old."body"); END"#
+                ),
+                concat!(
+                    r#"CREATE TRIGGER "post_fts_au" AFTER UPDATE ON "post" BEGIN "#,
+                    r#"INSERT INTO "post_fts"("post_fts", rowid, "title", "body") VALUES ('delete', old.rowid, old."title", old."body"); "#,
+                    r#"INSERT INTO "post_fts"(rowid, "title", "body") VALUES (new.rowid, new."title", new."body"); END"#
+                ),
+                r#"INSERT INTO "post_fts"("post_fts") VALUES ('rebuild')"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn create_index_schema() {
+        let stmts = |db: DbBackend| -> Vec<String> {
+            FullTextSearch::new(note::Entity)
+                .create_index(db)
+                .unwrap()
+                .into_iter()
+                .map(|stmt| stmt.to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            stmts(DbBackend::Postgres),
+            [
+                r#"CREATE INDEX "idx-note-fts" ON "blog"."note" USING GIN (to_tsvector('english', "text"))"#
+            ]
+        );
+        assert_eq!(
+            stmts(DbBackend::MySql),
+            ["CREATE FULLTEXT INDEX `idx-note-fts` ON `blog`.`note` (`text`)"]
+        );
+        assert_eq!(
+            stmts(DbBackend::Sqlite),
+            [
+                r#"CREATE VIRTUAL TABLE "blog"."note_fts" USING fts5("text", content='note')"#,
+                concat!(
+                    r#"CREATE TRIGGER "blog"."note_fts_ai" AFTER INSERT ON "note" BEGIN "#,
+                    r#"INSERT INTO "note_fts"(rowid, "text") VALUES (new.rowid, new."text"); END"#
+                ),
+                concat!(
+                    r#"CREATE TRIGGER "blog"."note_fts_ad" AFTER DELETE ON "note" BEGIN "#,
+                    r#"INSERT INTO "note_fts"("note_fts", rowid, "text") VALUES ('delete', old.rowid, old."text"); END"#
+                ),
+                concat!(
+                    r#"CREATE TRIGGER "blog"."note_fts_au" AFTER UPDATE ON "note" BEGIN "#,
+                    r#"INSERT INTO "note_fts"("note_fts", rowid, "text") VALUES ('delete', old.rowid, old."text"); "#,
+                    r#"INSERT INTO "note_fts"(rowid, "text") VALUES (new.rowid, new."text"); END"#
+                ),
+                r#"INSERT INTO "blog"."note_fts"("note_fts") VALUES ('rebuild')"#,
+            ]
+        );
+
+        assert_eq!(
+            note::Entity::find()
+                .filter(note::Column::Text.matches_text(DbBackend::Sqlite, "rust"))
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"SELECT "note"."id", "note"."text" FROM "blog"."note""#,
+                r#"WHERE "note"."rowid" IN (SELECT "rowid" FROM "blog"."note_fts" WHERE "note_fts" MATCH '{"text"} : ("rust")')"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn search_no_columns() {
+        let search = FullTextSearch::new(cake::Entity);
+        let err = || {
+            DbErr::Custom(
+                "FullTextSearch: `cake` has no column marked `#[sea_orm(full_text)]`, give the columns to search with `column`"
+                    .to_owned(),
+            )
+        };
+        assert_eq!(search.matches(DbBackend::Postgres, "cheese"), Err(err()));
+        assert_eq!(search.rank(DbBackend::MySql, "cheese"), Err(err()));
+        assert_eq!(search.create_index(DbBackend::Sqlite), Err(err()));
+
+        let search = search.column(cake::Column::Name);
+        assert!(search.matches(DbBackend::Postgres, "cheese").is_ok());
+    }
+}
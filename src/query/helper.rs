@@ -4,9 +4,12 @@ use crate::{
 };
 use sea_query::{
     Alias, Expr, Iden, IntoCondition, IntoIden, LockBehavior, LockType, NullOrdering, SeaRc,
-    SelectExpr, SelectStatement, SimpleExpr, TableRef,
+    SelectExpr, SelectStatement, SimpleExpr, TableRef, WindowStatement,
+};
+pub use sea_query::{
+    CommonTableExpression, Condition, ConditionalStatement, DynIden, JoinType, Order,
+    OrderedStatement, WithClause,
 };
-pub use sea_query::{Condition, ConditionalStatement, DynIden, JoinType, Order, OrderedStatement};
 
 use sea_query::IntoColumnRef;
 
@@ -552,6 +555,83 @@ pub trait QuerySelect: Sized {
         self
     }
 
+    /// Select a window function call over a window, e.g. `ROW_NUMBER() OVER (...)`.
+    /// The window can be a [`Window`](crate::Window) or a [`WindowStatement`].
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .expr_window_as(
+    ///             WindowFunc::rank(),
+    ///             Window::new().order_by(cake::Column::Name, Order::Desc),
+    ///             "rank"
+    ///         )
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name", RANK() OVER (  ORDER BY "cake"."name" DESC ) AS "rank" FROM "cake""#
+    /// );
+    /// ```
+    fn expr_window_as<T, W, A>(mut self, expr: T, window: W, alias: A) -> Self
+    where
+        T: Into<SimpleExpr>,
+        W: Into<WindowStatement>,
+        A: IntoIdentity,
+    {
+        self.query()
+            .expr_window_as(expr, window.into(), alias.into_identity());
+        self
+    }
+
+    /// Select a window function call over a named window defined with [`QuerySelect::window`].
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Func, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .select_only()
+    ///         .column(fruit::Column::Id)
+    ///         .expr_window_name_as(WindowFunc::row_number(), "w", "row_num")
+    ///         .expr_window_name_as(Func::count(fruit::Column::Id.into_expr()), "w", "num_fruits")
+    ///         .window(
+    ///             "w",
+    ///             Window::new()
+    ///                 .partition_by(fruit::Column::CakeId)
+    ///                 .order_by(fruit::Column::Id, Order::Asc)
+    ///         )
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         "SELECT `fruit`.`id`, ROW_NUMBER() OVER `w` AS `row_num`, COUNT(`fruit`.`id`) OVER `w` AS `num_fruits`",
+    ///         "FROM `fruit` WINDOW `w` AS (PARTITION BY `fruit`.`cake_id` ORDER BY `fruit`.`id` ASC)",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn expr_window_name_as<T, N, A>(mut self, expr: T, window: N, alias: A) -> Self
+    where
+        T: Into<SimpleExpr>,
+        N: IntoIdentity,
+        A: IntoIdentity,
+    {
+        self.query()
+            .expr_window_name_as(expr, window.into_identity(), alias.into_identity());
+        self
+    }
+
+    /// Define a named window in the `WINDOW` clause.
+    /// Only one named window is supported, defining another replaces the previous one.
+    fn window<N, W>(mut self, name: N, window: W) -> Self
+    where
+        N: IntoIdentity,
+        W: Into<WindowStatement>,
+    {
+        self.query().window(name.into_identity(), window.into());
+        self
+    }
+
     /// Shorthand of `expr_as(Expr::col((T, C)), A)`.
     ///
     /// ```
@@ -577,6 +657,103 @@ pub trait QuerySelect: Sized {
             .expr_as(Expr::col((tbl, col)), alias.into_identity());
         self
     }
+
+    /// Attach a `WITH` clause to the query, replacing any existing one.
+    /// Multiple CTEs can be attached at once by passing a [WithClause].
+    ///
+    /// ```
+    /// use sea_orm::sea_query::{CommonTableExpression, Expr, Query};
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// let fruit_count = CommonTableExpression::new()
+    ///     .query(
+    ///         fruit::Entity::find()
+    ///             .select_only()
+    ///             .column(fruit::Column::CakeId)
+    ///             .column_as(fruit::Column::Id.count(), "num_fruits")
+    ///             .group_by(fruit::Column::CakeId)
+    ///             .into_query(),
+    ///     )
+    ///     .table_name("fruit_count")
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .with_cte(fruit_count)
+    ///         .filter(
+    ///             cake::Column::Id.in_subquery(
+    ///                 Query::select()
+    ///                     .column("cake_id")
+    ///                     .from("fruit_count")
+    ///                     .and_where(Expr::col("num_fruits").gt(1))
+    ///                     .to_owned()
+    ///             )
+    ///         )
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"WITH "fruit_count" AS (SELECT "fruit"."cake_id", COUNT("fruit"."id") AS "num_fruits" FROM "fruit" GROUP BY "fruit"."cake_id")"#,
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+    ///         r#"WHERE "cake"."id" IN (SELECT "cake_id" FROM "fruit_count" WHERE "num_fruits" > 1)"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn with_cte<C>(mut self, clause: C) -> Self
+    where
+        C: Into<WithClause>,
+    {
+        self.query().with_cte(clause);
+        self
+    }
+
+    /// Attach a `WITH RECURSIVE` clause to the query, replacing any existing one.
+    ///
+    /// ```
+    /// use sea_orm::sea_query::{CommonTableExpression, Expr, Query, UnionType};
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let ids = CommonTableExpression::new()
+    ///     .query(
+    ///         Query::select()
+    ///             .expr(Expr::val(1))
+    ///             .union(
+    ///                 UnionType::All,
+    ///                 Query::select()
+    ///                     .expr(Expr::col("n").add(1))
+    ///                     .from("ids")
+    ///                     .and_where(Expr::col("n").lt(3))
+    ///                     .to_owned(),
+    ///             )
+    ///             .to_owned(),
+    ///     )
+    ///     .column("n")
+    ///     .table_name("ids")
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .with_recursive(ids)
+    ///         .filter(cake::Column::Id.in_subquery(Query::select().column("n").from("ids").to_owned()))
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     [
+    ///         r#"WITH RECURSIVE "ids" ("n") AS (SELECT 1 UNION ALL SELECT "n" + 1 FROM "ids" WHERE "n" < 3)"#,
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+    ///         r#"WHERE "cake"."id" IN (SELECT "n" FROM "ids")"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn with_recursive<C>(mut self, clause: C) -> Self
+    where
+        C: Into<WithClause>,
+    {
+        let mut clause = clause.into();
+        clause.recursive(true);
+        self.query().with_cte(clause);
+        self
+    }
 }
 
 // LINT: when the column does not appear in tables selected from
@@ -902,3 +1079,21 @@ pub(crate) fn unpack_table_alias(table_ref: &TableRef) -> Option<DynIden> {
         | TableRef::FunctionCall(_, alias) => Some(SeaRc::clone(alias)),
     }
 }
+
+/// `SELECT <expr> FROM <the joined tables> WHERE <the join conditions> AND <condition>`,
+/// correlated with the UPDATE or DELETE statement the tables are joined to
+pub(crate) fn joined_query(
+    joins: &[(TableRef, Condition)],
+    condition: Condition,
+    expr: SimpleExpr,
+) -> SelectStatement {
+    let mut query = SelectStatement::new();
+    query.expr(expr);
+    for (tbl, on) in joins {
+        query.from(tbl.clone()).cond_where(on.clone());
+    }
+    if !condition.is_empty() {
+        query.cond_where(condition);
+    }
+    query
+}
@@ -1,9 +1,11 @@
 use crate::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityName, EntityTrait, IntoActiveModel, Iterable,
-    PrimaryKeyTrait, QueryTrait,
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbBackend, DbErr, EntityName, EntityTrait,
+    IdenStatic, IntoActiveModel, Iterable, PrimaryKeyTrait, QueryTrait, Select, Statement,
 };
 use core::marker::PhantomData;
-use sea_query::{Expr, InsertStatement, Keyword, OnConflict, SimpleExpr, Value, ValueTuple};
+use sea_query::{
+    ColumnType, Expr, InsertStatement, Keyword, OnConflict, SimpleExpr, Value, ValueTuple,
+};
 
 /// Performs INSERT operations on a ActiveModel
 #[derive(Debug)]
@@ -11,9 +13,13 @@ pub struct Insert<A>
 where
     A: ActiveModelTrait,
 {
+    /// The statement without the rows kept in `rows`
     pub(crate) query: InsertStatement,
     pub(crate) columns: Vec<bool>,
     pub(crate) primary_key: Option<ValueTuple>,
+    /// The rows, kept apart from the statement so that they can be split up by the bind
+    /// parameter limit on execution
+    pub(crate) rows: Vec<Vec<SimpleExpr>>,
     pub(crate) model: PhantomData<A>,
 }
 
@@ -38,6 +44,7 @@ where
                 .to_owned(),
             columns: Vec::new(),
             primary_key: None,
+            rows: Vec::new(),
             model: PhantomData,
         }
     }
@@ -81,6 +88,16 @@ where
 
     /// Insert many Model or ActiveModel
     ///
+    /// On execution, the rows are split up into as many statements as needed to stay
+    /// within the bind parameter limit of the backend (see [`DbBackend::max_bind_params`](crate::DbBackend::max_bind_params)).
+    /// Use [`Insert::exec_in_transaction`] to insert all of them or none.
+    ///
+    /// The rows are kept apart from the statement until it is built or executed, so that
+    /// the changes made through [`QueryTrait::query`], like `ON CONFLICT` or `RETURNING`,
+    /// apply to every statement. Values added to the statement directly through
+    /// [`QueryTrait::query`] are not split up, but repeated in every statement;
+    /// add the rows with [`Insert::add`] or [`Insert::add_many`] instead.
+    ///
     /// ```
     /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
     ///
@@ -108,6 +125,88 @@ where
         Self::new().add_many(models)
     }
 
+    /// Insert the rows of a Select, with `INSERT ... SELECT`.
+    ///
+    /// Each pair of `columns` maps a column of the Select onto a column of this Entity.
+    /// The Select keeps its joins, filters and order, while its selected columns are replaced.
+    /// Returns an error if no pair of columns is given, or if the types of any pair differ.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, vendor}, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     Insert::<vendor::ActiveModel>::from_select(
+    ///         cake::Entity::find().filter(cake::Column::Name.contains("Cheese")),
+    ///         [
+    ///             (vendor::Column::Id, cake::Column::Id),
+    ///             (vendor::Column::Name, cake::Column::Name),
+    ///         ],
+    ///     )?
+    ///     .on_conflict_do_nothing()
+    ///     .build(DbBackend::Postgres)
+    ///     .to_string(),
+    ///     [
+    ///         r#"INSERT INTO "vendor" ("id", "name")"#,
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE '%Cheese%'"#,
+    ///         r#"ON CONFLICT ("id") DO NOTHING"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    ///
+    /// assert!(Insert::<vendor::ActiveModel>::from_select(
+    ///     cake::Entity::find(),
+    ///     [(vendor::Column::Name, cake::Column::Id)],
+    /// )
+    /// .is_err());
+    ///
+    /// assert!(Insert::<vendor::ActiveModel>::from_select::<_, cake::Column, _>(
+    ///     cake::Entity::find(),
+    ///     [],
+    /// )
+    /// .is_err());
+    /// # Ok::<(), sea_orm::DbErr>(())
+    /// ```
+    pub fn from_select<E, C, I>(select: Select<E>, columns: I) -> Result<Self, DbErr>
+    where
+        E: EntityTrait,
+        C: ColumnTrait,
+        I: IntoIterator<Item = (<A::Entity as EntityTrait>::Column, C)>,
+    {
+        let mut this = Self::new();
+        let mut query = select.query;
+        query.clear_selects();
+        let mut targets = Vec::new();
+        for (target, source) in columns {
+            let (target_type, source_type) = (
+                target.def().get_column_type().clone(),
+                source.def().get_column_type().clone(),
+            );
+            if !same_column_type(&target_type, &source_type) {
+                return Err(DbErr::Type(format!(
+                    "Cannot insert `{}` of type {source_type:?} into `{}` of type {target_type:?}",
+                    source.as_str(),
+                    target.as_str()
+                )));
+            }
+            query.expr(Expr::col((source.entity_name(), source)));
+            targets.push(target);
+        }
+        if targets.is_empty() {
+            return Err(DbErr::Custom(
+                "Cannot insert from a Select without any column".to_owned(),
+            ));
+        }
+
+        this.columns = <A::Entity as EntityTrait>::Column::iter()
+            .map(|col| targets.iter().any(|target| target.as_str() == col.as_str()))
+            .collect();
+        this.query.columns(targets);
+        this.query
+            .select_from(query)
+            .map_err(|err| DbErr::Custom(err.to_string()))?;
+        Ok(this)
+    }
+
     /// Add a Model to Self
     ///
     /// # Panics
@@ -145,7 +244,7 @@ where
             }
         }
         self.query.columns(columns);
-        self.query.values_panic(values);
+        self.push_row(values);
         self
     }
 
@@ -216,9 +315,10 @@ where
         }
 
         for values in all_values {
-            // since we've aligned the column set, this never panics
-            self.query
-                .values_panic(values.into_iter().enumerate().filter_map(|(i, v)| {
+            let values: Vec<_> = values
+                .into_iter()
+                .enumerate()
+                .filter_map(|(i, v)| {
                     if columns[i].is_some() {
                         // only if the column is used
                         if !matches!(v, SimpleExpr::Keyword(Keyword::Null)) {
@@ -231,7 +331,10 @@ where
                     } else {
                         None
                     }
-                }));
+                })
+                .collect();
+            // since we've aligned the column set, this never panics
+            self.push_row(values);
         }
 
         self
@@ -333,18 +436,28 @@ where
     ///     r#"INSERT INTO "cake" ("id", "name") VALUES (2, 'Orange') ON CONFLICT ("id") DO NOTHING"#,
     /// );
     /// ```
-    pub fn on_conflict_do_nothing(mut self) -> TryInsert<A>
+    pub fn on_conflict_do_nothing(self) -> TryInsert<A>
     where
         A: ActiveModelTrait,
     {
         let primary_keys = <A::Entity as EntityTrait>::PrimaryKey::iter();
-        self.query.on_conflict(
-            OnConflict::columns(primary_keys.clone())
-                .do_nothing_on(primary_keys)
-                .to_owned(),
-        );
+        TryInsert::from_insert(
+            self.on_conflict(
+                OnConflict::columns(primary_keys.clone())
+                    .do_nothing_on(primary_keys)
+                    .to_owned(),
+            ),
+        )
+    }
 
-        TryInsert::from_insert(self)
+    /// The statement to execute along with the rows to add to it, split up by the bind parameter
+    /// limit
+    pub(crate) fn into_statement_and_rows(self) -> (InsertStatement, Vec<Vec<SimpleExpr>>) {
+        (self.query, self.rows)
+    }
+
+    fn push_row(&mut self, values: Vec<SimpleExpr>) {
+        self.rows.push(values);
     }
 }
 
@@ -358,12 +471,26 @@ where
         &mut self.query
     }
 
+    /// The statement without the rows, which are only added by [`QueryTrait::into_query`],
+    /// [`QueryTrait::build`] and on execution
     fn as_query(&self) -> &InsertStatement {
         &self.query
     }
 
     fn into_query(self) -> InsertStatement {
-        self.query
+        let (mut query, rows) = self.into_statement_and_rows();
+        if !rows.is_empty() {
+            query.values_from_panic(rows);
+        }
+        query
+    }
+
+    fn build(&self, db_backend: DbBackend) -> Statement {
+        let mut query = self.query.clone();
+        if !self.rows.is_empty() {
+            query.values_from_panic(self.rows.iter().cloned());
+        }
+        db_backend.build(&query)
     }
 }
 
@@ -432,7 +559,7 @@ where
     }
 
     pub fn on_conflict(mut self, on_conflict: OnConflict) -> Self {
-        self.insert_struct.query.on_conflict(on_conflict);
+        self.insert_struct = self.insert_struct.on_conflict(on_conflict);
         self
     }
 
@@ -451,17 +578,34 @@ where
     type QueryStatement = InsertStatement;
 
     fn query(&mut self) -> &mut InsertStatement {
-        &mut self.insert_struct.query
+        self.insert_struct.query()
     }
 
     fn as_query(&self) -> &InsertStatement {
-        &self.insert_struct.query
+        self.insert_struct.as_query()
     }
 
     fn into_query(self) -> InsertStatement {
-        self.insert_struct.query
+        self.insert_struct.into_query()
+    }
+
+    fn build(&self, db_backend: DbBackend) -> Statement {
+        self.insert_struct.build(db_backend)
     }
 }
+
+/// Whether two column types are the same, regardless of their length or precision
+fn same_column_type(a: &ColumnType, b: &ColumnType) -> bool {
+    match (a, b) {
+        (ColumnType::Custom(a), ColumnType::Custom(b)) => a.to_string() == b.to_string(),
+        (ColumnType::Enum { name: a, .. }, ColumnType::Enum { name: b, .. }) => {
+            a.to_string() == b.to_string()
+        }
+        (ColumnType::Array(a), ColumnType::Array(b)) => same_column_type(a, b),
+        _ => std::mem::discriminant(a) == std::mem::discriminant(b),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use sea_query::OnConflict;
@@ -469,7 +613,7 @@ mod tests {
     use crate::tests_cfg::{cake, cake_filling};
     use crate::{
         ActiveValue, DbBackend, DbErr, EntityTrait, Insert, IntoActiveModel, NotSet, QueryTrait,
-        Set,
+        Set, TryInsertResult,
     };
 
     #[test]
@@ -735,4 +879,231 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn insert_many_chunks() -> Result<(), DbErr> {
+        use crate::{DbBackend, MockDatabase, MockExecResult};
+
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 32766,
+                    rows_affected: 32766,
+                },
+                MockExecResult {
+                    last_insert_id: 40000,
+                    rows_affected: 7234,
+                },
+            ])
+            .into_connection();
+
+        let rows_affected = cake::Entity::insert_many((1..=40000).map(|i| cake::ActiveModel {
+            id: NotSet,
+            name: Set(format!("cake {i}")),
+        }))
+        .exec_without_returning(&db)
+        .await?;
+
+        assert_eq!(rows_affected, 40000);
+
+        // one bind parameter per row, split at the limit of SQLite
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 2);
+        let values: Vec<_> = log
+            .iter()
+            .map(|txn| txn.statements()[0].values.as_ref().map(|v| v.0.len()))
+            .collect();
+        assert_eq!(values, [Some(32766), Some(7234)]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_many_chunks_on_conflict() -> Result<(), DbErr> {
+        use crate::{DbBackend, MockDatabase, MockExecResult};
+
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 32765,
+                    rows_affected: 32765,
+                },
+                MockExecResult {
+                    last_insert_id: 32766,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+
+        // the rows fit the limit of SQLite, but not along with the value of ON CONFLICT
+        cake::Entity::insert_many((1..=32766).map(|i| cake::ActiveModel {
+            id: NotSet,
+            name: Set(format!("cake {i}")),
+        }))
+        .on_conflict(
+            OnConflict::column(cake::Column::Name)
+                .value(cake::Column::Name, "duplicate")
+                .to_owned(),
+        )
+        .exec_without_returning(&db)
+        .await?;
+
+        let values: Vec<_> = db
+            .into_transaction_log()
+            .iter()
+            .map(|txn| txn.statements()[0].values.as_ref().map(|v| v.0.len()))
+            .collect();
+        assert_eq!(values, [Some(32766), Some(2)]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_many_changed_query() -> Result<(), DbErr> {
+        use crate::{DbBackend, MockDatabase, MockExecResult};
+
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 32766,
+                    rows_affected: 32766,
+                },
+                MockExecResult {
+                    last_insert_id: 40001,
+                    rows_affected: 7235,
+                },
+            ])
+            .into_connection();
+
+        let mut insert = cake::Entity::insert_many((1..=40000).map(|i| cake::ActiveModel {
+            id: NotSet,
+            name: Set(format!("cake {i}")),
+        }));
+        // the changes made through the statement apply to every chunk
+        insert.query().on_conflict(
+            OnConflict::column(cake::Column::Name)
+                .do_nothing()
+                .to_owned(),
+        );
+        // and the rows added afterwards are split up along with the others
+        let insert = insert.add(cake::ActiveModel {
+            id: NotSet,
+            name: Set("cake 40001".to_owned()),
+        });
+        assert!(insert
+            .build(DbBackend::Sqlite)
+            .to_string()
+            .ends_with(r#"('cake 40000'), ('cake 40001') ON CONFLICT ("name") DO NOTHING"#));
+        insert.exec_without_returning(&db).await?;
+
+        let log = db.into_transaction_log();
+        let values: Vec<_> = log
+            .iter()
+            .map(|txn| txn.statements()[0].values.as_ref().map(|v| v.0.len()))
+            .collect();
+        assert_eq!(values, [Some(32766), Some(7235)]);
+        assert!(log.iter().all(|txn| txn.statements()[0]
+            .sql
+            .ends_with(r#"ON CONFLICT ("name") DO NOTHING"#)));
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_many_chunks_in_transaction() -> Result<(), DbErr> {
+        use crate::{DbBackend, MockDatabase};
+
+        let models: Vec<_> = (1..=40000)
+            .map(|id| cake::Model {
+                id,
+                name: format!("cake {id}"),
+            })
+            .collect();
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([models[..32767].to_vec(), models[32767..].to_vec()])
+            .into_connection();
+
+        let inserted = cake::Entity::insert_many(
+            models
+                .iter()
+                .cloned()
+                .map(IntoActiveModel::into_active_model),
+        )
+        .on_conflict_do_nothing()
+        .exec_with_returning_many_in_transaction(&db)
+        .await?;
+
+        assert!(matches!(inserted, TryInsertResult::Inserted(inserted) if inserted == models));
+
+        // two bind parameters per row, both statements in a single transaction
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 1);
+        let statements = log[0].statements();
+        assert_eq!(statements.len(), 4);
+        assert_eq!(statements[0].sql, "BEGIN");
+        assert!(statements[1]
+            .sql
+            .ends_with(r#"ON CONFLICT ("id") DO NOTHING RETURNING "id", "name""#));
+        assert_eq!(
+            statements[1..3]
+                .iter()
+                .map(|stmt| stmt.values.as_ref().map(|v| v.0.len()))
+                .collect::<Vec<_>>(),
+            [Some(65534), Some(14466)]
+        );
+        assert_eq!(statements[3].sql, "COMMIT");
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_from_select_returning() -> Result<(), DbErr> {
+        use crate::tests_cfg::vendor;
+        use crate::{
+            ColumnTrait, JoinType, MockDatabase, QueryFilter, QuerySelect, RelationTrait,
+            Statement, Transaction,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[vendor::Model {
+                id: 1,
+                name: "Apple Pie".to_owned(),
+            }]])
+            .into_connection();
+
+        let select = cake::Entity::find()
+            .join(JoinType::InnerJoin, cake::Relation::Fruit.def())
+            .filter(crate::tests_cfg::fruit::Column::Name.eq("Apple"));
+
+        let vendors = Insert::<vendor::ActiveModel>::from_select(
+            select,
+            [
+                (vendor::Column::Id, cake::Column::Id),
+                (vendor::Column::Name, cake::Column::Name),
+            ],
+        )?
+        .exec_with_returning_many(&db)
+        .await?;
+
+        assert_eq!(vendors.len(), 1);
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"INSERT INTO "vendor" ("id", "name")"#,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                    r#"INNER JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+                    r#"WHERE "fruit"."name" = $1"#,
+                    r#"RETURNING "id", "name""#,
+                ]
+                .join(" ")
+                .as_str(),
+                ["Apple".into()]
+            )])]
+        );
+
+        Ok(())
+    }
 }
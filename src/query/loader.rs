@@ -1,11 +1,20 @@
+#[cfg(feature = "with-json")]
+use crate::JsonValue;
 use crate::{
-    error::*, Condition, ConnectionTrait, DbErr, EntityTrait, Identity, ModelTrait, QueryFilter,
-    Related, RelationType, Select,
+    error::*, join_tbl_on_condition, unpack_table_ref, Condition, ConnectionTrait, DbBackend,
+    DbErr, EntityTrait, FromQueryResult, Identity, Iterable, Linked, LoadNested, ModelTrait, Order,
+    PartialModelTrait, PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter,
+    QueryResult, QuerySelect, Related, RelationDef, RelationType, Select, TryGetableMany,
 };
 use async_trait::async_trait;
-use sea_query::{ColumnRef, DynIden, Expr, IntoColumnRef, SimpleExpr, TableRef, ValueTuple};
+use sea_query::{
+    Alias, Asterisk, ColumnRef, DynIden, Expr, Func, IntoColumnRef, IntoIden, IntoValueTuple,
+    JoinType, OverStatement, SeaRc, SelectStatement, SimpleExpr, TableRef, ValueTuple,
+    WindowStatement,
+};
 use std::{
     collections::{HashMap, HashSet},
+    marker::PhantomData,
     str::FromStr,
 };
 
@@ -56,6 +65,197 @@ pub trait LoaderTrait {
         <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
 }
 
+/// Further eager loading on top of [`LoaderTrait`], implemented for every slice of Models
+/// (and so for a `Vec` of them too).
+#[async_trait]
+pub trait LoaderTraitExt {
+    /// Source model
+    type Model: ModelTrait;
+
+    /// Used to eager load has_many relations, keeping at most `limit` related models
+    /// for each parent in the given order.
+    ///
+    /// It uses a `LATERAL` join on Postgres and a `ROW_NUMBER()` window on MySQL and SQLite.
+    ///
+    /// The ordering must be given by `order_by`: any `ORDER BY` already on `stmt` is replaced,
+    /// because it cannot be carried over to the per-parent window. Any `LIMIT` or `OFFSET` on
+    /// `stmt` is dropped as well, since it would truncate the rows of all parents together.
+    async fn load_many_limited<R, S, C>(
+        &self,
+        stmt: S,
+        order_by: Vec<(R::Column, Order)>,
+        limit: u64,
+        db: &C,
+    ) -> Result<Vec<Vec<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        S: EntityOrSelect<R>,
+        <Self::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to eager load a tree of relations, one batched query per level of the tree.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[fruit::Model {
+    /// #         id: 1,
+    /// #         name: "Apple".to_owned(),
+    /// #         cake_id: Some(1),
+    /// #     }]])
+    /// #     .append_query_results([[cake::Model {
+    /// #         id: 1,
+    /// #         name: "Apple Pie".to_owned(),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// let cakes = vec![cake::Model {
+    ///     id: 1,
+    ///     name: "Apple Pie".to_owned(),
+    /// }];
+    ///
+    /// // cake -> fruits -> cake of each fruit
+    /// let loaded = cakes
+    ///     .load_nested(
+    ///         WithMany::new(fruit::Entity).with(WithOne::new(cake::Entity)),
+    ///         &db,
+    ///     )
+    ///     .await?;
+    ///
+    /// assert_eq!(loaded[0][0].model.name, "Apple");
+    /// assert_eq!(
+    ///     loaded[0][0].related.as_ref().map(|cake| cake.model.id),
+    ///     Some(1)
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn load_nested<W, C>(&self, with: W, db: &C) -> Result<Vec<W::Output>, DbErr>
+    where
+        C: ConnectionTrait,
+        W: LoadNested<Self::Model>;
+
+    /// Used to eager load the Entity at the end of a [`Linked`] chain, which can span multiple hops.
+    ///
+    /// The tables along the chain are aliased as in [`Select::find_also_linked`]: the table the
+    /// `i`-th relation leads to is `r{i}`, except the linked Entity which keeps its own name.
+    async fn load_linked<L, S, C>(
+        &self,
+        stmt: S,
+        l: L,
+        db: &C,
+    ) -> Result<Vec<Vec<<L::ToEntity as EntityTrait>::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        L: Linked<FromEntity = <Self::Model as ModelTrait>::Entity> + Send,
+        S: EntityOrSelect<L::ToEntity>,
+        <L::ToEntity as EntityTrait>::Model: Send + Sync;
+
+    /// Used to eager load has_many and many_to_many relations into a [`PartialModelTrait`],
+    /// selecting only the columns it needs.
+    ///
+    /// The foreign key is not required to be part of the partial model.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[std::collections::BTreeMap::from([
+    /// #         ("name", Value::from("Apple")),
+    /// #         ("parent_key_0", Value::from(1)),
+    /// #     ])]])
+    /// #     .into_connection();
+    /// #
+    /// #[derive(Clone, Debug, PartialEq, DerivePartialModel, FromQueryResult)]
+    /// #[sea_orm(entity = "fruit::Entity")]
+    /// struct FruitName {
+    ///     name: String,
+    /// }
+    ///
+    /// let cakes = vec![cake::Model {
+    ///     id: 1,
+    ///     name: "Apple Pie".to_owned(),
+    /// }];
+    ///
+    /// let fruits: Vec<Vec<FruitName>> = cakes.load_many_partial(fruit::Entity, &db).await?;
+    ///
+    /// assert_eq!(
+    ///     fruits,
+    ///     [vec![FruitName {
+    ///         name: "Apple".to_owned()
+    ///     }]]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"SELECT "fruit"."name" AS "name", "parent"."id" AS "parent_key_0" FROM "fruit" INNER JOIN "cake" AS "parent" ON "parent"."id" = "fruit"."cake_id" WHERE "parent"."id" IN ($1)"#,
+    ///         [1.into()]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn load_many_partial<R, S, P, C>(&self, stmt: S, db: &C) -> Result<Vec<Vec<P>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        S: EntityOrSelect<R>,
+        P: PartialModelTrait + Clone + Send + Sync,
+        <Self::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to eager load the Entity at the end of a [`Linked`] chain into a [`PartialModelTrait`],
+    /// selecting only the columns it needs
+    async fn load_linked_partial<L, S, P, C>(
+        &self,
+        stmt: S,
+        l: L,
+        db: &C,
+    ) -> Result<Vec<Vec<P>>, DbErr>
+    where
+        C: ConnectionTrait,
+        L: Linked<FromEntity = <Self::Model as ModelTrait>::Entity> + Send,
+        S: EntityOrSelect<L::ToEntity>,
+        P: PartialModelTrait + Clone + Send + Sync;
+
+    /// Used to eager load has_many and many_to_many relations as [`JsonValue`]s,
+    /// selecting only the columns of the given Select
+    #[cfg(feature = "with-json")]
+    async fn load_many_json<R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Vec<JsonValue>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        S: EntityOrSelect<R>,
+        <Self::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to eager load the Entity at the end of a [`Linked`] chain as [`JsonValue`]s,
+    /// selecting only the columns of the given Select
+    #[cfg(feature = "with-json")]
+    async fn load_linked_json<L, S, C>(
+        &self,
+        stmt: S,
+        l: L,
+        db: &C,
+    ) -> Result<Vec<Vec<JsonValue>>, DbErr>
+    where
+        C: ConnectionTrait,
+        L: Linked<FromEntity = <Self::Model as ModelTrait>::Entity> + Send,
+        S: EntityOrSelect<L::ToEntity>;
+}
+
 impl<E> EntityOrSelect<E> for E
 where
     E: EntityTrait,
@@ -186,15 +386,7 @@ where
         S: EntityOrSelect<R>,
         <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
     {
-        // we verify that is HasMany relation
-
-        if <<<Self as LoaderTrait>::Model as ModelTrait>::Entity as Related<R>>::via().is_some() {
-            return Err(query_err("Relation is ManyToMany instead of HasMany"));
-        }
-        let rel_def = <<<Self as LoaderTrait>::Model as ModelTrait>::Entity as Related<R>>::to();
-        if rel_def.rel_type == RelationType::HasOne {
-            return Err(query_err("Relation is HasOne instead of HasMany"));
-        }
+        let rel_def = has_many_def::<<<Self as LoaderTrait>::Model as ModelTrait>::Entity, R>()?;
 
         if self.is_empty() {
             return Ok(Vec::new());
@@ -211,29 +403,7 @@ where
 
         let data = stmt.all(db).await?;
 
-        let mut hashmap: HashMap<ValueTuple, Vec<<R as EntityTrait>::Model>> =
-            keys.iter()
-                .fold(HashMap::new(), |mut acc, key: &ValueTuple| {
-                    acc.insert(key.clone(), Vec::new());
-                    acc
-                });
-
-        for value in data {
-            let key = extract_key(&rel_def.to_col, &value)?;
-
-            let vec = hashmap.get_mut(&key).ok_or_else(|| {
-                DbErr::RecordNotFound(format!("Loader: failed to find model for {key:?}"))
-            })?;
-
-            vec.push(value);
-        }
-
-        let result: Vec<Vec<R::Model>> = keys
-            .iter()
-            .map(|key: &ValueTuple| hashmap.get(key).cloned().unwrap_or_default())
-            .collect();
-
-        Ok(result)
+        group_many(&keys, data, &rel_def.to_col)
     }
 
     async fn load_many_to_many<R, S, V, C>(
@@ -334,12 +504,310 @@ where
     }
 }
 
+#[async_trait]
+impl<M> LoaderTraitExt for [M]
+where
+    M: ModelTrait + Sync,
+{
+    type Model = M;
+
+    async fn load_many_limited<R, S, C>(
+        &self,
+        stmt: S,
+        order_by: Vec<(R::Column, Order)>,
+        limit: u64,
+        db: &C,
+    ) -> Result<Vec<Vec<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        S: EntityOrSelect<R>,
+        M::Entity: Related<R>,
+    {
+        let rel_def = has_many_def::<M::Entity, R>()?;
+
+        if self.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let keys = self
+            .iter()
+            .map(|model| extract_key(&rel_def.from_col, model))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let to_col = rel_def.to_col.clone();
+        let db_backend = db.get_database_backend();
+        let query = limited_query(stmt.select(), rel_def, &keys, &order_by, limit, db_backend);
+
+        let data = R::find()
+            .from_raw_sql(db_backend.build(&query))
+            .all(db)
+            .await?;
+
+        group_many(&keys, data, &to_col)
+    }
+
+    async fn load_nested<W, C>(&self, with: W, db: &C) -> Result<Vec<W::Output>, DbErr>
+    where
+        C: ConnectionTrait,
+        W: LoadNested<M>,
+    {
+        with.load_nested(self, db).await
+    }
+
+    async fn load_linked<L, S, C>(
+        &self,
+        stmt: S,
+        l: L,
+        db: &C,
+    ) -> Result<Vec<Vec<<L::ToEntity as EntityTrait>::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        L: Linked<FromEntity = M::Entity> + Send,
+        S: EntityOrSelect<L::ToEntity>,
+        <L::ToEntity as EntityTrait>::Model: Send + Sync,
+    {
+        let select = stmt.select();
+        load_keyed(self, select, l.link(), db).await
+    }
+
+    async fn load_many_partial<R, S, P, C>(&self, stmt: S, db: &C) -> Result<Vec<Vec<P>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        S: EntityOrSelect<R>,
+        P: PartialModelTrait + Clone + Send + Sync,
+        M::Entity: Related<R>,
+    {
+        let select = P::select_cols(stmt.select().select_only());
+        load_keyed(self, select, related_link::<M::Entity, R>(), db).await
+    }
+
+    async fn load_linked_partial<L, S, P, C>(
+        &self,
+        stmt: S,
+        l: L,
+        db: &C,
+    ) -> Result<Vec<Vec<P>>, DbErr>
+    where
+        C: ConnectionTrait,
+        L: Linked<FromEntity = M::Entity> + Send,
+        S: EntityOrSelect<L::ToEntity>,
+        P: PartialModelTrait + Clone + Send + Sync,
+    {
+        let select = P::select_cols(stmt.select().select_only());
+        load_keyed(self, select, l.link(), db).await
+    }
+
+    #[cfg(feature = "with-json")]
+    async fn load_many_json<R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Vec<JsonValue>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        S: EntityOrSelect<R>,
+        M::Entity: Related<R>,
+    {
+        let data = load_keyed(self, stmt.select(), related_link::<M::Entity, R>(), db).await?;
+        Ok(strip_parent_key::<M>(data))
+    }
+
+    #[cfg(feature = "with-json")]
+    async fn load_linked_json<L, S, C>(
+        &self,
+        stmt: S,
+        l: L,
+        db: &C,
+    ) -> Result<Vec<Vec<JsonValue>>, DbErr>
+    where
+        C: ConnectionTrait,
+        L: Linked<FromEntity = M::Entity> + Send,
+        S: EntityOrSelect<L::ToEntity>,
+    {
+        let data = load_keyed(self, stmt.select(), l.link(), db).await?;
+        Ok(strip_parent_key::<M>(data))
+    }
+}
+
+/// The relation from `E` to `R`, which must be HasMany
+fn has_many_def<E, R>() -> Result<RelationDef, DbErr>
+where
+    E: Related<R>,
+    R: EntityTrait,
+{
+    if E::via().is_some() {
+        return Err(query_err("Relation is ManyToMany instead of HasMany"));
+    }
+    let rel_def = E::to();
+    if rel_def.rel_type == RelationType::HasOne {
+        return Err(query_err("Relation is HasOne instead of HasMany"));
+    }
+    Ok(rel_def)
+}
+
+/// The relations from an Entity to a related Entity, through the junction Entity if any
+fn related_link<E, R>() -> Vec<RelationDef>
+where
+    E: EntityTrait + Related<R>,
+    R: EntityTrait,
+{
+    match E::via() {
+        Some(via) => vec![via, E::to()],
+        None => vec![E::to()],
+    }
+}
+
+fn parent_key_alias(i: usize) -> String {
+    format!("parent_key_{i}")
+}
+
+/// A loaded row, along with the primary key of the parent model it was loaded for
+struct KeyedRow<T, P> {
+    key: ValueTuple,
+    row: T,
+    parent: PhantomData<P>,
+}
+
+impl<T, P> FromQueryResult for KeyedRow<T, P>
+where
+    T: FromQueryResult,
+    P: PrimaryKeyTrait,
+{
+    fn from_query_result(res: &QueryResult, pre: &str) -> Result<Self, DbErr> {
+        let cols: Vec<String> = (0..<P::ValueType as PrimaryKeyArity>::ARITY)
+            .map(parent_key_alias)
+            .collect();
+        Ok(Self {
+            key: P::ValueType::try_get_many(res, pre, &cols)?.into_value_tuple(),
+            row: T::from_query_result(res, pre)?,
+            parent: PhantomData,
+        })
+    }
+}
+
+/// Join the link in reverse onto the Select and filter by the primary keys of the parent models.
+/// Each row is returned with the primary key of its parent, so the selected columns need not
+/// contain the foreign key.
+async fn load_keyed<M, R, T, C>(
+    models: &[M],
+    mut select: Select<R>,
+    link: Vec<RelationDef>,
+    db: &C,
+) -> Result<Vec<Vec<T>>, DbErr>
+where
+    M: ModelTrait,
+    R: EntityTrait,
+    T: FromQueryResult + Clone + Send + Sync,
+    C: ConnectionTrait,
+{
+    if link.is_empty() {
+        return Err(query_err("Linked has no relation"));
+    }
+    if models.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let pk: Vec<DynIden> = <M::Entity as EntityTrait>::PrimaryKey::iter()
+        .map(|key| key.into_column().into_iden())
+        .collect();
+    let pk = match pk.as_slice() {
+        [a] => Identity::Unary(SeaRc::clone(a)),
+        [a, b] => Identity::Binary(SeaRc::clone(a), SeaRc::clone(b)),
+        [a, b, c] => Identity::Ternary(SeaRc::clone(a), SeaRc::clone(b), SeaRc::clone(c)),
+        _ => Identity::Many(pk),
+    };
+    let keys = models
+        .iter()
+        .map(|model| extract_key(&pk, model))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let parent = Alias::new("parent").into_iden();
+    join_link(QuerySelect::query(&mut select), link, SeaRc::clone(&parent));
+    for (i, col) in pk.clone().into_iter().enumerate() {
+        QuerySelect::query(&mut select).expr_as(
+            Expr::col((SeaRc::clone(&parent), col)),
+            Alias::new(parent_key_alias(i)),
+        );
+    }
+    let condition = prepare_condition(&TableRef::Table(parent), &pk, &keys);
+
+    let data = select
+        .filter(condition)
+        .into_model::<KeyedRow<T, <M::Entity as EntityTrait>::PrimaryKey>>()
+        .all(db)
+        .await?;
+
+    let mut hashmap: HashMap<ValueTuple, Vec<T>> = HashMap::new();
+    for data in data {
+        hashmap.entry(data.key).or_default().push(data.row);
+    }
+
+    Ok(keys
+        .iter()
+        .map(|key| hashmap.get(key).cloned().unwrap_or_default())
+        .collect())
+}
+
+/// Join the tables of a link in reverse, starting from the table being selected from.
+/// As in [`Select::find_also_linked`], the table the `i`-th relation leads to is aliased as `r{i}`,
+/// except the last one which is the table being selected from; the table of the parent is aliased
+/// as `parent`.
+fn join_link(query: &mut SelectStatement, link: Vec<RelationDef>, parent: DynIden) {
+    let last = link.len().saturating_sub(1);
+    for (i, mut rel) in link.into_iter().enumerate().rev() {
+        let from_tbl = if i > 0 {
+            Alias::new(format!("r{}", i - 1)).into_iden()
+        } else {
+            SeaRc::clone(&parent)
+        };
+        let to_tbl = if i < last {
+            Alias::new(format!("r{i}")).into_iden()
+        } else {
+            unpack_table_ref(&rel.to_tbl)
+        };
+
+        let mut condition = Condition::all().add(join_tbl_on_condition(
+            SeaRc::clone(&from_tbl),
+            SeaRc::clone(&to_tbl),
+            rel.from_col,
+            rel.to_col,
+        ));
+        if let Some(f) = rel.on_condition.take() {
+            condition = condition.add(f(SeaRc::clone(&from_tbl), SeaRc::clone(&to_tbl)));
+        }
+
+        query.join_as(JoinType::InnerJoin, rel.from_tbl, from_tbl, condition);
+    }
+}
+
+#[cfg(feature = "with-json")]
+fn strip_parent_key<M>(data: Vec<Vec<JsonValue>>) -> Vec<Vec<JsonValue>>
+where
+    M: ModelTrait,
+{
+    let arity = <<<M::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType as PrimaryKeyArity>::ARITY;
+    data.into_iter()
+        .map(|rows| {
+            rows.into_iter()
+                .map(|mut row| {
+                    if let JsonValue::Object(obj) = &mut row {
+                        for i in 0..arity {
+                            obj.remove(&parent_key_alias(i));
+                        }
+                    }
+                    row
+                })
+                .collect()
+        })
+        .collect()
+}
+
 fn cmp_table_ref(left: &TableRef, right: &TableRef) -> bool {
     // not ideal; but
     format!("{left:?}") == format!("{right:?}")
 }
 
-fn extract_key<Model>(target_col: &Identity, model: &Model) -> Result<ValueTuple, DbErr>
+pub(crate) fn extract_key<Model>(target_col: &Identity, model: &Model) -> Result<ValueTuple, DbErr>
 where
     Model: ModelTrait,
 {
@@ -403,6 +871,117 @@ where
     })
 }
 
+fn group_many<M>(keys: &[ValueTuple], data: Vec<M>, to_col: &Identity) -> Result<Vec<Vec<M>>, DbErr>
+where
+    M: ModelTrait,
+{
+    let mut hashmap: HashMap<ValueTuple, Vec<M>> =
+        keys.iter()
+            .fold(HashMap::new(), |mut acc, key: &ValueTuple| {
+                acc.insert(key.clone(), Vec::new());
+                acc
+            });
+
+    for value in data {
+        let key = extract_key(to_col, &value)?;
+
+        let vec = hashmap.get_mut(&key).ok_or_else(|| {
+            DbErr::RecordNotFound(format!("Loader: failed to find model for {key:?}"))
+        })?;
+
+        vec.push(value);
+    }
+
+    let result: Vec<Vec<M>> = keys
+        .iter()
+        .map(|key: &ValueTuple| hashmap.get(key).cloned().unwrap_or_default())
+        .collect();
+
+    Ok(result)
+}
+
+/// Select the related rows of the given parents, at most `limit` rows for each parent.
+/// The ordering of `stmt` is dropped in favour of `order_by`, and its limit and offset are
+/// dropped because they would apply across all parents rather than to each of them.
+fn limited_query<R>(
+    stmt: Select<R>,
+    mut rel_def: RelationDef,
+    keys: &[ValueTuple],
+    order_by: &[(R::Column, Order)],
+    limit: u64,
+    db_backend: DbBackend,
+) -> SelectStatement
+where
+    R: EntityTrait,
+{
+    let limited = Alias::new("limited").into_iden();
+    let mut query = SelectStatement::new();
+    match db_backend {
+        DbBackend::Postgres => {
+            // SELECT "limited".* FROM "parent"
+            // JOIN LATERAL (SELECT .. WHERE <correlated> ORDER BY .. LIMIT ..) AS "limited" ON TRUE
+            let parent = Alias::new("parent").into_iden();
+            rel_def.from_tbl = rel_def.from_tbl.alias(SeaRc::clone(&parent));
+            let parent_tbl = rel_def.from_tbl.clone();
+            let parent_condition =
+                prepare_condition(&TableRef::Table(parent), &rel_def.from_col, keys);
+
+            let mut inner = stmt.filter(rel_def).query;
+            inner.clear_order_by().reset_offset().limit(limit);
+            for (col, order) in order_by {
+                inner.order_by((R::default(), *col), order.clone());
+            }
+
+            query
+                .expr(Expr::col((SeaRc::clone(&limited), Asterisk)))
+                .from(parent_tbl)
+                .join_lateral(
+                    JoinType::InnerJoin,
+                    inner,
+                    SeaRc::clone(&limited),
+                    Expr::cust("TRUE"),
+                )
+                .cond_where(parent_condition);
+            for (col, order) in order_by {
+                query.order_by((SeaRc::clone(&limited), *col), order.clone());
+            }
+        }
+        DbBackend::MySql | DbBackend::Sqlite => {
+            // SELECT * FROM (SELECT .., ROW_NUMBER() OVER (PARTITION BY .. ORDER BY ..) AS "row_num"
+            // WHERE .. IN (..)) AS "limited" WHERE "row_num" <= ..
+            let row_num = Alias::new("row_num").into_iden();
+            let condition = prepare_condition(&rel_def.to_tbl, &rel_def.to_col, keys);
+            let to_tbl = unpack_table_ref(&rel_def.to_tbl);
+
+            let mut window = WindowStatement::new();
+            for col in rel_def.to_col {
+                window.partition_by((SeaRc::clone(&to_tbl), col));
+            }
+            for (col, order) in order_by {
+                window.order_by((R::default(), *col), order.clone());
+            }
+
+            let mut inner = stmt.filter(condition).query;
+            inner
+                .clear_order_by()
+                .reset_limit()
+                .reset_offset()
+                .expr_window_as(
+                    Func::cust(Alias::new("ROW_NUMBER")),
+                    window,
+                    SeaRc::clone(&row_num),
+                );
+
+            query
+                .expr(Expr::col(Asterisk))
+                .from_subquery(inner, SeaRc::clone(&limited))
+                .and_where(Expr::col((SeaRc::clone(&limited), SeaRc::clone(&row_num))).lte(limit))
+                .order_by((limited, row_num), Order::Asc);
+        }
+    }
+    query
+}
+
 fn prepare_condition(table: &TableRef, col: &Identity, keys: &[ValueTuple]) -> Condition {
     let keys = if !keys.is_empty() {
         let set: HashSet<_> = keys.iter().cloned().collect();
@@ -597,6 +1176,143 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_load_many_limited_postgres() {
+        use sea_orm::{
+            entity::prelude::*, tests_cfg::*, DbBackend, LoaderTraitExt, MockDatabase, Order,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                fruit_model(4, Some(1)),
+                fruit_model(3, Some(2)),
+                fruit_model(2, Some(1)),
+            ]])
+            .into_connection();
+
+        let cakes = vec![cake_model(1), cake_model(2), cake_model(3)];
+
+        let fruits = cakes
+            .load_many_limited(
+                fruit::Entity::find().filter(fruit::Column::Name.ne("banana")),
+                vec![(fruit::Column::Id, Order::Desc)],
+                2,
+                &db,
+            )
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            fruits,
+            [
+                vec![fruit_model(4, Some(1)), fruit_model(2, Some(1))],
+                vec![fruit_model(3, Some(2))],
+                vec![],
+            ]
+        );
+
+        // the order of the bound keys is unspecified, so only the SQL is compared
+        let log = db.into_transaction_log();
+        assert_eq!(
+            log[0].statements()[0].sql,
+            [
+                r#"SELECT "limited".* FROM "cake" AS "parent""#,
+                r#"INNER JOIN LATERAL (SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                r#"WHERE "fruit"."name" <> $1 AND "parent"."id" = "fruit"."cake_id""#,
+                r#"ORDER BY "fruit"."id" DESC LIMIT $2) AS "limited" ON TRUE"#,
+                r#"WHERE "parent"."id" IN ($3, $4, $5) ORDER BY "limited"."id" DESC"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_many_limited_sqlite() {
+        use sea_orm::{
+            entity::prelude::*, tests_cfg::*, DbBackend, LoaderTraitExt, MockDatabase, Order,
+            Transaction,
+        };
+
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([[fruit_model(1, Some(1)), fruit_model(3, Some(1))]])
+            .into_connection();
+
+        let cakes = vec![cake_model(1)];
+
+        let fruits = cakes
+            .load_many_limited(
+                fruit::Entity::find(),
+                vec![(fruit::Column::Name, Order::Asc)],
+                2,
+                &db,
+            )
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            fruits,
+            [vec![fruit_model(1, Some(1)), fruit_model(3, Some(1))]]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Sqlite,
+                [
+                    r#"SELECT * FROM (SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id","#,
+                    r#"ROW_NUMBER() OVER ( PARTITION BY "fruit"."cake_id" ORDER BY "fruit"."name" ASC ) AS "row_num""#,
+                    r#"FROM "fruit" WHERE "fruit"."cake_id" IN (?)) AS "limited""#,
+                    r#"WHERE "limited"."row_num" <= ? ORDER BY "limited"."row_num" ASC"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into(), 2u64.into()]
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_many_limited_drops_limit() {
+        use sea_orm::{
+            entity::prelude::*, tests_cfg::*, DbBackend, LoaderTraitExt, MockDatabase, Order,
+            QuerySelect,
+        };
+
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([[fruit_model(1, Some(1)), fruit_model(2, Some(2))]])
+            .into_connection();
+
+        let cakes = vec![cake_model(1), cake_model(2)];
+
+        let fruits = cakes
+            .load_many_limited(
+                fruit::Entity::find().limit(1).offset(1),
+                vec![(fruit::Column::Id, Order::Asc)],
+                1,
+                &db,
+            )
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            fruits,
+            [vec![fruit_model(1, Some(1))], vec![fruit_model(2, Some(2))]]
+        );
+
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 1);
+        assert_eq!(
+            log[0].statements()[0].sql,
+            [
+                r#"SELECT * FROM (SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id","#,
+                r#"ROW_NUMBER() OVER ( PARTITION BY "fruit"."cake_id" ORDER BY "fruit"."id" ASC ) AS "row_num""#,
+                r#"FROM "fruit" WHERE "fruit"."cake_id" IN (?, ?)) AS "limited""#,
+                r#"WHERE "limited"."row_num" <= ? ORDER BY "limited"."row_num" ASC"#,
+            ]
+            .join(" ")
+        );
+    }
+
     #[tokio::test]
     async fn test_load_many_empty() {
         use sea_orm::{entity::prelude::*, tests_cfg::*, DbBackend, MockDatabase};
@@ -757,4 +1473,109 @@ mod tests {
         let values_count = sql.matches("$1").count() + sql.matches("$2").count();
         assert_eq!(values_count, 2, "Duplicate values were not removed");
     }
+
+    #[tokio::test]
+    async fn test_load_linked() {
+        use sea_orm::{
+            entity::prelude::*, tests_cfg::*, DbBackend, LoaderTraitExt, MockDatabase, Transaction,
+        };
+        use std::collections::BTreeMap;
+
+        let vendor_row = |id: i32, cake_id: i32| {
+            BTreeMap::from([
+                ("id", Value::from(id)),
+                ("name", Value::from(format!("vendor {id}"))),
+                ("parent_key_0", Value::from(cake_id)),
+            ])
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[vendor_row(1, 1), vendor_row(2, 1)]])
+            .into_connection();
+
+        let cakes = vec![cake_model(1)];
+
+        let vendors = cakes
+            .load_linked(vendor::Entity, entity_linked::CakeToFillingVendor, &db)
+            .await
+            .expect("Should return something");
+
+        let vendor_model = |id: i32| vendor::Model {
+            id,
+            name: format!("vendor {id}"),
+        };
+        assert_eq!(vendors, [vec![vendor_model(1), vendor_model(2)]]);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "vendor"."id", "vendor"."name", "parent"."id" AS "parent_key_0" FROM "vendor""#,
+                    r#"INNER JOIN "filling" AS "r1" ON "r1"."vendor_id" = "vendor"."id""#,
+                    r#"INNER JOIN "cake_filling" AS "r0" ON "r0"."filling_id" = "r1"."id""#,
+                    r#"INNER JOIN "cake" AS "parent" ON "parent"."id" = "r0"."cake_id""#,
+                    r#"WHERE "parent"."id" IN ($1)"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into()]
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_many_json() {
+        use sea_orm::{
+            entity::prelude::*, tests_cfg::*, DbBackend, LoaderTraitExt, MockDatabase, QuerySelect,
+            Transaction,
+        };
+        use serde_json::json;
+        use std::collections::BTreeMap;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                BTreeMap::from([
+                    ("name", Value::from("apple")),
+                    ("parent_key_0", 1i32.into()),
+                ]),
+                BTreeMap::from([
+                    ("name", Value::from("grape")),
+                    ("parent_key_0", 1i32.into()),
+                ]),
+            ]])
+            .into_connection();
+
+        let cakes = vec![cake_model(1)];
+
+        let fruits = cakes
+            .load_many_json(
+                fruit::Entity::find()
+                    .select_only()
+                    .column(fruit::Column::Name),
+                &db,
+            )
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            fruits,
+            [vec![json!({ "name": "apple" }), json!({ "name": "grape" })]]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."name", "parent"."id" AS "parent_key_0" FROM "fruit""#,
+                    r#"INNER JOIN "cake" AS "parent" ON "parent"."id" = "fruit"."cake_id""#,
+                    r#"WHERE "parent"."id" IN ($1)"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into()]
+            )]
+        );
+    }
 }
@@ -1,27 +1,44 @@
 pub(crate) mod combine;
+mod data_loader;
 mod delete;
+mod exists;
+mod full_text;
 mod helper;
 mod insert;
 mod join;
 #[cfg(feature = "with-json")]
 mod json;
 mod loader;
+mod nested;
+mod params;
 mod select;
 mod traits;
+mod tree;
+mod union;
 mod update;
+mod upsert;
 mod util;
+mod window;
 
 pub use combine::{SelectA, SelectB, SelectC};
+pub use data_loader::*;
 pub use delete::*;
+pub use exists::RelatedFilter;
+pub use full_text::*;
 pub use helper::*;
 pub use insert::*;
 #[cfg(feature = "with-json")]
 pub use json::*;
 pub use loader::*;
+pub use nested::*;
+pub use params::*;
 pub use select::*;
 pub use traits::*;
+pub use tree::*;
 pub use update::*;
+pub use upsert::*;
 pub use util::*;
+pub use window::*;
 
 pub use crate::{
     ConnectionTrait, CursorTrait, InsertResult, PaginatorTrait, Statement, StreamTrait,
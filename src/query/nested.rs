@@ -0,0 +1,411 @@
+use crate::{
+    ConnectionTrait, DbErr, EntityOrSelect, EntityTrait, LoaderTrait, ModelTrait, Related, Select,
+};
+use async_trait::async_trait;
+
+/// A model together with the relations loaded for it by [`LoaderTraitExt::load_nested`](crate::LoaderTraitExt::load_nested)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Nested<M, N> {
+    /// The model
+    pub model: M,
+    /// The nested relations of the model; `()` when nothing more was loaded
+    pub related: N,
+}
+
+/// A tree of relations to be eager loaded by [`LoaderTraitExt::load_nested`](crate::LoaderTraitExt::load_nested).
+///
+/// It is implemented by [`WithOne`], [`WithMany`] and [`WithManyToMany`], by `()` for loading nothing,
+/// and by tuples of them for loading several relations of the same models side by side.
+/// Each level of the tree is loaded with one batched query
+/// (two for [`WithManyToMany`], one of them on the junction table), regardless of the number of parents.
+#[async_trait]
+pub trait LoadNested<M>: Send {
+    /// The loaded relations of a single parent model
+    type Output: Send;
+
+    /// Load the relations of each parent model, in the order of `models`
+    async fn load_nested<C>(self, models: &[M], db: &C) -> Result<Vec<Self::Output>, DbErr>
+    where
+        C: ConnectionTrait;
+}
+
+/// Eager load a has_one (or belongs_to) relation, then the relations nested under it
+#[derive(Debug)]
+pub struct WithOne<R, N = ()>
+where
+    R: EntityTrait,
+{
+    select: Select<R>,
+    nested: N,
+}
+
+/// Eager load a has_many relation, then the relations nested under it
+#[derive(Debug)]
+pub struct WithMany<R, N = ()>
+where
+    R: EntityTrait,
+{
+    select: Select<R>,
+    nested: N,
+}
+
+/// Eager load a many_to_many relation through the junction Entity `V`,
+/// then the relations nested under it
+#[derive(Debug)]
+pub struct WithManyToMany<R, V, N = ()>
+where
+    R: EntityTrait,
+    V: EntityTrait,
+{
+    select: Select<R>,
+    via: V,
+    nested: N,
+}
+
+impl<R> WithOne<R>
+where
+    R: EntityTrait,
+{
+    /// Load the related Entity, or a filtered Select of it
+    pub fn new<S>(stmt: S) -> Self
+    where
+        S: EntityOrSelect<R>,
+    {
+        Self {
+            select: stmt.select(),
+            nested: (),
+        }
+    }
+}
+
+impl<R, N> WithOne<R, N>
+where
+    R: EntityTrait,
+{
+    /// Load these relations of the related models as well
+    pub fn with<W>(self, nested: W) -> WithOne<R, W> {
+        WithOne {
+            select: self.select,
+            nested,
+        }
+    }
+}
+
+impl<R> WithMany<R>
+where
+    R: EntityTrait,
+{
+    /// Load the related Entity, or a filtered Select of it
+    pub fn new<S>(stmt: S) -> Self
+    where
+        S: EntityOrSelect<R>,
+    {
+        Self {
+            select: stmt.select(),
+            nested: (),
+        }
+    }
+}
+
+impl<R, N> WithMany<R, N>
+where
+    R: EntityTrait,
+{
+    /// Load these relations of the related models as well
+    pub fn with<W>(self, nested: W) -> WithMany<R, W> {
+        WithMany {
+            select: self.select,
+            nested,
+        }
+    }
+}
+
+impl<R, V> WithManyToMany<R, V>
+where
+    R: EntityTrait,
+    V: EntityTrait,
+{
+    /// Load the related Entity, or a filtered Select of it, through the junction Entity `via`
+    pub fn new<S>(stmt: S, via: V) -> Self
+    where
+        S: EntityOrSelect<R>,
+    {
+        Self {
+            select: stmt.select(),
+            via,
+            nested: (),
+        }
+    }
+}
+
+impl<R, V, N> WithManyToMany<R, V, N>
+where
+    R: EntityTrait,
+    V: EntityTrait,
+{
+    /// Load these relations of the related models as well
+    pub fn with<W>(self, nested: W) -> WithManyToMany<R, V, W> {
+        WithManyToMany {
+            select: self.select,
+            via: self.via,
+            nested,
+        }
+    }
+}
+
+#[async_trait]
+impl<M> LoadNested<M> for ()
+where
+    M: ModelTrait + Sync,
+{
+    type Output = ();
+
+    async fn load_nested<C>(self, models: &[M], _: &C) -> Result<Vec<()>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(vec![(); models.len()])
+    }
+}
+
+#[async_trait]
+impl<M, R, N> LoadNested<M> for WithOne<R, N>
+where
+    M: ModelTrait + Sync,
+    M::Entity: Related<R>,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+    N: LoadNested<R::Model>,
+{
+    type Output = Option<Nested<R::Model, N::Output>>;
+
+    async fn load_nested<C>(self, models: &[M], db: &C) -> Result<Vec<Self::Output>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let loaded = models.load_one(self.select, db).await?;
+        let found: Vec<bool> = loaded.iter().map(Option::is_some).collect();
+        let flat: Vec<R::Model> = loaded.into_iter().flatten().collect();
+        let related = self.nested.load_nested(&flat, db).await?;
+
+        let mut nested = flat
+            .into_iter()
+            .zip(related)
+            .map(|(model, related)| Nested { model, related });
+
+        Ok(found
+            .into_iter()
+            .map(|found| if found { nested.next() } else { None })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl<M, R, N> LoadNested<M> for WithMany<R, N>
+where
+    M: ModelTrait + Sync,
+    M::Entity: Related<R>,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+    N: LoadNested<R::Model>,
+{
+    type Output = Vec<Nested<R::Model, N::Output>>;
+
+    async fn load_nested<C>(self, models: &[M], db: &C) -> Result<Vec<Self::Output>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let loaded = models.load_many(self.select, db).await?;
+        nest_many(loaded, self.nested, db).await
+    }
+}
+
+#[async_trait]
+impl<M, R, V, N> LoadNested<M> for WithManyToMany<R, V, N>
+where
+    M: ModelTrait + Sync,
+    M::Entity: Related<R>,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+    V: EntityTrait,
+    V::Model: Send + Sync,
+    N: LoadNested<R::Model>,
+{
+    type Output = Vec<Nested<R::Model, N::Output>>;
+
+    async fn load_nested<C>(self, models: &[M], db: &C) -> Result<Vec<Self::Output>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let loaded = models.load_many_to_many(self.select, self.via, db).await?;
+        nest_many(loaded, self.nested, db).await
+    }
+}
+
+/// Load the nested relations of all the related models at once, then split them up per parent again
+async fn nest_many<R, N, C>(
+    loaded: Vec<Vec<R>>,
+    nested: N,
+    db: &C,
+) -> Result<Vec<Vec<Nested<R, N::Output>>>, DbErr>
+where
+    R: ModelTrait + Sync,
+    N: LoadNested<R>,
+    C: ConnectionTrait,
+{
+    let lens: Vec<usize> = loaded.iter().map(Vec::len).collect();
+    let flat: Vec<R> = loaded.into_iter().flatten().collect();
+    let related = nested.load_nested(&flat, db).await?;
+
+    let mut nested = flat
+        .into_iter()
+        .zip(related)
+        .map(|(model, related)| Nested { model, related });
+
+    Ok(lens
+        .into_iter()
+        .map(|len| nested.by_ref().take(len).collect())
+        .collect())
+}
+
+macro_rules! impl_load_nested_tuple {
+    ($($name: ident),+) => {
+        #[async_trait]
+        impl<M, $($name),+> LoadNested<M> for ($($name,)+)
+        where
+            M: ModelTrait + Sync,
+            $($name: LoadNested<M>,)+
+        {
+            type Output = ($($name::Output,)+);
+
+            #[allow(non_snake_case)]
+            async fn load_nested<C>(self, models: &[M], db: &C) -> Result<Vec<Self::Output>, DbErr>
+            where
+                C: ConnectionTrait,
+            {
+                let ($($name,)+) = self;
+                $(let mut $name = $name.load_nested(models, db).await?.into_iter();)+
+                Ok(models
+                    .iter()
+                    .filter_map(|_| Some(($($name.next()?,)+)))
+                    .collect())
+            }
+        }
+    };
+}
+
+impl_load_nested_tuple!(T1);
+impl_load_nested_tuple!(T1, T2);
+impl_load_nested_tuple!(T1, T2, T3);
+impl_load_nested_tuple!(T1, T2, T3, T4);
+impl_load_nested_tuple!(T1, T2, T3, T4, T5);
+impl_load_nested_tuple!(T1, T2, T3, T4, T5, T6);
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, cake_filling, cake_model, filling, fruit, fruit_model};
+    use crate::{
+        DbBackend, LoaderTraitExt, MockDatabase, Nested, WithMany, WithManyToMany, WithOne,
+    };
+
+    fn filling_model(id: i32) -> filling::Model {
+        filling::Model {
+            id,
+            name: format!("filling {id}"),
+            vendor_id: None,
+            ignored_attr: 0,
+        }
+    }
+
+    fn nested<M>(model: M) -> Nested<M, ()> {
+        Nested { model, related: () }
+    }
+
+    #[smol_potat::test]
+    async fn load_nested_many_then_one() -> Result<(), crate::DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                fruit_model(1, Some(1)),
+                fruit_model(2, Some(2)),
+                fruit_model(3, Some(1)),
+            ]])
+            .append_query_results([[cake_model(1), cake_model(2)]])
+            .into_connection();
+
+        let cakes = vec![cake_model(1), cake_model(2), cake_model(3)];
+
+        assert_eq!(
+            cakes
+                .load_nested(
+                    WithMany::new(fruit::Entity).with(WithOne::new(cake::Entity)),
+                    &db
+                )
+                .await?,
+            [
+                vec![
+                    Nested {
+                        model: fruit_model(1, Some(1)),
+                        related: Some(nested(cake_model(1))),
+                    },
+                    Nested {
+                        model: fruit_model(3, Some(1)),
+                        related: Some(nested(cake_model(1))),
+                    },
+                ],
+                vec![Nested {
+                    model: fruit_model(2, Some(2)),
+                    related: Some(nested(cake_model(2))),
+                }],
+                vec![],
+            ]
+        );
+
+        // one query per level
+        assert_eq!(db.into_transaction_log().len(), 2);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn load_nested_siblings() -> Result<(), crate::DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[fruit_model(1, Some(1)), fruit_model(2, Some(2))]])
+            .append_query_results([[
+                cake_filling::Model {
+                    cake_id: 1,
+                    filling_id: 10,
+                },
+                cake_filling::Model {
+                    cake_id: 1,
+                    filling_id: 11,
+                },
+            ]])
+            .append_query_results([[filling_model(10), filling_model(11)]])
+            .into_connection();
+
+        let cakes = vec![cake_model(1), cake_model(2)];
+
+        let loaded = cakes
+            .load_nested(
+                (
+                    WithMany::new(fruit::Entity),
+                    WithManyToMany::new(filling::Entity, cake_filling::Entity),
+                ),
+                &db,
+            )
+            .await?;
+
+        assert_eq!(
+            loaded,
+            [
+                (
+                    vec![nested(fruit_model(1, Some(1)))],
+                    vec![nested(filling_model(10)), nested(filling_model(11))],
+                ),
+                (vec![nested(fruit_model(2, Some(2)))], vec![]),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,553 @@
+use crate::{
+    error::QueryParamsErr,
+    value::{parse_value, value_kinds},
+    ColumnTrait, EntityTrait, IdenStatic, QueryFilter, QueryOrder,
+};
+use sea_query::{ColumnType, Condition, LikeExpr, Order, SimpleExpr};
+use std::str::FromStr;
+
+/// Parses the filters and the sorting of a list endpoint, from a query string or from JSON,
+/// against the columns of an Entity.
+///
+/// Only the columns whitelisted with [`QueryParams::filterable`] and [`QueryParams::sortable`]
+/// are accepted, and each operator is checked against the [`ColumnType`] of the column.
+/// Columns are named as in the database, see `Column::from_str`.
+///
+/// The query string form is
+///
+/// - `filter[<column>]=<value>`, the same as `filter[<column>][eq]=<value>`
+/// - `filter[<column>][<op>]=<value>`, where `<op>` is one of `eq`, `ne`, `gt`, `gte`, `lt`, `lte`,
+///   `contains`, `starts_with`, `ends_with` (text columns only, matching `%` and `_` as they are),
+///   `in`, `nin` (with comma separated values) and `null` (with `true` or `false`)
+/// - `sort=<column>,-<column>`, where `-` sorts in descending order
+///
+/// Any other parameter is ignored. The JSON form is an object with the same structure, e.g.
+/// `{"filter": {"name": {"contains": "x"}, "cake_id": {"in": [1, 2]}, "id": 3}, "sort": ["-id"]}`,
+/// where a JSON `null` is the same as the `null` operator.
+///
+/// All the filters must hold.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend, QueryParamsErr};
+///
+/// let params = QueryParams::<fruit::Entity>::new()
+///     .filterable([fruit::Column::Name, fruit::Column::CakeId])
+///     .sortable([fruit::Column::Id, fruit::Column::Name]);
+///
+/// let parsed = params
+///     .parse_query_string("filter[name][contains]=app&filter[cake_id][in]=1,2&sort=-id")?;
+///
+/// assert_eq!(
+///     parsed
+///         .apply(fruit::Entity::find())
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     [
+///         r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+///         r#"WHERE "fruit"."name" LIKE '%app%' ESCAPE E'\\'"#,
+///         r#"AND "fruit"."cake_id" IN (1, 2)"#,
+///         r#"ORDER BY "fruit"."id" DESC"#,
+///     ]
+///     .join(" ")
+/// );
+///
+/// assert_eq!(
+///     params.parse_query_string("filter[id]=1").unwrap_err(),
+///     QueryParamsErr::NotFilterable("id".to_owned())
+/// );
+/// assert_eq!(
+///     params.parse_query_string("filter[cake_id][contains]=1").unwrap_err(),
+///     QueryParamsErr::UnsupportedOperator {
+///         column: "cake_id".to_owned(),
+///         op: "contains".to_owned()
+///     }
+/// );
+/// # Ok::<(), QueryParamsErr>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct QueryParams<E>
+where
+    E: EntityTrait,
+{
+    filterable: Vec<E::Column>,
+    sortable: Vec<E::Column>,
+}
+
+/// The filters and the sorting parsed by [`QueryParams`]
+#[derive(Debug, Clone)]
+pub struct ParsedParams<E>
+where
+    E: EntityTrait,
+{
+    /// The filters, all of which must hold
+    pub condition: Condition,
+    /// The columns to sort by, in order
+    pub orders: Vec<(E::Column, Order)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    Contains,
+    StartsWith,
+    EndsWith,
+    In,
+    NotIn,
+    Null,
+}
+
+/// The argument of an operator, as text
+#[derive(Debug)]
+enum Arg {
+    /// A JSON `null`
+    #[cfg(feature = "with-json")]
+    Null,
+    Text(String),
+    List(Vec<String>),
+}
+
+impl<E> Default for QueryParams<E>
+where
+    E: EntityTrait,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> QueryParams<E>
+where
+    E: EntityTrait,
+{
+    /// Accept neither filters nor sorting
+    pub fn new() -> Self {
+        Self {
+            filterable: Vec::new(),
+            sortable: Vec::new(),
+        }
+    }
+
+    /// Accept filters on these columns
+    pub fn filterable<I>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = E::Column>,
+    {
+        self.filterable.extend(columns);
+        self
+    }
+
+    /// Accept sorting by these columns
+    pub fn sortable<I>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = E::Column>,
+    {
+        self.sortable.extend(columns);
+        self
+    }
+
+    /// Parse a query string, with or without the leading `?`
+    pub fn parse_query_string(&self, query: &str) -> Result<ParsedParams<E>, QueryParamsErr> {
+        let query = query.strip_prefix('?').unwrap_or(query);
+        self.parse_pairs(url::form_urlencoded::parse(query.as_bytes()))
+    }
+
+    /// Parse the decoded key-value pairs of a query string
+    pub fn parse_pairs<I, K, V>(&self, pairs: I) -> Result<ParsedParams<E>, QueryParamsErr>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut parsed = ParsedParams::new();
+        for (key, value) in pairs {
+            let (key, value) = (key.as_ref(), value.as_ref());
+            if key == "sort" {
+                self.parse_sort(&mut parsed, value.split(','))?;
+            } else if let Some(filter) = key.strip_prefix("filter[") {
+                let filter = filter
+                    .strip_suffix(']')
+                    .ok_or_else(|| QueryParamsErr::Malformed(key.to_owned()))?;
+                let (column, op) = filter.split_once("][").unwrap_or((filter, "eq"));
+                let arg = match op {
+                    "in" | "nin" => Arg::List(value.split(',').map(str::to_owned).collect()),
+                    _ => Arg::Text(value.to_owned()),
+                };
+                parsed.condition = parsed.condition.add(self.filter(column, op, arg)?);
+            }
+        }
+        Ok(parsed)
+    }
+
+    /// Parse the JSON form, an object with an optional `filter` object and an optional `sort`,
+    /// which is either a comma separated string or an array of strings
+    #[cfg(feature = "with-json")]
+    pub fn parse_json(&self, json: &serde_json::Value) -> Result<ParsedParams<E>, QueryParamsErr> {
+        use serde_json::Value as Json;
+
+        let malformed = |key: &str| QueryParamsErr::Malformed(key.to_owned());
+        let json = json.as_object().ok_or_else(|| malformed("{}"))?;
+
+        let mut parsed = ParsedParams::new();
+        if let Some(filter) = json.get("filter") {
+            for (column, spec) in filter.as_object().ok_or_else(|| malformed("filter"))? {
+                let filters: Vec<(&str, &Json)> = match spec {
+                    Json::Object(ops) => ops.iter().map(|(op, arg)| (op.as_str(), arg)).collect(),
+                    arg => vec![("eq", arg)],
+                };
+                for (op, arg) in filters {
+                    let arg = json_arg(arg).ok_or_else(|| malformed(column))?;
+                    parsed.condition = parsed.condition.add(self.filter(column, op, arg)?);
+                }
+            }
+        }
+        match json.get("sort") {
+            None => {}
+            Some(Json::String(sort)) => self.parse_sort(&mut parsed, sort.split(','))?,
+            Some(Json::Array(sort)) => {
+                let sort = sort
+                    .iter()
+                    .map(Json::as_str)
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| malformed("sort"))?;
+                self.parse_sort(&mut parsed, sort)?;
+            }
+            Some(_) => return Err(malformed("sort")),
+        }
+        Ok(parsed)
+    }
+
+    fn parse_sort<'s, I>(
+        &self,
+        parsed: &mut ParsedParams<E>,
+        fields: I,
+    ) -> Result<(), QueryParamsErr>
+    where
+        I: IntoIterator<Item = &'s str>,
+    {
+        for field in fields.into_iter().map(str::trim).filter(|f| !f.is_empty()) {
+            let (name, order) = match field.strip_prefix('-') {
+                Some(name) => (name, Order::Desc),
+                None => (field.strip_prefix('+').unwrap_or(field), Order::Asc),
+            };
+            let column = column::<E>(name)?;
+            if !self.sortable.iter().any(|c| c.as_str() == column.as_str()) {
+                return Err(QueryParamsErr::NotSortable(name.to_owned()));
+            }
+            parsed.orders.push((column, order));
+        }
+        Ok(())
+    }
+
+    fn filter(&self, name: &str, op: &str, arg: Arg) -> Result<SimpleExpr, QueryParamsErr> {
+        let column = column::<E>(name)?;
+        if !self
+            .filterable
+            .iter()
+            .any(|c| c.as_str() == column.as_str())
+        {
+            return Err(QueryParamsErr::NotFilterable(name.to_owned()));
+        }
+        let parsed_op = Op::from_str(op)?;
+        let def = column.def();
+        let col_type = def.get_column_type();
+        if !parsed_op.supports(col_type) {
+            return Err(QueryParamsErr::UnsupportedOperator {
+                column: name.to_owned(),
+                op: op.to_owned(),
+            });
+        }
+
+        let invalid = |value: &str| QueryParamsErr::InvalidValue {
+            column: name.to_owned(),
+            value: value.to_owned(),
+        };
+        let value = |text: &str| {
+            let kind = value_kinds(col_type).into_iter().next();
+            kind.and_then(|kind| parse_value(&kind, Some(text)))
+                .ok_or_else(|| invalid(text))
+        };
+
+        let expr = match (parsed_op, arg) {
+            #[cfg(feature = "with-json")]
+            (Op::Eq, Arg::Null) => column.is_null(),
+            #[cfg(feature = "with-json")]
+            (Op::Ne, Arg::Null) => column.is_not_null(),
+            #[cfg(feature = "with-json")]
+            (_, Arg::Null) => return Err(invalid("null")),
+            (Op::In, Arg::List(list)) => column.is_in(
+                list.iter()
+                    .map(|v| value(v))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            (Op::NotIn, Arg::List(list)) => column.is_not_in(
+                list.iter()
+                    .map(|v| value(v))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            (Op::In | Op::NotIn, Arg::Text(text)) => return Err(invalid(&text)),
+            (_, Arg::List(list)) => return Err(invalid(&list.join(","))),
+            (Op::Null, Arg::Text(text)) => match text.as_str() {
+                "true" => column.is_null(),
+                "false" => column.is_not_null(),
+                _ => return Err(invalid(&text)),
+            },
+            (Op::Contains, Arg::Text(text)) => column.like(like("%", &text, "%")),
+            (Op::StartsWith, Arg::Text(text)) => column.like(like("", &text, "%")),
+            (Op::EndsWith, Arg::Text(text)) => column.like(like("%", &text, "")),
+            (Op::Eq, Arg::Text(text)) => column.eq(value(&text)?),
+            (Op::Ne, Arg::Text(text)) => column.ne(value(&text)?),
+            (Op::Gt, Arg::Text(text)) => column.gt(value(&text)?),
+            (Op::Gte, Arg::Text(text)) => column.gte(value(&text)?),
+            (Op::Lt, Arg::Text(text)) => column.lt(value(&text)?),
+            (Op::Lte, Arg::Text(text)) => column.lte(value(&text)?),
+        };
+        Ok(expr)
+    }
+}
+
+impl<E> ParsedParams<E>
+where
+    E: EntityTrait,
+{
+    fn new() -> Self {
+        Self {
+            condition: Condition::all(),
+            orders: Vec::new(),
+        }
+    }
+
+    /// Apply the filters and the sorting to a query
+    pub fn apply<Q>(self, mut query: Q) -> Q
+    where
+        Q: QueryFilter + QueryOrder,
+    {
+        if !self.condition.is_empty() {
+            query = query.filter(self.condition);
+        }
+        for (column, order) in self.orders {
+            query = query.order_by(column, order);
+        }
+        query
+    }
+}
+
+impl FromStr for Op {
+    type Err = QueryParamsErr;
+
+    fn from_str(op: &str) -> Result<Self, Self::Err> {
+        Ok(match op {
+            "eq" => Self::Eq,
+            "ne" => Self::Ne,
+            "gt" => Self::Gt,
+            "gte" => Self::Gte,
+            "lt" => Self::Lt,
+            "lte" => Self::Lte,
+            "contains" => Self::Contains,
+            "starts_with" => Self::StartsWith,
+            "ends_with" => Self::EndsWith,
+            "in" => Self::In,
+            "nin" => Self::NotIn,
+            "null" => Self::Null,
+            _ => return Err(QueryParamsErr::UnknownOperator(op.to_owned())),
+        })
+    }
+}
+
+impl Op {
+    fn supports(self, col_type: &ColumnType) -> bool {
+        let text = matches!(
+            col_type,
+            ColumnType::Char(_) | ColumnType::String(_) | ColumnType::Text
+        );
+        let opaque = matches!(
+            col_type,
+            ColumnType::Json
+                | ColumnType::JsonBinary
+                | ColumnType::Array(_)
+                | ColumnType::Binary(_)
+                | ColumnType::VarBinary(_)
+                | ColumnType::Blob
+        );
+        match self {
+            Self::Contains | Self::StartsWith | Self::EndsWith => text,
+            Self::Gt | Self::Gte | Self::Lt | Self::Lte => {
+                !opaque && !matches!(col_type, ColumnType::Boolean)
+            }
+            Self::Eq | Self::Ne | Self::In | Self::NotIn => !opaque,
+            Self::Null => true,
+        }
+    }
+}
+
+fn column<E>(name: &str) -> Result<E::Column, QueryParamsErr>
+where
+    E: EntityTrait,
+{
+    E::Column::from_str(name).map_err(|_| QueryParamsErr::UnknownColumn(name.to_owned()))
+}
+
+/// A `LIKE` pattern matching the text as it is, with the wildcards around it
+fn like(before: &str, text: &str, after: &str) -> LikeExpr {
+    let text = text
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    LikeExpr::new(format!("{before}{text}{after}")).escape('\\')
+}
+
+#[cfg(feature = "with-json")]
+fn json_arg(arg: &serde_json::Value) -> Option<Arg> {
+    use serde_json::Value as Json;
+
+    fn scalar(arg: &Json) -> Option<String> {
+        match arg {
+            Json::Bool(b) => Some(b.to_string()),
+            Json::Number(n) => Some(n.to_string()),
+            Json::String(s) => Some(s.clone()),
+            _ => None,
+        }
+    }
+
+    match arg {
+        Json::Null => Some(Arg::Null),
+        Json::Array(items) => items
+            .iter()
+            .map(scalar)
+            .collect::<Option<_>>()
+            .map(Arg::List),
+        arg => scalar(arg).map(Arg::Text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, fruit};
+    use crate::{
+        DbBackend, EntityTrait, Iterable, QueryParams, QueryParamsErr, QueryTrait, Statement,
+    };
+
+    fn params() -> QueryParams<fruit::Entity> {
+        QueryParams::new()
+            .filterable(fruit::Column::iter())
+            .sortable([fruit::Column::Name])
+    }
+
+    #[test]
+    fn parse_query_string() -> Result<(), QueryParamsErr> {
+        let parsed = params().parse_query_string(
+            "?filter[id][gte]=2&filter[name][starts_with]=Red%20A&filter[cake_id][null]=false&page=3&sort=+name",
+        )?;
+        assert_eq!(
+            parsed
+                .apply(fruit::Entity::find())
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`",
+                "WHERE `fruit`.`id` >= 2 AND `fruit`.`name` LIKE 'Red A%' ESCAPE '\\\\' AND `fruit`.`cake_id` IS NOT NULL",
+                "ORDER BY `fruit`.`name` ASC",
+            ]
+            .join(" ")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_query_string_escapes_like() -> Result<(), QueryParamsErr> {
+        let parsed = params()
+            .parse_query_string("filter[name][contains]=100%25&filter[name][ends_with]=_a%5Cb")?;
+        assert_eq!(
+            parsed
+                .apply(fruit::Entity::find())
+                .build(DbBackend::Sqlite),
+            Statement::from_sql_and_values(
+                DbBackend::Sqlite,
+                [
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                    r#"WHERE "fruit"."name" LIKE ? ESCAPE '\' AND "fruit"."name" LIKE ? ESCAPE '\'"#,
+                ]
+                .join(" "),
+                [r"%100\%%".into(), r"%\_a\\b".into()]
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_query_string_errors() {
+        let params = params();
+        let err = |query: &str| params.parse_query_string(query).unwrap_err();
+
+        assert_eq!(
+            err("filter[color]=red"),
+            QueryParamsErr::UnknownColumn("color".to_owned())
+        );
+        assert_eq!(
+            err("filter[id][like]=1"),
+            QueryParamsErr::UnknownOperator("like".to_owned())
+        );
+        assert_eq!(
+            err("filter[id]=one"),
+            QueryParamsErr::InvalidValue {
+                column: "id".to_owned(),
+                value: "one".to_owned()
+            }
+        );
+        assert_eq!(
+            err("filter[id"),
+            QueryParamsErr::Malformed("filter[id".to_owned())
+        );
+        assert_eq!(
+            err("sort=-id"),
+            QueryParamsErr::NotSortable("id".to_owned())
+        );
+
+        // another Entity
+        assert_eq!(
+            QueryParams::<cake::Entity>::new()
+                .parse_query_string("filter[id]=1")
+                .unwrap_err(),
+            QueryParamsErr::NotFilterable("id".to_owned())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-json")]
+    fn parse_json() -> Result<(), QueryParamsErr> {
+        let parsed = params().parse_json(&serde_json::json!({
+            "filter": {
+                "cake_id": null,
+                "id": { "nin": [1, 2], "lt": 10 },
+                "name": "Apple",
+            },
+            "sort": ["-name"],
+        }))?;
+        assert_eq!(
+            parsed
+                .apply(fruit::Entity::find())
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                r#"WHERE "fruit"."cake_id" IS NULL"#,
+                r#"AND "fruit"."id" < 10 AND "fruit"."id" NOT IN (1, 2)"#,
+                r#"AND "fruit"."name" = 'Apple'"#,
+                r#"ORDER BY "fruit"."name" DESC"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            params()
+                .parse_json(&serde_json::json!({ "sort": 1 }))
+                .unwrap_err(),
+            QueryParamsErr::Malformed("sort".to_owned())
+        );
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{ColumnAsExpr, ColumnTrait, DbBackend, IntoIdentity, QuerySelect, Statement};
-use sea_query::QueryStatementBuilder;
+use sea_query::{QueryStatementBuilder, SimpleExpr, WindowStatement};
 
 /// A Trait for any type performing queries on a Model or ActiveModel
 pub trait QueryTrait {
@@ -70,6 +70,15 @@ pub trait SelectColumns {
     where
         C: ColumnAsExpr,
         I: IntoIdentity;
+
+    /// Add a window function call with alias
+    ///
+    /// For more detail, please visit [QuerySelect::expr_window_as]
+    fn select_window_as<T, W, I>(self, expr: T, window: W, alias: I) -> Self
+    where
+        T: Into<SimpleExpr>,
+        W: Into<WindowStatement>,
+        I: IntoIdentity;
 }
 
 impl<S> SelectColumns for S
@@ -87,4 +96,13 @@ where
     {
         QuerySelect::column_as(self, col, alias)
     }
+
+    fn select_window_as<T, W, I>(self, expr: T, window: W, alias: I) -> Self
+    where
+        T: Into<SimpleExpr>,
+        W: Into<WindowStatement>,
+        I: IntoIdentity,
+    {
+        QuerySelect::expr_window_as(self, expr, window, alias)
+    }
 }
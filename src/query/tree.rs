@@ -0,0 +1,422 @@
+use crate::{
+    unpack_table_ref, ColumnTrait, ColumnType, DbBackend, EntityTrait, IdenStatic, Identity,
+    Iterable, QueryFilter, RelationDef, Select, Statement,
+};
+use core::marker::PhantomData;
+use sea_query::{
+    Alias, BinOper, CommonTableExpression, DynIden, Expr, Func, IntoIden, JoinType, Order, SeaRc,
+    SelectStatement, SimpleExpr, UnionType, Value, WithClause,
+};
+
+/// The direction to walk a self-referencing relation in a [SelectTree]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreeDirection {
+    /// From parents to their children
+    Descendants,
+    /// From children to their parents
+    Ancestors,
+}
+
+/// Defines a recursive query over a self-referencing relation.
+///
+/// Each row is returned along with its `depth`, counted from the starting rows,
+/// and its `path`, the keys visited from the starting row joined by `/`.
+/// A row already in the path is not visited again, so that a cycle in the data ends the walk.
+///
+/// Descendants are returned depth first, with siblings in the order of their keys;
+/// integer keys are zero-padded to 20 digits for sorting, so they must not be negative.
+/// On MySQL the path is limited to 65535 characters, as it is cast to `CHAR(65535)`.
+#[derive(Clone, Debug)]
+pub struct SelectTree<E>
+where
+    E: EntityTrait,
+{
+    pub(crate) query: SelectStatement,
+    /// The column referencing the parent row
+    pub(crate) parent_col: Identity,
+    /// The column being referenced by the child rows
+    pub(crate) key_col: Identity,
+    pub(crate) direction: TreeDirection,
+    pub(crate) max_depth: Option<u32>,
+    pub(crate) entity: PhantomData<E>,
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Find the rows selected by this query and all of their descendants,
+    /// walking the given self-referencing relation recursively.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the relation is not self-referencing.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::category, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     category::Entity::find_by_id(1)
+    ///         .find_descendants(category::Relation::Parent.def())
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"WITH RECURSIVE "tree" ("id", "parent_id", "name", "depth", "path", "sort") AS"#,
+    ///         r#"(SELECT "category"."id", "category"."parent_id", "category"."name", 0 AS "depth","#,
+    ///         r#"CAST("category"."id" AS TEXT) AS "path", LPAD(CAST("category"."id" AS TEXT), 20, '0') AS "sort""#,
+    ///         r#"FROM "category" WHERE "category"."id" = 1"#,
+    ///         r#"UNION ALL (SELECT "category"."id", "category"."parent_id", "category"."name", "tree"."depth" + 1,"#,
+    ///         r#"("tree"."path" || '/') || CAST("category"."id" AS TEXT),"#,
+    ///         r#"("tree"."sort" || '/') || LPAD(CAST("category"."id" AS TEXT), 20, '0') FROM "category""#,
+    ///         r#"INNER JOIN "tree" ON "category"."parent_id" = "tree"."id""#,
+    ///         r#"WHERE STRPOS(('/' || "tree"."path") || '/', ('/' || CAST("category"."id" AS TEXT)) || '/') = 0))"#,
+    ///         r#"SELECT "tree"."id", "tree"."parent_id", "tree"."name", "tree"."depth", "tree"."path""#,
+    ///         r#"FROM "tree" ORDER BY "tree"."sort" ASC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn find_descendants(self, rel: RelationDef) -> SelectTree<E> {
+        SelectTree::new(self.query, rel, TreeDirection::Descendants)
+    }
+
+    /// Find the rows selected by this query and all of their ancestors,
+    /// walking the given self-referencing relation recursively.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the relation is not self-referencing.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::category, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     category::Entity::find_by_id(5)
+    ///         .find_ancestors(category::Relation::Parent.def())
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         r#"WITH RECURSIVE `tree` (`id`, `parent_id`, `name`, `depth`, `path`) AS"#,
+    ///         r#"(SELECT `category`.`id`, `category`.`parent_id`, `category`.`name`, 0 AS `depth`,"#,
+    ///         r#"CAST(`category`.`id` AS CHAR(65535)) AS `path` FROM `category` WHERE `category`.`id` = 5"#,
+    ///         r#"UNION ALL (SELECT `category`.`id`, `category`.`parent_id`, `category`.`name`, `tree`.`depth` + 1,"#,
+    ///         r#"CONCAT(`tree`.`path`, '/', CAST(`category`.`id` AS CHAR(65535))) FROM `category`"#,
+    ///         r#"INNER JOIN `tree` ON `category`.`id` = `tree`.`parent_id`"#,
+    ///         r#"WHERE INSTR(CONCAT('/', `tree`.`path`, '/'), CONCAT('/', CAST(`category`.`id` AS CHAR(65535)), '/')) = 0))"#,
+    ///         r#"SELECT `tree`.`id`, `tree`.`parent_id`, `tree`.`name`, `tree`.`depth`, `tree`.`path`"#,
+    ///         r#"FROM `tree` ORDER BY `tree`.`depth` ASC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn find_ancestors(self, rel: RelationDef) -> SelectTree<E> {
+        SelectTree::new(self.query, rel, TreeDirection::Ancestors)
+    }
+
+    /// Find the root rows, i.e. those without a parent, among the rows selected by this query,
+    /// together with all of their descendants.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the relation is not self-referencing.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::category, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     category::Entity::find()
+    ///         .find_tree(category::Relation::Parent.def())
+    ///         .max_depth(3)
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     [
+    ///         r#"WITH RECURSIVE "tree" ("id", "parent_id", "name", "depth", "path", "sort") AS"#,
+    ///         r#"(SELECT "category"."id", "category"."parent_id", "category"."name", 0 AS "depth","#,
+    ///         r#"CAST("category"."id" AS TEXT) AS "path", PRINTF('%020d', "category"."id") AS "sort""#,
+    ///         r#"FROM "category" WHERE "category"."parent_id" IS NULL"#,
+    ///         r#"UNION ALL SELECT "category"."id", "category"."parent_id", "category"."name", "tree"."depth" + 1,"#,
+    ///         r#"("tree"."path" || '/') || CAST("category"."id" AS TEXT),"#,
+    ///         r#"("tree"."sort" || '/') || PRINTF('%020d', "category"."id") FROM "category""#,
+    ///         r#"INNER JOIN "tree" ON "category"."parent_id" = "tree"."id""#,
+    ///         r#"WHERE INSTR(('/' || "tree"."path") || '/', ('/' || CAST("category"."id" AS TEXT)) || '/') = 0"#,
+    ///         r#"AND "tree"."depth" < 3)"#,
+    ///         r#"SELECT "tree"."id", "tree"."parent_id", "tree"."name", "tree"."depth", "tree"."path""#,
+    ///         r#"FROM "tree" ORDER BY "tree"."sort" ASC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn find_tree(self, rel: RelationDef) -> SelectTree<E> {
+        let mut tree = SelectTree::new(self.query, rel, TreeDirection::Descendants);
+        for col in tree.parent_col.clone() {
+            tree.query
+                .and_where(Expr::col((E::default().into_iden(), col)).is_null());
+        }
+        tree
+    }
+}
+
+impl<E> SelectTree<E>
+where
+    E: EntityTrait,
+{
+    fn new(mut query: SelectStatement, rel: RelationDef, direction: TreeDirection) -> Self {
+        let rel = if rel.is_owner { rel.rev() } else { rel };
+        assert!(
+            unpack_table_ref(&rel.from_tbl).to_string()
+                == unpack_table_ref(&rel.to_tbl).to_string(),
+            "Relation is not self-referencing"
+        );
+        query.clear_order_by().reset_limit().reset_offset();
+        Self {
+            query,
+            parent_col: rel.from_col,
+            key_col: rel.to_col,
+            direction,
+            max_depth: None,
+            entity: PhantomData,
+        }
+    }
+
+    /// Stop walking the relation after `max_depth` levels from the starting rows.
+    pub fn max_depth(mut self, max_depth: u32) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Build the recursive query for the given database backend
+    pub fn into_query(self, db_backend: DbBackend) -> SelectStatement {
+        let table = E::default().into_iden();
+        let tree = Alias::new("tree").into_iden();
+        let entity_cols = || E::Column::iter().map(|col| Expr::col((E::default(), col)));
+
+        let mut anchor = self.query;
+        anchor
+            .clear_selects()
+            .exprs(entity_cols())
+            .expr_as(
+                SimpleExpr::Constant(Value::Int(Some(0))),
+                Alias::new("depth"),
+            )
+            .expr_as(
+                path_segment(db_backend, &table, &self.key_col),
+                Alias::new("path"),
+            );
+        // descendants are sorted by a path of their keys with integers zero-padded,
+        // cast on MySQL as the anchor gives the width of the column
+        let sort_segment = || sort_segment::<E>(db_backend, &table, &self.key_col);
+        let sorted = self.direction == TreeDirection::Descendants;
+        if sorted {
+            let sort = match db_backend {
+                DbBackend::MySql => sort_segment().cast_as(text_type(db_backend)),
+                DbBackend::Postgres | DbBackend::Sqlite => sort_segment(),
+            };
+            anchor.expr_as(sort, Alias::new("sort"));
+        }
+
+        let (join_from, join_to) = match self.direction {
+            TreeDirection::Descendants => (&self.parent_col, &self.key_col),
+            TreeDirection::Ancestors => (&self.key_col, &self.parent_col),
+        };
+        let mut on = sea_query::Condition::all();
+        for (from, to) in join_from.clone().into_iter().zip(join_to.clone()) {
+            on = on.add(Expr::col((SeaRc::clone(&table), from)).equals((SeaRc::clone(&tree), to)));
+        }
+
+        let mut recursive = SelectStatement::new();
+        recursive
+            .exprs(entity_cols())
+            .expr(
+                Expr::col((SeaRc::clone(&tree), Alias::new("depth")))
+                    .add(SimpleExpr::Constant(Value::Int(Some(1)))),
+            )
+            .expr(concat(
+                db_backend,
+                [
+                    Expr::col((SeaRc::clone(&tree), Alias::new("path"))).into(),
+                    SimpleExpr::Constant("/".into()),
+                    path_segment(db_backend, &table, &self.key_col),
+                ],
+            ));
+        if sorted {
+            recursive.expr(concat(
+                db_backend,
+                [
+                    Expr::col((SeaRc::clone(&tree), Alias::new("sort"))).into(),
+                    SimpleExpr::Constant("/".into()),
+                    sort_segment(),
+                ],
+            ));
+        }
+        recursive
+            .from(E::default().table_ref())
+            .join(JoinType::InnerJoin, SeaRc::clone(&tree), on)
+            .and_where(not_in_path(db_backend, &table, &tree, &self.key_col));
+        if let Some(max_depth) = self.max_depth {
+            recursive
+                .and_where(Expr::col((SeaRc::clone(&tree), Alias::new("depth"))).lt(max_depth));
+        }
+
+        let mut cte = CommonTableExpression::new();
+        cte.query(anchor.union(UnionType::All, recursive).to_owned())
+            .columns(
+                E::Column::iter()
+                    .map(|col| col.into_iden())
+                    .chain([
+                        Alias::new("depth").into_iden(),
+                        Alias::new("path").into_iden(),
+                    ])
+                    .chain(sorted.then(|| Alias::new("sort").into_iden())),
+            )
+            .table_name(SeaRc::clone(&tree));
+
+        let order_by = match self.direction {
+            TreeDirection::Descendants => "sort",
+            TreeDirection::Ancestors => "depth",
+        };
+
+        let mut query = SelectStatement::new();
+        query
+            .exprs(
+                E::Column::iter().map(|col| col.select_as(Expr::col((SeaRc::clone(&tree), col)))),
+            )
+            .expr(Expr::col((SeaRc::clone(&tree), Alias::new("depth"))))
+            .expr(Expr::col((SeaRc::clone(&tree), Alias::new("path"))))
+            .from(SeaRc::clone(&tree))
+            .order_by((tree, Alias::new(order_by)), Order::Asc)
+            .with_cte(WithClause::new().recursive(true).cte(cte).to_owned());
+        query
+    }
+
+    /// Build the recursive query as [`Statement`]
+    pub fn build(&self, db_backend: DbBackend) -> Statement {
+        db_backend.build(&self.clone().into_query(db_backend))
+    }
+}
+
+impl<E> QueryFilter for SelectTree<E>
+where
+    E: EntityTrait,
+{
+    type QueryStatement = SelectStatement;
+
+    /// Filter the starting rows of the recursive query
+    fn query(&mut self) -> &mut SelectStatement {
+        &mut self.query
+    }
+}
+
+fn text_type(db_backend: DbBackend) -> Alias {
+    match db_backend {
+        DbBackend::MySql => Alias::new("CHAR(65535)"),
+        DbBackend::Postgres | DbBackend::Sqlite => Alias::new("TEXT"),
+    }
+}
+
+/// The key columns of a row cast as text, separated by `,`
+fn path_segment(db_backend: DbBackend, table: &DynIden, cols: &Identity) -> SimpleExpr {
+    let mut parts = Vec::new();
+    for (i, col) in cols.clone().into_iter().enumerate() {
+        if i > 0 {
+            parts.push(SimpleExpr::Constant(",".into()));
+        }
+        parts.push(Expr::col((SeaRc::clone(table), col)).cast_as(text_type(db_backend)));
+    }
+    concat(db_backend, parts)
+}
+
+/// [path_segment] with the integer columns zero-padded to 20 digits, so that the text sorts like the keys
+fn sort_segment<E>(db_backend: DbBackend, table: &DynIden, cols: &Identity) -> SimpleExpr
+where
+    E: EntityTrait,
+{
+    let is_integer = |col: &DynIden| {
+        E::Column::iter().any(|c| {
+            c.as_str() == col.to_string()
+                && matches!(
+                    c.def().get_column_type(),
+                    ColumnType::TinyInteger
+                        | ColumnType::SmallInteger
+                        | ColumnType::Integer
+                        | ColumnType::BigInteger
+                        | ColumnType::TinyUnsigned
+                        | ColumnType::SmallUnsigned
+                        | ColumnType::Unsigned
+                        | ColumnType::BigUnsigned
+                )
+        })
+    };
+    let mut parts = Vec::new();
+    for (i, col) in cols.clone().into_iter().enumerate() {
+        if i > 0 {
+            parts.push(SimpleExpr::Constant(",".into()));
+        }
+        let padded = is_integer(&col);
+        let col = Expr::col((SeaRc::clone(table), col));
+        parts.push(match (padded, db_backend) {
+            (false, _) => col.cast_as(text_type(db_backend)),
+            (true, DbBackend::Postgres | DbBackend::MySql) => Func::cust(Alias::new("LPAD"))
+                .args([
+                    col.cast_as(text_type(db_backend)),
+                    SimpleExpr::Constant(Value::Int(Some(20))),
+                    SimpleExpr::Constant("0".into()),
+                ])
+                .into(),
+            (true, DbBackend::Sqlite) => Func::cust(Alias::new("PRINTF"))
+                .args([SimpleExpr::Constant("%020d".into()), col.into()])
+                .into(),
+        });
+    }
+    concat(db_backend, parts)
+}
+
+/// Whether the key of a row is not among the keys of the path, by searching for `/key/` in `/path/`
+fn not_in_path(
+    db_backend: DbBackend,
+    table: &DynIden,
+    tree: &DynIden,
+    cols: &Identity,
+) -> SimpleExpr {
+    let separator = || SimpleExpr::Constant("/".into());
+    let path = concat(
+        db_backend,
+        [
+            separator(),
+            Expr::col((SeaRc::clone(tree), Alias::new("path"))).into(),
+            separator(),
+        ],
+    );
+    let key = concat(
+        db_backend,
+        [
+            separator(),
+            path_segment(db_backend, table, cols),
+            separator(),
+        ],
+    );
+    let position = match db_backend {
+        DbBackend::Postgres => Func::cust(Alias::new("STRPOS")),
+        DbBackend::MySql | DbBackend::Sqlite => Func::cust(Alias::new("INSTR")),
+    };
+    Expr::expr(position.arg(path).arg(key)).eq(SimpleExpr::Constant(Value::Int(Some(0))))
+}
+
+fn concat<I>(db_backend: DbBackend, parts: I) -> SimpleExpr
+where
+    I: IntoIterator<Item = SimpleExpr>,
+{
+    let mut parts = parts.into_iter();
+    match db_backend {
+        DbBackend::MySql => {
+            let mut parts: Vec<_> = parts.collect();
+            match parts.len() {
+                1 => parts.remove(0),
+                _ => Func::cust(Alias::new("CONCAT")).args(parts).into(),
+            }
+        }
+        DbBackend::Postgres | DbBackend::Sqlite => {
+            let first = parts.next().unwrap_or(SimpleExpr::Constant("".into()));
+            parts.fold(first, |acc, part| acc.binary(BinOper::Custom("||"), part))
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{EntityTrait, Select};
+use core::marker::PhantomData;
+use sea_query::{Alias, Asterisk, SelectStatement, UnionType};
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Combine with another Select of the same Entity with `UNION`, removing duplicate rows.
+    ///
+    /// Both Selects must select the same columns, e.g. all the columns of the Entity,
+    /// or the same columns of a partial model.
+    /// The combined query is wrapped in a subquery aliased as the table of the Entity,
+    /// so that the result is an ordinary Select which can be filtered, ordered, paginated
+    /// and streamed on its own, and converted with `into_model`, `into_partial_model` and the like.
+    /// Any ordering or limit should thus be applied to the combined Select.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.contains("Cheese"))
+    ///         .union(cake::Entity::find().filter(cake::Column::Id.lt(3)))
+    ///         .order_by_asc(cake::Column::Name)
+    ///         .limit(10)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake".* FROM (SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE '%Cheese%'"#,
+    ///         r#"UNION (SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" < 3)) AS "cake""#,
+    ///         r#"ORDER BY "cake"."name" ASC LIMIT 10"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn union(self, other: Select<E>) -> Self {
+        self.combine(UnionType::Distinct, other)
+    }
+
+    /// Combine with another Select of the same Entity with `UNION ALL`, keeping duplicate rows.
+    /// See [`Select::union`].
+    pub fn union_all(self, other: Select<E>) -> Self {
+        self.combine(UnionType::All, other)
+    }
+
+    /// Combine with another Select of the same Entity with `INTERSECT`,
+    /// keeping the rows returned by both. See [`Select::union`].
+    ///
+    /// MySQL supports `INTERSECT` since 8.0.31.
+    pub fn intersect(self, other: Select<E>) -> Self {
+        self.combine(UnionType::Intersect, other)
+    }
+
+    /// Combine with another Select of the same Entity with `EXCEPT`,
+    /// keeping the rows not returned by the other. See [`Select::union`].
+    ///
+    /// MySQL supports `EXCEPT` since 8.0.31.
+    pub fn except(self, other: Select<E>) -> Self {
+        self.combine(UnionType::Except, other)
+    }
+
+    fn combine(self, union_type: UnionType, other: Select<E>) -> Self {
+        let mut combined = self.query;
+        combined.union(union_type, other.query);
+
+        let alias = Alias::new(E::default().table_name());
+        let mut query = SelectStatement::new();
+        query
+            .column((alias.clone(), Asterisk))
+            .from_subquery(combined, alias);
+
+        Self {
+            query,
+            entity: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, cake_model};
+    use crate::{
+        entity::prelude::*, DbBackend, MockDatabase, PaginatorTrait, QueryOrder, QuerySelect,
+        QueryTrait, Transaction,
+    };
+
+    #[test]
+    fn intersect_except_sqlite() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter(cake::Column::Id.gt(1))
+                .intersect(cake::Entity::find().filter(cake::Column::Id.lt(5)))
+                .except(cake::Entity::find().filter(cake::Column::Id.eq(3)))
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"SELECT "cake".* FROM (SELECT "cake".* FROM"#,
+                r#"(SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" > 1"#,
+                r#"INTERSECT SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" < 5) AS "cake""#,
+                r#"EXCEPT SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = 3) AS "cake""#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[smol_potat::test]
+    async fn union_all_into_tuple() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([[
+                maplit::btreemap! { "name" => Into::<Value>::into("Apple Pie") },
+                maplit::btreemap! { "name" => Into::<Value>::into("Apple Pie") },
+            ]])
+            .into_connection();
+
+        let names: Vec<String> = cake::Entity::find()
+            .select_only()
+            .column(cake::Column::Name)
+            .union_all(
+                cake::Entity::find()
+                    .select_only()
+                    .column(cake::Column::Name),
+            )
+            .into_tuple()
+            .all(&db)
+            .await?;
+        assert_eq!(names, ["Apple Pie", "Apple Pie"]);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::MySql,
+                [
+                    "SELECT `cake`.* FROM (SELECT `cake`.`name` FROM `cake`",
+                    "UNION ALL (SELECT `cake`.`name` FROM `cake`)) AS `cake`",
+                ]
+                .join(" ")
+                .as_str(),
+                []
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn union_paginate_and_cursor() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake_model(1), cake_model(2)]])
+            .append_query_results([[cake_model(3)]])
+            .into_connection();
+
+        let union = cake::Entity::find()
+            .filter(cake::Column::Id.lt(3))
+            .union(cake::Entity::find().filter(cake::Column::Id.gt(5)));
+
+        assert_eq!(
+            union
+                .clone()
+                .order_by_asc(cake::Column::Id)
+                .paginate(&db, 2)
+                .fetch_page(0)
+                .await?,
+            [cake_model(1), cake_model(2)]
+        );
+        assert_eq!(
+            union
+                .cursor_by(cake::Column::Id)
+                .after(2)
+                .first(1)
+                .all(&db)
+                .await?,
+            [cake_model(3)]
+        );
+
+        let inner = [
+            r#"FROM (SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" < $1"#,
+            r#"UNION (SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" > $2)) AS "cake""#,
+        ]
+        .join(" ");
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    format!(r#"SELECT "cake".* {inner} ORDER BY "cake"."id" ASC LIMIT $3 OFFSET $4"#)
+                        .as_str(),
+                    [3.into(), 5.into(), 2u64.into(), 0u64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    format!(
+                        r#"SELECT "cake".* {inner} WHERE "cake"."id" > $3 ORDER BY "cake"."id" ASC LIMIT $4"#
+                    )
+                    .as_str(),
+                    [3.into(), 5.into(), 2.into(), 1u64.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -1,9 +1,13 @@
 use crate::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, Iterable, PrimaryKeyToColumn,
-    QueryFilter, QueryTrait,
+    query::helper::{joined_query, unpack_table_alias, unpack_table_ref},
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbBackend, DbErr, EntityName, EntityTrait,
+    IdenStatic, Iterable, PrimaryKeyToColumn, QueryFilter, QueryTrait, RelationDef, Statement,
 };
 use core::marker::PhantomData;
-use sea_query::{Expr, IntoIden, SimpleExpr, UpdateStatement};
+use sea_query::{
+    Alias, CaseStatement, ColumnRef, Condition, DynIden, Expr, IntoCondition, IntoIden, SimpleExpr,
+    TableRef, UpdateStatement, Value, ValueTuple,
+};
 
 /// Defines a structure to perform UPDATE query operations on a ActiveModel
 #[derive(Clone, Debug)]
@@ -29,6 +33,33 @@ where
     pub(crate) entity: PhantomData<E>,
 }
 
+/// Defines an UPDATE operation on multiple ActiveModels, joined to the tables
+/// of other Entities, see [`UpdateMany::join`]
+#[derive(Clone, Debug)]
+pub struct JoinedUpdateMany<E>
+where
+    E: EntityTrait,
+{
+    /// The statement before the joins, with the values and filters on the Entity alone
+    pub(crate) query: UpdateStatement,
+    /// The joined tables and their join conditions
+    pub(crate) joins: Vec<(TableRef, Condition)>,
+    /// The values set after the joins, which may refer to the joined tables
+    pub(crate) values: Vec<(DynIden, SimpleExpr)>,
+    /// The filters added after the joins, which may refer to the joined tables
+    pub(crate) condition: Condition,
+    pub(crate) entity: PhantomData<E>,
+}
+
+/// Defines an UPDATE operation on many ActiveModels, each with its own values
+#[derive(Clone, Debug)]
+pub struct UpdateManyModels<A>
+where
+    A: ActiveModelTrait,
+{
+    pub(crate) models: Vec<A>,
+}
+
 impl Update {
     /// Update one ActiveModel
     ///
@@ -83,6 +114,76 @@ impl Update {
             entity: PhantomData,
         }
     }
+
+    /// Update many ActiveModels with different values, in as few statements as possible.
+    ///
+    /// Only the columns a model has `Set` are updated. On MySQL and SQLite, the models are
+    /// updated with one statement, with a `CASE WHEN` per column listing the models having
+    /// the column set. On Postgres, the models are grouped by the columns they have set, and
+    /// each group is updated with one `UPDATE ... FROM (VALUES ...)` statement. The statements
+    /// are split into chunks below the bind parameter limit of the backend; use
+    /// [`UpdateManyModels::exec_in_transaction`] to update all of the models or none.
+    ///
+    /// Building the statements fails with [`DbErr::UpdateGetPrimaryKey`] if the primary key
+    /// of a model is not set.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// # fn main() -> Result<(), sea_orm::DbErr> {
+    /// let models = [
+    ///     fruit::ActiveModel {
+    ///         id: Unchanged(1),
+    ///         name: Set("Apple".to_owned()),
+    ///         cake_id: NotSet,
+    ///     },
+    ///     fruit::ActiveModel {
+    ///         id: Unchanged(2),
+    ///         name: Set("Orange".to_owned()),
+    ///         cake_id: NotSet,
+    ///     },
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     Update::many_models(models.clone())
+    ///         .build(DbBackend::Postgres)?
+    ///         .iter()
+    ///         .map(ToString::to_string)
+    ///         .collect::<Vec<_>>(),
+    ///     [[
+    ///         r#"UPDATE "fruit" SET "name" = "v"."column2""#,
+    ///         r#"FROM (VALUES (1, 'Apple'), (2, 'Orange')) AS "v""#,
+    ///         r#"WHERE "fruit"."id" = "v"."column1""#,
+    ///     ]
+    ///     .join(" ")]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Update::many_models(models)
+    ///         .build(DbBackend::MySql)?
+    ///         .iter()
+    ///         .map(ToString::to_string)
+    ///         .collect::<Vec<_>>(),
+    ///     [[
+    ///         "UPDATE `fruit` SET `name` = (CASE",
+    ///         "WHEN (`fruit`.`id` = 1) THEN 'Apple' WHEN (`fruit`.`id` = 2) THEN 'Orange'",
+    ///         "ELSE `name` END)",
+    ///         "WHERE `fruit`.`id` IN (1, 2)",
+    ///     ]
+    ///     .join(" ")]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn many_models<A, I>(models: I) -> UpdateManyModels<A>
+    where
+        A: ActiveModelTrait,
+        I: IntoIterator<Item = A>,
+    {
+        UpdateManyModels {
+            models: models.into_iter().collect(),
+        }
+    }
 }
 
 impl<A> UpdateOne<A>
@@ -208,13 +309,398 @@ where
         self.query.value(col, expr);
         self
     }
+
+    /// Join the table of another Entity through a relation, so that the values and the filters
+    /// set on the returned [`JoinedUpdateMany`] can refer to its columns.
+    ///
+    /// The join is rendered as `UPDATE ... FROM` on Postgres and as `UPDATE ... JOIN` on MySQL.
+    /// On SQLite, and on MySQL with more than one join, the filters set after the join are moved
+    /// into a correlated `EXISTS` subquery over the joined tables, and so is each value set after
+    /// the join which refers to a column of a joined table. Custom SQL, `CASE` and subqueries
+    /// are not looked into, and are kept as they are. The values and the filters set before the
+    /// join are on the Entity alone, and are kept as they are on every backend.
+    ///
+    /// When a row matches more than one row of the joined tables, as on a one-to-many relation,
+    /// a value referring to a joined table is taken from any one of them.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Expr, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// let update = fruit::Entity::update_many()
+    ///     .join(fruit::Relation::Cake.def())
+    ///     .col_expr(fruit::Column::Name, Expr::col((cake::Entity, cake::Column::Name)).into())
+    ///     .filter(cake::Column::Name.contains("Cheese"));
+    ///
+    /// assert_eq!(
+    ///     update.build(DbBackend::Postgres).to_string(),
+    ///     [
+    ///         r#"UPDATE "fruit" SET "name" = "cake"."name" FROM "cake""#,
+    ///         r#"WHERE "fruit"."cake_id" = "cake"."id" AND "cake"."name" LIKE '%Cheese%'"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert_eq!(
+    ///     update.build(DbBackend::MySql).to_string(),
+    ///     [
+    ///         "UPDATE `fruit` JOIN `cake`",
+    ///         "ON `fruit`.`cake_id` = `cake`.`id` AND `cake`.`name` LIKE '%Cheese%'",
+    ///         "SET `fruit`.`name` = `cake`.`name`",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert_eq!(
+    ///     update.build(DbBackend::Sqlite).to_string(),
+    ///     [
+    ///         r#"UPDATE "fruit""#,
+    ///         r#"SET "name" = (SELECT "cake"."name" FROM "cake""#,
+    ///         r#"WHERE "fruit"."cake_id" = "cake"."id" AND "cake"."name" LIKE '%Cheese%' LIMIT 1)"#,
+    ///         r#"WHERE EXISTS(SELECT 1 FROM "cake""#,
+    ///         r#"WHERE "fruit"."cake_id" = "cake"."id" AND "cake"."name" LIKE '%Cheese%')"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn join(self, rel: RelationDef) -> JoinedUpdateMany<E> {
+        JoinedUpdateMany {
+            query: self.query,
+            joins: Vec::new(),
+            values: Vec::new(),
+            condition: Condition::all(),
+            entity: PhantomData,
+        }
+        .join(rel)
+    }
+}
+
+impl<E> JoinedUpdateMany<E>
+where
+    E: EntityTrait,
+{
+    /// Join the table of one more Entity through a relation, see [`UpdateMany::join`]
+    pub fn join(mut self, rel: RelationDef) -> Self {
+        self.joins.push((rel.to_tbl.clone(), rel.into_condition()));
+        self
+    }
+
+    /// Add the models to update to Self
+    pub fn set<A>(mut self, model: A) -> Self
+    where
+        A: ActiveModelTrait<Entity = E>,
+    {
+        for col in E::Column::iter() {
+            match model.get(col) {
+                ActiveValue::Set(value) => {
+                    let expr = col.save_as(Expr::val(value));
+                    self.values.push((col.into_iden(), expr));
+                }
+                ActiveValue::Unchanged(_) | ActiveValue::NotSet => {}
+            }
+        }
+        self
+    }
+
+    /// Creates a [SimpleExpr] from a column, which can refer to the joined tables
+    pub fn col_expr<T>(mut self, col: T, expr: SimpleExpr) -> Self
+    where
+        T: IntoIden,
+    {
+        self.values.push((col.into_iden(), expr));
+        self
+    }
+
+    /// Add a filter, which can refer to the joined tables
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: IntoCondition,
+    {
+        self.condition = self.condition.add(filter.into_condition());
+        self
+    }
+
+    /// Build the statement for the backend as [`Statement`], see [`UpdateMany::join`]
+    pub fn build(&self, db_backend: DbBackend) -> Statement {
+        db_backend.build(&self.clone().into_query(db_backend))
+    }
+
+    /// The statement for the backend, see [`UpdateMany::join`]
+    pub fn into_query(self, db_backend: DbBackend) -> UpdateStatement {
+        let mut query = self.query;
+        // MySQL only renders one table in `UPDATE ... JOIN`
+        let from = match db_backend {
+            DbBackend::Postgres => true,
+            DbBackend::MySql => self.joins.len() == 1,
+            DbBackend::Sqlite => false,
+        };
+        if from {
+            query.values(self.values);
+            for (tbl, on) in self.joins {
+                query.from(tbl).cond_where(on);
+            }
+            if !self.condition.is_empty() {
+                query.cond_where(self.condition);
+            }
+        } else {
+            // the filters and the values referring to the joined tables are moved into subqueries
+            let joined: Vec<String> = self
+                .joins
+                .iter()
+                .map(|(tbl, _)| {
+                    unpack_table_alias(tbl)
+                        .unwrap_or_else(|| unpack_table_ref(tbl))
+                        .to_string()
+                })
+                .collect();
+            for (col, expr) in self.values {
+                let mut tables = Vec::new();
+                collect_tables(&expr, &mut tables);
+                let expr = if tables.iter().any(|tbl| joined.contains(tbl)) {
+                    let mut subquery = joined_query(&self.joins, self.condition.clone(), expr);
+                    subquery.limit(1);
+                    SimpleExpr::SubQuery(None, Box::new(subquery.into_sub_query_statement()))
+                } else {
+                    expr
+                };
+                query.value(col, expr);
+            }
+            query.and_where(Expr::exists(joined_query(
+                &self.joins,
+                self.condition,
+                Expr::cust("1"),
+            )));
+        }
+        query
+    }
+}
+
+impl<A> UpdateManyModels<A>
+where
+    A: ActiveModelTrait,
+{
+    /// Build the UPDATE statements for the given backend
+    pub fn build(&self, db_backend: DbBackend) -> Result<Vec<Statement>, DbErr> {
+        Ok(self
+            .queries(db_backend)?
+            .iter()
+            .map(|query| db_backend.build(query))
+            .collect())
+    }
+
+    pub(crate) fn queries(&self, db_backend: DbBackend) -> Result<Vec<UpdateStatement>, DbErr> {
+        let primary_key: Vec<_> = <A::Entity as EntityTrait>::PrimaryKey::iter()
+            .map(|key| key.into_column())
+            .collect();
+
+        // the models having any column set, along with these columns and their primary key
+        let mut models = Vec::new();
+        for model in self.models.iter() {
+            let cols: Vec<_> = <A::Entity as EntityTrait>::Column::iter()
+                .filter(|col| {
+                    <A::Entity as EntityTrait>::PrimaryKey::from_column(*col).is_none()
+                        && matches!(model.get(*col), ActiveValue::Set(_))
+                })
+                .collect();
+            if cols.is_empty() {
+                continue;
+            }
+            let key: Vec<Value> = primary_key
+                .iter()
+                .map(|col| match model.get(*col) {
+                    ActiveValue::Set(value) | ActiveValue::Unchanged(value) => Ok(value),
+                    ActiveValue::NotSet => Err(DbErr::UpdateGetPrimaryKey),
+                })
+                .collect::<Result<_, _>>()?;
+            models.push((model, cols, key));
+        }
+
+        let mut queries = Vec::new();
+        match db_backend {
+            DbBackend::Postgres => {
+                // the rows of `VALUES` have the same columns,
+                // so the models are grouped by their columns set, in order of appearance
+                let mut groups: Vec<(Vec<_>, Vec<_>)> = Vec::new();
+                for (model, cols, key) in models {
+                    let same_cols = |group: &Vec<_>| {
+                        group
+                            .iter()
+                            .map(IdenStatic::as_str)
+                            .eq(cols.iter().map(IdenStatic::as_str))
+                    };
+                    match groups.iter_mut().find(|(group, _)| same_cols(group)) {
+                        Some((_, models)) => models.push((model, key)),
+                        None => groups.push((cols, vec![(model, key)])),
+                    }
+                }
+                for (cols, models) in groups {
+                    let params_per_model = primary_key.len() + cols.len();
+                    let chunk_size = (db_backend.max_bind_params() / params_per_model).max(1);
+                    for chunk in models.chunks(chunk_size) {
+                        queries.push(values_query(&primary_key, &cols, chunk));
+                    }
+                }
+            }
+            DbBackend::MySql | DbBackend::Sqlite => {
+                // a `CASE` per column, listing the models having the column set
+                let mut chunk = Vec::new();
+                let mut params = 0;
+                for (model, cols, key) in models {
+                    let model_params = primary_key.len() + cols.len() * (primary_key.len() + 1);
+                    if !chunk.is_empty() && params + model_params > db_backend.max_bind_params() {
+                        queries.push(case_query(&primary_key, &chunk));
+                        chunk.clear();
+                        params = 0;
+                    }
+                    chunk.push((model, cols, key));
+                    params += model_params;
+                }
+                if !chunk.is_empty() {
+                    queries.push(case_query(&primary_key, &chunk));
+                }
+            }
+        }
+        Ok(queries)
+    }
+}
+
+/// A model to update, along with the columns it has set and its primary key
+type ModelValues<'a, A> = (
+    &'a A,
+    Vec<<<A as ActiveModelTrait>::Entity as EntityTrait>::Column>,
+    Vec<Value>,
+);
+
+/// `UPDATE ... FROM (VALUES ...)` of the models, having the same columns set
+fn values_query<A>(
+    primary_key: &[<A::Entity as EntityTrait>::Column],
+    cols: &[<A::Entity as EntityTrait>::Column],
+    models: &[(&A, Vec<Value>)],
+) -> UpdateStatement
+where
+    A: ActiveModelTrait,
+{
+    let mut query = UpdateStatement::new()
+        .table(A::Entity::default().table_ref())
+        .to_owned();
+    let values = Alias::new("v").into_iden();
+    let column = |i: usize| (values.clone(), Alias::new(format!("column{i}")));
+    for (i, col) in cols.iter().enumerate() {
+        let expr = Expr::col(column(primary_key.len() + i + 1));
+        query.value(*col, col.save_as(expr));
+    }
+    let rows = models
+        .iter()
+        .map(|(model, key)| {
+            let mut row = key.clone();
+            row.extend(cols.iter().filter_map(|col| model.get(*col).into_value()));
+            ValueTuple::Many(row)
+        })
+        .collect();
+    query.from(TableRef::ValuesList(rows, values.clone()));
+    for (i, col) in primary_key.iter().enumerate() {
+        query.and_where(Expr::col((A::Entity::default(), *col)).equals(column(i + 1)));
+    }
+    query
+}
+
+/// `UPDATE ... SET col = CASE ... END` of the models, each `CASE` listing the models
+/// having the column set
+fn case_query<A>(
+    primary_key: &[<A::Entity as EntityTrait>::Column],
+    models: &[ModelValues<A>],
+) -> UpdateStatement
+where
+    A: ActiveModelTrait,
+{
+    let mut query = UpdateStatement::new()
+        .table(A::Entity::default().table_ref())
+        .to_owned();
+    let conditions: Vec<Condition> = models
+        .iter()
+        .map(|(_, _, key)| {
+            primary_key
+                .iter()
+                .zip(key)
+                .fold(Condition::all(), |cond, (col, value)| {
+                    cond.add(col.eq(value.clone()))
+                })
+        })
+        .collect();
+    for col in <A::Entity as EntityTrait>::Column::iter() {
+        let is_set = |cols: &[<A::Entity as EntityTrait>::Column]| {
+            cols.iter().any(|c| c.as_str() == col.as_str())
+        };
+        if !models.iter().any(|(_, cols, _)| is_set(cols)) {
+            continue;
+        }
+        let case = models.iter().zip(conditions.iter()).fold(
+            CaseStatement::new(),
+            |case, ((model, cols, _), cond)| match model.get(col).into_value() {
+                Some(value) if is_set(cols) => {
+                    case.case(cond.clone(), col.save_as(Expr::val(value)))
+                }
+                _ => case,
+            },
+        );
+        query.value(col, case.finally(Expr::col(col)));
+    }
+    let keys = models.iter().map(|(_, _, key)| key.clone());
+    query.cond_where(match primary_key {
+        [col] => {
+            Condition::all().add(Expr::col((A::Entity::default(), *col)).is_in(keys.flatten()))
+        }
+        _ => Condition::all().add(
+            Expr::tuple(
+                primary_key
+                    .iter()
+                    .map(|col| Expr::col((A::Entity::default(), *col)).into()),
+            )
+            .in_tuples(keys.map(ValueTuple::Many)),
+        ),
+    });
+    query
+}
+
+/// Collect the tables of the columns the expression refers to.
+/// Custom SQL, `CASE` and subqueries are not looked into.
+fn collect_tables(expr: &SimpleExpr, tables: &mut Vec<String>) {
+    match expr {
+        SimpleExpr::Column(
+            ColumnRef::TableColumn(tbl, _)
+            | ColumnRef::SchemaTableColumn(_, tbl, _)
+            | ColumnRef::TableAsterisk(tbl),
+        ) => tables.push(tbl.to_string()),
+        SimpleExpr::Tuple(exprs) | SimpleExpr::CustomWithExpr(_, exprs) => {
+            for expr in exprs {
+                collect_tables(expr, tables);
+            }
+        }
+        SimpleExpr::Unary(_, expr) | SimpleExpr::AsEnum(_, expr) => collect_tables(expr, tables),
+        SimpleExpr::Binary(left, _, right) => {
+            collect_tables(left, tables);
+            collect_tables(right, tables);
+        }
+        SimpleExpr::FunctionCall(call) => {
+            for expr in call.get_args() {
+                collect_tables(expr, tables);
+            }
+        }
+        SimpleExpr::Column(ColumnRef::Column(_) | ColumnRef::Asterisk)
+        | SimpleExpr::SubQuery(..)
+        | SimpleExpr::Value(_)
+        | SimpleExpr::Values(_)
+        | SimpleExpr::Custom(_)
+        | SimpleExpr::Keyword(_)
+        | SimpleExpr::Case(_)
+        | SimpleExpr::Constant(_) => {}
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::tests_cfg::{cake, fruit, lunch_set, sea_orm_active_enums::Tea};
-    use crate::{entity::*, query::*, DbBackend};
-    use sea_query::{Expr, Value};
+    use crate::tests_cfg::{
+        cake, cake_filling, filling, fruit, lunch_set, sea_orm_active_enums::Tea, vendor,
+    };
+    use crate::{entity::*, query::*, DbBackend, DbErr, MockDatabase, MockExecResult, Transaction};
+    use sea_query::{Expr, Func, Value};
 
     #[test]
     fn update_1() {
@@ -328,4 +814,252 @@ mod tests {
             r#"UPDATE "lunch_set" SET "tea" = CAST('EverydayTea' AS "tea") WHERE "lunch_set"."id" = 1"#,
         );
     }
+
+    #[test]
+    fn update_many_models_1() {
+        let update = Update::many_models([
+            fruit::ActiveModel {
+                id: Unchanged(1),
+                name: Set("Apple".to_owned()),
+                cake_id: NotSet,
+            },
+            fruit::ActiveModel {
+                id: Unchanged(2),
+                name: Set("Orange".to_owned()),
+                cake_id: Set(Some(3)),
+            },
+            fruit::ActiveModel {
+                id: Unchanged(3),
+                name: Unchanged("Grape".to_owned()),
+                cake_id: NotSet,
+            },
+            fruit::ActiveModel {
+                id: Unchanged(4),
+                name: Set("Cherry".to_owned()),
+                cake_id: NotSet,
+            },
+        ]);
+
+        assert_eq!(
+            update
+                .build(DbBackend::Sqlite)
+                .unwrap()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [[
+                r#"UPDATE "fruit" SET "name" = (CASE"#,
+                r#"WHEN ("fruit"."id" = 1) THEN 'Apple' WHEN ("fruit"."id" = 2) THEN 'Orange'"#,
+                r#"WHEN ("fruit"."id" = 4) THEN 'Cherry' ELSE "name" END),"#,
+                r#""cake_id" = (CASE WHEN ("fruit"."id" = 2) THEN 3 ELSE "cake_id" END)"#,
+                r#"WHERE "fruit"."id" IN (1, 2, 4)"#,
+            ]
+            .join(" ")]
+        );
+        // the rows of `VALUES` have the same columns
+        assert_eq!(
+            update
+                .build(DbBackend::Postgres)
+                .unwrap()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                [
+                    r#"UPDATE "fruit" SET "name" = "v"."column2""#,
+                    r#"FROM (VALUES (1, 'Apple'), (4, 'Cherry')) AS "v""#,
+                    r#"WHERE "fruit"."id" = "v"."column1""#,
+                ]
+                .join(" "),
+                [
+                    r#"UPDATE "fruit" SET "name" = "v"."column2", "cake_id" = "v"."column3""#,
+                    r#"FROM (VALUES (2, 'Orange', 3)) AS "v""#,
+                    r#"WHERE "fruit"."id" = "v"."column1""#,
+                ]
+                .join(" "),
+            ]
+        );
+    }
+
+    #[test]
+    fn update_many_models_2() {
+        assert_eq!(
+            Update::many_models([
+                lunch_set::ActiveModel {
+                    id: Unchanged(1),
+                    tea: Set(Tea::EverydayTea),
+                    ..Default::default()
+                },
+                lunch_set::ActiveModel {
+                    id: Unchanged(2),
+                    tea: Set(Tea::BreakfastTea),
+                    ..Default::default()
+                },
+            ])
+            .build(DbBackend::Postgres)
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+            [[
+                r#"UPDATE "lunch_set" SET "tea" = CAST("v"."column2" AS "tea")"#,
+                r#"FROM (VALUES (1, 'EverydayTea'), (2, 'BreakfastTea')) AS "v""#,
+                r#"WHERE "lunch_set"."id" = "v"."column1""#,
+            ]
+            .join(" ")]
+        );
+    }
+
+    #[test]
+    fn update_many_models_chunks() {
+        let models: Vec<_> = (0..DbBackend::Sqlite.max_bind_params() as i32)
+            .map(|id| cake::ActiveModel {
+                id: Unchanged(id),
+                name: Set(format!("Cake {id}")),
+            })
+            .collect();
+
+        // 3 parameters per model
+        let chunks: Vec<_> = Update::many_models(models)
+            .build(DbBackend::Sqlite)
+            .unwrap()
+            .into_iter()
+            .map(|stmt| stmt.values.map(|values| values.0.len()))
+            .collect();
+        assert_eq!(chunks, [Some(32766), Some(32766), Some(32766)]);
+    }
+
+    #[test]
+    fn update_many_models_without_primary_key() {
+        let update = Update::many_models([
+            cake::ActiveModel {
+                id: Unchanged(1),
+                name: Set("Apple Pie".to_owned()),
+            },
+            cake::ActiveModel {
+                id: NotSet,
+                name: Set("Orange Cake".to_owned()),
+            },
+        ]);
+
+        assert_eq!(
+            update.build(DbBackend::Postgres),
+            Err(DbErr::UpdateGetPrimaryKey)
+        );
+    }
+
+    #[test]
+    fn update_many_join_2() {
+        let update = cake_filling::Entity::update_many()
+            .join(cake_filling::Relation::Filling.def())
+            .join(filling::Relation::Vendor.def())
+            .col_expr(cake_filling::Column::CakeId, Expr::value(1))
+            .filter(vendor::Column::Name.eq("Bakery"));
+
+        assert_eq!(
+            update.build(DbBackend::Postgres).to_string(),
+            [
+                r#"UPDATE "cake_filling" SET "cake_id" = 1 FROM "filling", "vendor""#,
+                r#"WHERE "cake_filling"."filling_id" = "filling"."id""#,
+                r#"AND "filling"."vendor_id" = "vendor"."id""#,
+                r#"AND "vendor"."name" = 'Bakery'"#,
+            ]
+            .join(" ")
+        );
+        // MySQL only renders one table in `UPDATE ... JOIN`
+        assert_eq!(
+            update.build(DbBackend::MySql).to_string(),
+            [
+                "UPDATE `cake_filling` SET `cake_id` = 1",
+                "WHERE EXISTS(SELECT 1 FROM `filling`, `vendor`",
+                "WHERE `cake_filling`.`filling_id` = `filling`.`id`",
+                "AND `filling`.`vendor_id` = `vendor`.`id`",
+                "AND `vendor`.`name` = 'Bakery')",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn update_many_join_one_to_many() {
+        assert_eq!(
+            cake::Entity::update_many()
+                .join(cake::Relation::Fruit.def())
+                .col_expr(
+                    cake::Column::Name,
+                    Expr::col((fruit::Entity, fruit::Column::Name)).into()
+                )
+                .filter(fruit::Column::Name.eq("Apple"))
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"UPDATE "cake" SET "name" = (SELECT "fruit"."name" FROM "fruit""#,
+                r#"WHERE "cake"."id" = "fruit"."cake_id" AND "fruit"."name" = 'Apple' LIMIT 1)"#,
+                r#"WHERE EXISTS(SELECT 1 FROM "fruit""#,
+                r#"WHERE "cake"."id" = "fruit"."cake_id" AND "fruit"."name" = 'Apple')"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn update_many_join_values() {
+        // only the values referring to a joined table are moved into a subquery,
+        // while the values and the filters set before the join stay on the Entity
+        assert_eq!(
+            cake::Entity::update_many()
+                .col_expr(cake::Column::Id, Expr::col(cake::Column::Id).add(1))
+                .filter(cake::Column::Id.gt(1))
+                .join(cake::Relation::Fruit.def())
+                .col_expr(
+                    cake::Column::Name,
+                    Func::upper(Expr::col((fruit::Entity, fruit::Column::Name))).into()
+                )
+                .filter(fruit::Column::Name.eq("Apple"))
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"UPDATE "cake" SET "id" = "id" + 1,"#,
+                r#""name" = (SELECT UPPER("fruit"."name") FROM "fruit""#,
+                r#"WHERE "cake"."id" = "fruit"."cake_id" AND "fruit"."name" = 'Apple' LIMIT 1)"#,
+                r#"WHERE "cake"."id" > 1 AND EXISTS(SELECT 1 FROM "fruit""#,
+                r#"WHERE "cake"."id" = "fruit"."cake_id" AND "fruit"."name" = 'Apple')"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[smol_potat::test]
+    async fn update_many_join_exec() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let res = fruit::Entity::update_many()
+            .join(fruit::Relation::Cake.def())
+            .col_expr(fruit::Column::Name, Expr::value("Apple"))
+            .filter(cake::Column::Name.eq("Cheese Cake"))
+            .exec(&db)
+            .await?;
+        assert_eq!(res.rows_affected, 1);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"UPDATE "fruit" SET "name" = $1 FROM "cake""#,
+                    r#"WHERE "fruit"."cake_id" = "cake"."id" AND "cake"."name" = $2"#,
+                ]
+                .join(" ")
+                .as_str(),
+                ["Apple".into(), "Cheese Cake".into()]
+            )]
+        );
+
+        Ok(())
+    }
 }
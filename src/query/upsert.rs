@@ -0,0 +1,255 @@
+use crate::{
+    ActiveModelTrait, ColumnTrait, DbBackend, DbErr, EntityTrait, IdenStatic, Insert,
+    IntoActiveModel, Iterable, PrimaryKeyToColumn, QueryTrait, Statement,
+};
+use sea_query::{OnConflict, Value};
+use std::collections::HashMap;
+
+type Column<A> = <<A as ActiveModelTrait>::Entity as EntityTrait>::Column;
+
+/// Insert ActiveModels, or update the rows which already exist, keyed on the primary key
+/// or on a unique column of the Entity
+#[derive(Clone, Debug)]
+pub struct Upsert<A>
+where
+    A: ActiveModelTrait,
+{
+    pub(crate) models: Vec<A>,
+    pub(crate) target: Option<Vec<Column<A>>>,
+    pub(crate) update: Option<Vec<Column<A>>>,
+}
+
+impl<A> Upsert<A>
+where
+    A: ActiveModelTrait,
+{
+    /// Upsert one Model or ActiveModel
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let cake = cake::Model {
+    ///     id: 1,
+    ///     name: "Apple Pie".to_owned(),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     Upsert::one(cake.clone())
+    ///         .build(DbBackend::Postgres)?
+    ///         .to_string(),
+    ///     [
+    ///         r#"INSERT INTO "cake" ("id", "name") VALUES (1, 'Apple Pie')"#,
+    ///         r#"ON CONFLICT ("id") DO UPDATE SET "name" = "excluded"."name""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert_eq!(
+    ///     Upsert::one(cake).build(DbBackend::MySql)?.to_string(),
+    ///     [
+    ///         "INSERT INTO `cake` (`id`, `name`) VALUES (1, 'Apple Pie')",
+    ///         "ON DUPLICATE KEY UPDATE `name` = VALUES(`name`)",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// # Ok::<(), sea_orm::DbErr>(())
+    /// ```
+    pub fn one<M>(model: M) -> Self
+    where
+        M: IntoActiveModel<A>,
+    {
+        Self::many([model])
+    }
+
+    /// Upsert many Models or ActiveModels, which must all have the same columns set
+    pub fn many<M, I>(models: I) -> Self
+    where
+        M: IntoActiveModel<A>,
+        I: IntoIterator<Item = M>,
+    {
+        Self {
+            models: models
+                .into_iter()
+                .map(IntoActiveModel::into_active_model)
+                .collect(),
+            target: None,
+            update: None,
+        }
+    }
+
+    /// Key the upsert on these columns, which must be the primary key or a `#[sea_orm(unique)]` column.
+    ///
+    /// By default, it is keyed on the primary key if it is set,
+    /// and on the first unique column which is set otherwise.
+    /// MySQL does not take a conflict target, so `ON DUPLICATE KEY` applies to any unique key there.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::indexes, DbBackend};
+    ///
+    /// let model = indexes::ActiveModel {
+    ///     indexes_id: NotSet,
+    ///     unique_attr: Set(1),
+    ///     index1_attr: Set(2),
+    ///     index2_attr: Set(3),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     Upsert::one(model.clone())
+    ///         .build(DbBackend::Postgres)?
+    ///         .to_string(),
+    ///     [
+    ///         r#"INSERT INTO "public"."indexes" ("unique_attr", "index1_attr", "index2_attr") VALUES (1, 2, 3)"#,
+    ///         r#"ON CONFLICT ("unique_attr") DO UPDATE"#,
+    ///         r#"SET "index1_attr" = "excluded"."index1_attr", "index2_attr" = "excluded"."index2_attr""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert_eq!(
+    ///     Upsert::one(model.clone())
+    ///         .on_columns([indexes::Column::Index2Attr])
+    ///         .update_columns([indexes::Column::Index1Attr])
+    ///         .build(DbBackend::Sqlite)?
+    ///         .to_string(),
+    ///     [
+    ///         r#"INSERT INTO "public"."indexes" ("unique_attr", "index1_attr", "index2_attr") VALUES (1, 2, 3)"#,
+    ///         r#"ON CONFLICT ("index2_attr") DO UPDATE SET "index1_attr" = "excluded"."index1_attr""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    ///
+    /// // not declared unique
+    /// assert!(Upsert::one(model)
+    ///     .on_columns([indexes::Column::Index1Attr])
+    ///     .build(DbBackend::Postgres)
+    ///     .is_err());
+    /// # Ok::<(), sea_orm::DbErr>(())
+    /// ```
+    pub fn on_columns<I>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = Column<A>>,
+    {
+        self.target = Some(columns.into_iter().collect());
+        self
+    }
+
+    /// Only update these columns of the existing rows, instead of every column which is set.
+    /// The columns of the key are never updated.
+    pub fn update_columns<I>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = Column<A>>,
+    {
+        self.update = Some(columns.into_iter().collect());
+        self
+    }
+
+    /// Build the INSERT statement, with an `ON CONFLICT` (or `ON DUPLICATE KEY`) clause.
+    /// Of the models with the same key, only the last one is inserted.
+    pub fn build(&self, db_backend: DbBackend) -> Result<Statement, DbErr> {
+        Ok(self.prepare()?.0.build(db_backend))
+    }
+
+    /// The INSERT statement along with the columns the upsert is keyed on
+    pub(crate) fn prepare(&self) -> Result<(Insert<A>, Vec<Column<A>>), DbErr> {
+        let first = self
+            .models
+            .first()
+            .ok_or_else(|| DbErr::Custom("Upsert: no models to upsert".to_owned()))?;
+        let is_set = |col: &Column<A>| {
+            let value = first.get(*col);
+            value.is_set() || value.is_unchanged()
+        };
+        let primary_key: Vec<Column<A>> = <A::Entity as EntityTrait>::PrimaryKey::iter()
+            .map(PrimaryKeyToColumn::into_column)
+            .collect();
+
+        let target = match &self.target {
+            Some(target) => {
+                let is_unique = match target.as_slice() {
+                    [col] => col.def().is_unique(),
+                    _ => false,
+                };
+                if !is_unique && !same_columns(target, &primary_key) {
+                    return Err(DbErr::Custom(format!(
+                        "Upsert: {} is not the primary key or a unique column",
+                        column_names(target)
+                    )));
+                }
+                target.clone()
+            }
+            None if primary_key.iter().all(is_set) => primary_key,
+            None => Column::<A>::iter()
+                .find(|col| col.def().is_unique() && is_set(col))
+                .map(|col| vec![col])
+                .ok_or_else(|| {
+                    DbErr::Custom(
+                        "Upsert: neither the primary key nor a unique column is set".to_owned(),
+                    )
+                })?,
+        };
+        if let Some(col) = target.iter().find(|col| !is_set(col)) {
+            return Err(DbErr::Custom(format!(
+                "Upsert: the key column `{}` is not set",
+                col.as_str()
+            )));
+        }
+
+        let update: Vec<Column<A>> = Column::<A>::iter()
+            .filter(|col| is_set(col) && !target.iter().any(|t| t.as_str() == col.as_str()))
+            .filter(|col| match &self.update {
+                Some(update) => update.iter().any(|u| u.as_str() == col.as_str()),
+                None => true,
+            })
+            .collect();
+
+        let mut on_conflict = OnConflict::columns(target.clone());
+        if update.is_empty() {
+            on_conflict.do_nothing_on(target.clone());
+        } else {
+            on_conflict.update_columns(update);
+        }
+
+        // a row can only be upserted once per statement, so the last model of each key wins
+        let keys = self.keys(&target);
+        let last: HashMap<&Vec<Value>, usize> =
+            keys.iter().enumerate().map(|(i, key)| (key, i)).collect();
+        let models = self
+            .models
+            .iter()
+            .zip(&keys)
+            .enumerate()
+            .filter(|(i, (_, key))| last.get(key) == Some(i))
+            .map(|(_, (model, _))| model.clone());
+
+        let insert = Insert::many(models).on_conflict(on_conflict);
+        Ok((insert, target))
+    }
+
+    /// The values of the key of each model
+    pub(crate) fn keys(&self, target: &[Column<A>]) -> Vec<Vec<Value>> {
+        self.models
+            .iter()
+            .map(|model| {
+                target
+                    .iter()
+                    .filter_map(|col| model.get(*col).into_value())
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+fn same_columns<C>(a: &[C], b: &[C]) -> bool
+where
+    C: IdenStatic,
+{
+    a.len() == b.len() && a.iter().all(|a| b.iter().any(|b| a.as_str() == b.as_str()))
+}
+
+fn column_names<C>(cols: &[C]) -> String
+where
+    C: IdenStatic,
+{
+    cols.iter()
+        .map(|col| format!("`{}`", col.as_str()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
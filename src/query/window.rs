@@ -0,0 +1,317 @@
+use crate::ColumnTrait;
+use sea_query::{
+    Alias, Expr, Frame, FrameType, Func, FunctionCall, Order, OverStatement, SimpleExpr,
+    WindowStatement,
+};
+
+/// A window specification, i.e. the `PARTITION BY`, `ORDER BY` and frame clause inside `OVER (...)`,
+/// built from the columns of an Entity.
+///
+/// Columns are qualified with the table name of their Entity.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+///
+/// assert_eq!(
+///     fruit::Entity::find()
+///         .select_only()
+///         .column(fruit::Column::Name)
+///         .expr_window_as(
+///             WindowFunc::row_number(),
+///             Window::new()
+///                 .partition_by(fruit::Column::CakeId)
+///                 .order_by(fruit::Column::Name, Order::Asc),
+///             "rank",
+///         )
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     [
+///         r#"SELECT "fruit"."name", ROW_NUMBER() OVER ("#,
+///         r#"PARTITION BY "fruit"."cake_id" ORDER BY "fruit"."name" ASC ) AS "rank" FROM "fruit""#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Window {
+    stmt: WindowStatement,
+}
+
+impl Window {
+    /// Create an empty window, equivalent to `OVER ()`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Partition by a column
+    pub fn partition_by<C>(mut self, col: C) -> Self
+    where
+        C: ColumnTrait,
+    {
+        self.stmt
+            .add_partition_by(Expr::col((col.entity_name(), col)).into());
+        self
+    }
+
+    /// Partition by an arbitrary expression
+    pub fn partition_by_expr<T>(mut self, expr: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.stmt.add_partition_by(expr.into());
+        self
+    }
+
+    /// Order rows within a partition by a column
+    pub fn order_by<C>(mut self, col: C, order: Order) -> Self
+    where
+        C: ColumnTrait,
+    {
+        self.stmt
+            .order_by_expr(Expr::col((col.entity_name(), col)).into(), order);
+        self
+    }
+
+    /// Order rows within a partition by an arbitrary expression
+    pub fn order_by_expr<T>(mut self, expr: T, order: Order) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.stmt.order_by_expr(expr.into(), order);
+        self
+    }
+
+    /// Set the frame clause to `ROWS BETWEEN start AND end`
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::{Frame, Func}, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .select_only()
+    ///         .column(fruit::Column::Id)
+    ///         .expr_window_as(
+    ///             Func::sum(fruit::Column::Id.into_expr()),
+    ///             Window::new()
+    ///                 .order_by(fruit::Column::Id, Order::Asc)
+    ///                 .rows_between(Frame::UnboundedPreceding, Frame::CurrentRow),
+    ///             "running_total",
+    ///         )
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         "SELECT `fruit`.`id`, SUM(`fruit`.`id`) OVER (  ORDER BY `fruit`.`id` ASC",
+    ///         "ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW ) AS `running_total` FROM `fruit`",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn rows_between(mut self, start: Frame, end: Frame) -> Self {
+        self.stmt.frame_between(FrameType::Rows, start, end);
+        self
+    }
+
+    /// Set the frame clause to `RANGE BETWEEN start AND end`
+    pub fn range_between(mut self, start: Frame, end: Frame) -> Self {
+        self.stmt.frame_between(FrameType::Range, start, end);
+        self
+    }
+
+    /// Set the frame clause to `ROWS start`
+    pub fn rows(mut self, start: Frame) -> Self {
+        self.stmt.frame_start(FrameType::Rows, start);
+        self
+    }
+
+    /// Set the frame clause to `RANGE start`
+    pub fn range(mut self, start: Frame) -> Self {
+        self.stmt.frame_start(FrameType::Range, start);
+        self
+    }
+}
+
+impl From<Window> for WindowStatement {
+    fn from(window: Window) -> Self {
+        window.stmt
+    }
+}
+
+/// Window functions, to be used with [`QuerySelect::expr_window_as`](crate::QuerySelect::expr_window_as)
+/// and [`QuerySelect::expr_window_name_as`](crate::QuerySelect::expr_window_name_as).
+///
+/// Aggregate functions such as [`Func::sum`] can be used over a window as well.
+#[derive(Clone, Copy, Debug)]
+pub struct WindowFunc;
+
+// The counts of `NTILE`, `LAG`, `LEAD` and `NTH_VALUE` take `i32`: Postgres only defines these
+// functions for an `integer` argument, and an unsigned value is bound as `int8`.
+impl WindowFunc {
+    fn call(name: &str) -> FunctionCall {
+        Func::cust(Alias::new(name))
+    }
+
+    /// `ROW_NUMBER()`
+    pub fn row_number() -> FunctionCall {
+        Self::call("ROW_NUMBER")
+    }
+
+    /// `RANK()`
+    pub fn rank() -> FunctionCall {
+        Self::call("RANK")
+    }
+
+    /// `DENSE_RANK()`
+    pub fn dense_rank() -> FunctionCall {
+        Self::call("DENSE_RANK")
+    }
+
+    /// `PERCENT_RANK()`
+    pub fn percent_rank() -> FunctionCall {
+        Self::call("PERCENT_RANK")
+    }
+
+    /// `CUME_DIST()`
+    pub fn cume_dist() -> FunctionCall {
+        Self::call("CUME_DIST")
+    }
+
+    /// `NTILE(buckets)`
+    pub fn ntile(buckets: i32) -> FunctionCall {
+        Self::call("NTILE").arg(buckets)
+    }
+
+    /// `LAG(expr, offset)`
+    pub fn lag<T>(expr: T, offset: i32) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+    {
+        Self::call("LAG").args([expr.into(), offset.into()])
+    }
+
+    /// `LEAD(expr, offset)`
+    pub fn lead<T>(expr: T, offset: i32) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+    {
+        Self::call("LEAD").args([expr.into(), offset.into()])
+    }
+
+    /// `FIRST_VALUE(expr)`
+    pub fn first_value<T>(expr: T) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+    {
+        Self::call("FIRST_VALUE").arg(expr)
+    }
+
+    /// `LAST_VALUE(expr)`
+    pub fn last_value<T>(expr: T) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+    {
+        Self::call("LAST_VALUE").arg(expr)
+    }
+
+    /// `NTH_VALUE(expr, n)`
+    pub fn nth_value<T>(expr: T, n: i32) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+    {
+        Self::call("NTH_VALUE").args([expr.into(), n.into()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::fruit;
+    use crate::{
+        entity::prelude::*, DbBackend, MockDatabase, Order, QuerySelect, QueryTrait, Transaction,
+        Value, Window, WindowFunc,
+    };
+    use std::collections::BTreeMap;
+
+    #[smol_potat::test]
+    async fn window_into_tuple() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([[
+                BTreeMap::from([("id", 1i32.into()), ("prev_id", Option::<i32>::None.into())]),
+                BTreeMap::from([("id", 2i32.into()), ("prev_id", 1i32.into())]),
+            ]])
+            .into_connection();
+
+        assert_eq!(
+            fruit::Entity::find()
+                .select_only()
+                .column(fruit::Column::Id)
+                .expr_window_name_as(
+                    WindowFunc::lag(Expr::col((fruit::Entity, fruit::Column::Id)), 1),
+                    "w",
+                    "prev_id"
+                )
+                .window("w", Window::new().order_by(fruit::Column::Id, Order::Asc))
+                .into_tuple::<(i32, Option<i32>)>()
+                .all(&db)
+                .await?,
+            [(1, None), (2, Some(1))]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Sqlite,
+                [
+                    r#"SELECT "fruit"."id", LAG("fruit"."id", ?) OVER "w" AS "prev_id""#,
+                    r#"FROM "fruit" WINDOW "w" AS ( ORDER BY "fruit"."id" ASC)"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn window_func_counts_bind_as_integer() {
+        let stmt = fruit::Entity::find()
+            .select_only()
+            .expr_window_as(WindowFunc::ntile(4), Window::new(), "bucket")
+            .expr_window_as(
+                WindowFunc::lag(Expr::col((fruit::Entity, fruit::Column::Id)), 1),
+                Window::new(),
+                "prev_id",
+            )
+            .expr_window_as(
+                WindowFunc::lead(Expr::col((fruit::Entity, fruit::Column::Id)), 2),
+                Window::new(),
+                "next_id",
+            )
+            .expr_window_as(
+                WindowFunc::nth_value(Expr::col((fruit::Entity, fruit::Column::Id)), 3),
+                Window::new(),
+                "third_id",
+            )
+            .build(DbBackend::Postgres);
+
+        assert_eq!(
+            stmt.sql,
+            [
+                r#"SELECT NTILE($1) OVER (  ) AS "bucket","#,
+                r#"LAG("fruit"."id", $2) OVER (  ) AS "prev_id","#,
+                r#"LEAD("fruit"."id", $3) OVER (  ) AS "next_id","#,
+                r#"NTH_VALUE("fruit"."id", $4) OVER (  ) AS "third_id" FROM "fruit""#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            stmt.values.map(|values| values.0),
+            Some(vec![
+                Value::Int(Some(4)),
+                Value::Int(Some(1)),
+                Value::Int(Some(2)),
+                Value::Int(Some(3)),
+            ])
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    ActiveEnum, ColumnTrait, ColumnType, DbBackend, EntityTrait, Iterable, PrimaryKeyArity,
-    PrimaryKeyToColumn, PrimaryKeyTrait, RelationTrait, Schema,
+    ActiveEnum, ColumnTrait, ColumnType, DbBackend, EntityTrait, FullTextSearch, Iterable,
+    PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait, RelationTrait, Schema, Statement,
 };
 use sea_query::{
     extension::postgres::{Type, TypeCreateStatement},
@@ -41,6 +41,17 @@ impl Schema {
         create_index_from_entity(entity, self.backend)
     }
 
+    /// Creates the full-text index over the columns of an Entity marked `#[sea_orm(full_text)]`,
+    /// returning an empty Vec if there are none. See [FullTextSearch::create_index] for more details
+    pub fn create_full_text_index_from_entity<E>(&self, entity: E) -> Vec<Statement>
+    where
+        E: EntityTrait,
+    {
+        FullTextSearch::new(entity)
+            .create_index(self.backend)
+            .unwrap_or_default()
+    }
+
     /// Creates a column definition for example to update a table.
     ///
     /// ```
@@ -6,23 +6,61 @@ pub mod cake;
 pub mod cake_expanded;
 pub mod cake_filling;
 pub mod cake_filling_price;
+pub mod category;
 pub mod entity_linked;
 pub mod filling;
 pub mod fruit;
 pub mod indexes;
 pub mod lunch_set;
 pub mod lunch_set_expanded;
+#[cfg(feature = "postgres-array")]
+pub mod menu;
+pub mod recipe;
 pub mod rust_keyword;
 pub mod sea_orm_active_enums;
+pub mod typed_cake;
 pub mod vendor;
 
 pub use cake::Entity as Cake;
 pub use cake_expanded::Entity as CakeExpanded;
 pub use cake_filling::Entity as CakeFilling;
 pub use cake_filling_price::Entity as CakeFillingPrice;
+pub use category::Entity as Category;
 pub use filling::Entity as Filling;
 pub use fruit::Entity as Fruit;
 pub use lunch_set::Entity as LunchSet;
 pub use lunch_set_expanded::Entity as LunchSetExpanded;
+#[cfg(feature = "postgres-array")]
+pub use menu::Entity as Menu;
+pub use recipe::Entity as Recipe;
 pub use rust_keyword::Entity as RustKeyword;
+pub use typed_cake::Entity as TypedCake;
 pub use vendor::Entity as Vendor;
+
+/// A cake Model for mock query results
+#[cfg(test)]
+pub(crate) fn cake_model(id: i32) -> cake::Model {
+    let name = match id {
+        1 => "apple cake",
+        2 => "orange cake",
+        3 => "fruit cake",
+        4 => "chocolate cake",
+        _ => "",
+    }
+    .to_string();
+    cake::Model { id, name }
+}
+
+/// A fruit Model for mock query results
+#[cfg(test)]
+pub(crate) fn fruit_model(id: i32, cake_id: Option<i32>) -> fruit::Model {
+    let name = match id {
+        1 => "apple",
+        2 => "orange",
+        3 => "grape",
+        4 => "strawberry",
+        _ => "",
+    }
+    .to_string();
+    fruit::Model { id, name, cake_id }
+}
@@ -1,6 +1,10 @@
 use crate::sea_query::{Nullable, ValueType};
 use crate::{ActiveValue, Value};
 
+mod text;
+
+pub(crate) use text::{parse_value, value_kinds, value_text};
+
 /// Default value for T
 pub trait DefaultActiveValue {
     /// `Default::default()` if implemented, dummy value otherwise
@@ -0,0 +1,539 @@
+//! The Postgres text representation of values, as used by `COPY`, cursors and query parameters
+
+use crate::{error::*, Value};
+use sea_query::{ColumnType, PostgresQueryBuilder, QueryBuilder};
+
+/// The Postgres text representation of a value, `None` for NULL
+pub(crate) fn value_text(value: &Value) -> Result<Option<String>, DbErr> {
+    let text = match value {
+        Value::Bool(Some(b)) => if *b { "t" } else { "f" }.to_owned(),
+        Value::String(Some(s)) => s.as_ref().clone(),
+        Value::Char(Some(c)) => c.to_string(),
+        Value::Bytes(Some(bytes)) => {
+            let mut text = String::with_capacity(2 + bytes.len() * 2);
+            text.push_str("\\x");
+            for byte in bytes.iter() {
+                text.push_str(&format!("{byte:02x}"));
+            }
+            text
+        }
+        #[cfg(feature = "with-json")]
+        Value::Json(Some(json)) => json.to_string(),
+        #[cfg(feature = "postgres-array")]
+        Value::Array(_, Some(values)) => {
+            let mut elems = Vec::with_capacity(values.len());
+            for value in values.iter() {
+                elems.push(match value_text(value)? {
+                    Some(text) => {
+                        format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+                    }
+                    None => "NULL".to_owned(),
+                });
+            }
+            format!("{{{}}}", elems.join(","))
+        }
+        _ => {
+            // numbers, dates and times etc. are rendered as literals, some of them quoted
+            let literal = PostgresQueryBuilder.value_to_string(value);
+            if literal == "NULL" {
+                return Ok(None);
+            }
+            match literal
+                .strip_prefix('\'')
+                .and_then(|literal| literal.strip_suffix('\''))
+            {
+                Some(unquoted) => unquoted.replace("''", "'"),
+                None => literal,
+            }
+        }
+    };
+    Ok(Some(text))
+}
+
+/// The null values of the types a column may be stored as, most likely first
+pub(crate) fn value_kinds(col_type: &ColumnType) -> Vec<Value> {
+    let integers = |first: Value| {
+        vec![
+            first,
+            Value::Int(None),
+            Value::BigInt(None),
+            Value::SmallInt(None),
+            Value::TinyInt(None),
+            Value::Unsigned(None),
+            Value::BigUnsigned(None),
+            Value::SmallUnsigned(None),
+            Value::TinyUnsigned(None),
+        ]
+    };
+    let mut kinds = match col_type {
+        ColumnType::Boolean => vec![Value::Bool(None)],
+        ColumnType::TinyInteger => integers(Value::TinyInt(None)),
+        ColumnType::SmallInteger => integers(Value::SmallInt(None)),
+        ColumnType::Integer | ColumnType::Year => integers(Value::Int(None)),
+        ColumnType::BigInteger => integers(Value::BigInt(None)),
+        ColumnType::TinyUnsigned => integers(Value::TinyUnsigned(None)),
+        ColumnType::SmallUnsigned => integers(Value::SmallUnsigned(None)),
+        ColumnType::Unsigned => integers(Value::Unsigned(None)),
+        ColumnType::BigUnsigned => integers(Value::BigUnsigned(None)),
+        ColumnType::Float => vec![Value::Float(None), Value::Double(None)],
+        ColumnType::Double => vec![Value::Double(None), Value::Float(None)],
+        ColumnType::Decimal(_) | ColumnType::Money(_) => vec![
+            #[cfg(feature = "with-rust_decimal")]
+            Value::Decimal(None),
+            #[cfg(feature = "with-bigdecimal")]
+            Value::BigDecimal(None),
+            Value::Double(None),
+        ],
+        ColumnType::Char(_) => vec![Value::Char(None)],
+        ColumnType::Binary(_) | ColumnType::VarBinary(_) | ColumnType::Blob => {
+            vec![Value::Bytes(None)]
+        }
+        #[cfg(feature = "with-json")]
+        ColumnType::Json | ColumnType::JsonBinary => vec![Value::Json(None)],
+        ColumnType::Date => vec![
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDate(None),
+            #[cfg(feature = "with-time")]
+            Value::TimeDate(None),
+        ],
+        ColumnType::Time => vec![
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoTime(None),
+            #[cfg(feature = "with-time")]
+            Value::TimeTime(None),
+        ],
+        ColumnType::DateTime | ColumnType::Timestamp => vec![
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDateTime(None),
+            #[cfg(feature = "with-time")]
+            Value::TimeDateTime(None),
+        ],
+        ColumnType::TimestampWithTimeZone => vec![
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDateTimeWithTimeZone(None),
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDateTimeUtc(None),
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDateTimeLocal(None),
+            #[cfg(feature = "with-time")]
+            Value::TimeDateTimeWithTimeZone(None),
+        ],
+        #[cfg(feature = "with-uuid")]
+        ColumnType::Uuid => vec![Value::Uuid(None)],
+        #[cfg(feature = "with-ipnetwork")]
+        ColumnType::Cidr | ColumnType::Inet => vec![Value::IpNetwork(None)],
+        #[cfg(feature = "postgres-vector")]
+        ColumnType::Vector(_) => vec![Value::Vector(None)],
+        #[cfg(feature = "postgres-array")]
+        ColumnType::Array(col_type) => value_kinds(col_type)
+            .iter()
+            .filter_map(|kind| Some(Value::Array(array_type(kind)?, None)))
+            .collect(),
+        _ => Vec::new(),
+    };
+    // enums, custom types and anything else read from text
+    kinds.push(Value::String(None));
+    kinds
+}
+
+/// Parse the Postgres text representation of a value of the same type as `kind`
+pub(crate) fn parse_value(kind: &Value, text: Option<&str>) -> Option<Value> {
+    let text = match text {
+        Some(text) => text,
+        None => return Some(kind.clone()),
+    };
+    let value = match kind {
+        Value::Bool(_) => Value::Bool(Some(match text {
+            "t" | "true" => true,
+            "f" | "false" => false,
+            _ => return None,
+        })),
+        Value::TinyInt(_) => Value::TinyInt(Some(text.parse().ok()?)),
+        Value::SmallInt(_) => Value::SmallInt(Some(text.parse().ok()?)),
+        Value::Int(_) => Value::Int(Some(text.parse().ok()?)),
+        Value::BigInt(_) => Value::BigInt(Some(text.parse().ok()?)),
+        Value::TinyUnsigned(_) => Value::TinyUnsigned(Some(text.parse().ok()?)),
+        Value::SmallUnsigned(_) => Value::SmallUnsigned(Some(text.parse().ok()?)),
+        Value::Unsigned(_) => Value::Unsigned(Some(text.parse().ok()?)),
+        Value::BigUnsigned(_) => Value::BigUnsigned(Some(text.parse().ok()?)),
+        Value::Float(_) => Value::Float(Some(text.parse().ok()?)),
+        Value::Double(_) => Value::Double(Some(text.parse().ok()?)),
+        Value::String(_) => Value::String(Some(Box::new(text.to_owned()))),
+        Value::Char(_) => {
+            let mut chars = text.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Value::Char(Some(c)),
+                _ => return None,
+            }
+        }
+        Value::Bytes(_) => {
+            let hex = text.strip_prefix("\\x")?;
+            let bytes = (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+                .collect::<Option<Vec<u8>>>()?;
+            Value::Bytes(Some(Box::new(bytes)))
+        }
+        #[cfg(feature = "with-json")]
+        Value::Json(_) => Value::Json(Some(Box::new(serde_json::from_str(text).ok()?))),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDate(_) => Value::ChronoDate(Some(Box::new(chrono_date(text)?))),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoTime(_) => Value::ChronoTime(Some(Box::new(chrono_time(text)?))),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTime(_) => {
+            let (date, time) = split_timestamp(text)?;
+            Value::ChronoDateTime(Some(Box::new(
+                chrono_date(date)?.and_time(chrono_time(time)?),
+            )))
+        }
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeWithTimeZone(_) => {
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(chrono_date_time_tz(text)?)))
+        }
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeUtc(_) => Value::ChronoDateTimeUtc(Some(Box::new(
+            chrono_date_time_tz(text)?.with_timezone(&chrono::Utc),
+        ))),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeLocal(_) => Value::ChronoDateTimeLocal(Some(Box::new(
+            chrono_date_time_tz(text)?.with_timezone(&chrono::Local),
+        ))),
+        #[cfg(feature = "with-time")]
+        Value::TimeDate(_) => Value::TimeDate(Some(Box::new(time_date(text)?))),
+        #[cfg(feature = "with-time")]
+        Value::TimeTime(_) => Value::TimeTime(Some(Box::new(time_time(text)?))),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTime(_) => {
+            let (date, time) = split_timestamp(text)?;
+            Value::TimeDateTime(Some(Box::new(time::PrimitiveDateTime::new(
+                time_date(date)?,
+                time_time(time)?,
+            ))))
+        }
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTimeWithTimeZone(_) => {
+            let (date, time) = split_timestamp(text)?;
+            let (time, offset) = split_offset(time)?;
+            Value::TimeDateTimeWithTimeZone(Some(Box::new(
+                time::PrimitiveDateTime::new(time_date(date)?, time_time(time)?)
+                    .assume_offset(time::UtcOffset::from_whole_seconds(offset).ok()?),
+            )))
+        }
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(_) => Value::Uuid(Some(Box::new(text.parse().ok()?))),
+        #[cfg(feature = "with-rust_decimal")]
+        Value::Decimal(_) => Value::Decimal(Some(Box::new(text.parse().ok()?))),
+        #[cfg(feature = "with-bigdecimal")]
+        Value::BigDecimal(_) => Value::BigDecimal(Some(Box::new(text.parse().ok()?))),
+        #[cfg(feature = "with-ipnetwork")]
+        Value::IpNetwork(_) => Value::IpNetwork(Some(Box::new(text.parse().ok()?))),
+        #[cfg(feature = "postgres-vector")]
+        Value::Vector(_) => {
+            let elems = text.strip_prefix('[')?.strip_suffix(']')?;
+            let elems = elems
+                .split(',')
+                .map(|elem| elem.trim().parse().ok())
+                .collect::<Option<Vec<f32>>>()?;
+            Value::Vector(Some(Box::new(elems.into())))
+        }
+        #[cfg(feature = "postgres-array")]
+        Value::Array(ty, _) => {
+            let kind = element_kind(ty)?;
+            let elems = array_elements(text)?
+                .iter()
+                .map(|elem| parse_value(&kind, elem.as_deref()))
+                .collect::<Option<Vec<_>>>()?;
+            Value::Array(ty.clone(), Some(Box::new(elems)))
+        }
+        #[allow(unreachable_patterns)]
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Split the Postgres text representation of a one-dimensional array into its elements,
+/// `None` for NULL
+#[cfg(feature = "postgres-array")]
+fn array_elements(text: &str) -> Option<Vec<Option<String>>> {
+    let inner = text.strip_prefix('{')?.strip_suffix('}')?;
+    let mut elems = Vec::new();
+    if inner.is_empty() {
+        return Some(elems);
+    }
+    let mut chars = inner.chars().peekable();
+    loop {
+        if chars.next_if_eq(&'"').is_some() {
+            let mut elem = String::new();
+            loop {
+                match chars.next()? {
+                    '\\' => elem.push(chars.next()?),
+                    '"' => break,
+                    c => elem.push(c),
+                }
+            }
+            elems.push(Some(elem));
+        } else {
+            let mut elem = String::new();
+            while let Some(c) = chars.next_if(|c| *c != ',') {
+                // nested arrays are not supported
+                if c == '{' || c == '"' {
+                    return None;
+                }
+                elem.push(c);
+            }
+            let elem = elem.trim();
+            elems.push((!elem.eq_ignore_ascii_case("NULL")).then(|| elem.to_owned()));
+        }
+        match chars.next() {
+            Some(',') => {}
+            Some(_) => return None,
+            None => return Some(elems),
+        }
+    }
+}
+
+macro_rules! array_types {
+    ($($(#[$meta:meta])* $name:ident,)*) => {
+        /// The type of an array of values of the same type as `kind`
+        #[cfg(feature = "postgres-array")]
+        fn array_type(kind: &Value) -> Option<sea_query::ArrayType> {
+            match kind {
+                $($(#[$meta])* Value::$name(_) => Some(sea_query::ArrayType::$name),)*
+                #[allow(unreachable_patterns)]
+                _ => None,
+            }
+        }
+
+        /// The null value of the elements of an array type
+        #[cfg(feature = "postgres-array")]
+        fn element_kind(ty: &sea_query::ArrayType) -> Option<Value> {
+            match ty {
+                $($(#[$meta])* sea_query::ArrayType::$name => Some(Value::$name(None)),)*
+                #[allow(unreachable_patterns)]
+                _ => None,
+            }
+        }
+    };
+}
+
+array_types!(
+    Bool,
+    TinyInt,
+    SmallInt,
+    Int,
+    BigInt,
+    TinyUnsigned,
+    SmallUnsigned,
+    Unsigned,
+    BigUnsigned,
+    Float,
+    Double,
+    String,
+    Char,
+    Bytes,
+    #[cfg(feature = "with-json")]
+    Json,
+    #[cfg(feature = "with-chrono")]
+    ChronoDate,
+    #[cfg(feature = "with-chrono")]
+    ChronoTime,
+    #[cfg(feature = "with-chrono")]
+    ChronoDateTime,
+    #[cfg(feature = "with-chrono")]
+    ChronoDateTimeUtc,
+    #[cfg(feature = "with-chrono")]
+    ChronoDateTimeLocal,
+    #[cfg(feature = "with-chrono")]
+    ChronoDateTimeWithTimeZone,
+    #[cfg(feature = "with-time")]
+    TimeDate,
+    #[cfg(feature = "with-time")]
+    TimeTime,
+    #[cfg(feature = "with-time")]
+    TimeDateTime,
+    #[cfg(feature = "with-time")]
+    TimeDateTimeWithTimeZone,
+    #[cfg(feature = "with-uuid")]
+    Uuid,
+    #[cfg(feature = "with-rust_decimal")]
+    Decimal,
+    #[cfg(feature = "with-bigdecimal")]
+    BigDecimal,
+    #[cfg(feature = "with-ipnetwork")]
+    IpNetwork,
+);
+
+/// Split `YYYY-MM-DD HH:MM:SS` into the date and the time
+#[cfg(any(feature = "with-chrono", feature = "with-time"))]
+fn split_timestamp(text: &str) -> Option<(&str, &str)> {
+    text.split_once(' ').or_else(|| text.split_once('T'))
+}
+
+/// Split `HH:MM:SS+HH:MM` into the time and the offset in seconds
+#[cfg(any(feature = "with-chrono", feature = "with-time"))]
+fn split_offset(text: &str) -> Option<(&str, i32)> {
+    let at = text.rfind(['+', '-'])?;
+    let (time, offset) = text.split_at(at);
+    let sign = if offset.starts_with('-') { -1 } else { 1 };
+    let mut parts = offset[1..].split(':');
+    let mut seconds = 0;
+    for unit in [3600, 60, 1] {
+        if let Some(part) = parts.next() {
+            seconds += part.parse::<i32>().ok()? * unit;
+        }
+    }
+    Some((time, sign * seconds))
+}
+
+/// The year, month and day of `YYYY-MM-DD`
+#[cfg(any(feature = "with-chrono", feature = "with-time"))]
+fn date_parts(text: &str) -> Option<(i32, u32, u32)> {
+    let mut parts = text.splitn(3, '-');
+    Some((
+        parts.next()?.parse().ok()?,
+        parts.next()?.parse().ok()?,
+        parts.next()?.parse().ok()?,
+    ))
+}
+
+/// The hour, minute, second and nanosecond of `HH:MM:SS.ffffff`
+#[cfg(any(feature = "with-chrono", feature = "with-time"))]
+fn time_parts(text: &str) -> Option<(u32, u32, u32, u32)> {
+    let (hms, fraction) = text.split_once('.').unwrap_or((text, ""));
+    let mut parts = hms.splitn(3, ':');
+    let nano = if fraction.is_empty() {
+        0
+    } else {
+        format!("{fraction:0<9}").get(..9)?.parse().ok()?
+    };
+    Some((
+        parts.next()?.parse().ok()?,
+        parts.next()?.parse().ok()?,
+        parts.next()?.parse().ok()?,
+        nano,
+    ))
+}
+
+#[cfg(feature = "with-chrono")]
+fn chrono_date(text: &str) -> Option<chrono::NaiveDate> {
+    let (year, month, day) = date_parts(text)?;
+    chrono::NaiveDate::from_ymd_opt(year, month, day)
+}
+
+#[cfg(feature = "with-chrono")]
+fn chrono_time(text: &str) -> Option<chrono::NaiveTime> {
+    let (hour, minute, second, nano) = time_parts(text)?;
+    chrono::NaiveTime::from_hms_nano_opt(hour, minute, second, nano)
+}
+
+#[cfg(feature = "with-chrono")]
+fn chrono_date_time_tz(text: &str) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    let (date, time) = split_timestamp(text)?;
+    let (time, offset) = split_offset(time)?;
+    chrono_date(date)?
+        .and_time(chrono_time(time)?)
+        .and_local_timezone(chrono::FixedOffset::east_opt(offset)?)
+        .single()
+}
+
+#[cfg(feature = "with-time")]
+fn time_date(text: &str) -> Option<time::Date> {
+    let (year, month, day) = date_parts(text)?;
+    let month = time::Month::try_from(u8::try_from(month).ok()?).ok()?;
+    time::Date::from_calendar_date(year, month, u8::try_from(day).ok()?).ok()
+}
+
+#[cfg(feature = "with-time")]
+fn time_time(text: &str) -> Option<time::Time> {
+    let (hour, minute, second, nano) = time_parts(text)?;
+    time::Time::from_hms_nano(
+        u8::try_from(hour).ok()?,
+        u8::try_from(minute).ok()?,
+        u8::try_from(second).ok()?,
+        nano,
+    )
+    .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_value, value_text};
+    use crate::{DbErr, Value};
+
+    #[test]
+    #[cfg(feature = "with-chrono")]
+    fn value_text_round_trip() -> Result<(), DbErr> {
+        let date_time = chrono::NaiveDate::from_ymd_opt(2024, 2, 29)
+            .and_then(|date| date.and_hms_micro_opt(23, 59, 1, 250))
+            .expect("a valid date time");
+        assert_eq!(
+            value_text(&date_time.into())?.as_deref(),
+            Some("2024-02-29 23:59:01.000250")
+        );
+        assert_eq!(
+            parse_value(
+                &Value::ChronoDateTime(None),
+                Some("2024-02-29 23:59:01.00025")
+            ),
+            Some(date_time.into())
+        );
+        assert_eq!(
+            value_text(&vec![1u8, 254].into())?.as_deref(),
+            Some("\\x01fe")
+        );
+        assert_eq!(
+            parse_value(&Value::Bytes(None), Some("\\x01fe")),
+            Some(vec![1u8, 254].into())
+        );
+        assert_eq!(value_text(&Value::Int(None))?, None);
+        assert_eq!(
+            value_text(&vec!["a\"b".to_owned(), "c".to_owned()].into())?.as_deref(),
+            Some(r#"{"a\"b","c"}"#)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "postgres-array")]
+    fn parse_array() {
+        use super::value_kinds;
+        use sea_query::{ArrayType, ColumnType, RcOrArc};
+
+        let kind = |col_type| value_kinds(&ColumnType::Array(RcOrArc::new(col_type)))[0].clone();
+
+        assert_eq!(
+            parse_value(&kind(ColumnType::Integer), Some("{1,NULL,3}")),
+            Some(Value::Array(
+                ArrayType::Int,
+                Some(Box::new(vec![1i32.into(), Value::Int(None), 3i32.into()]))
+            ))
+        );
+        assert_eq!(
+            parse_value(&kind(ColumnType::Text), Some(r#"{"a\"b",NULL,"NULL",c}"#)),
+            Some(Value::Array(
+                ArrayType::String,
+                Some(Box::new(vec![
+                    "a\"b".into(),
+                    Value::String(None),
+                    "NULL".into(),
+                    "c".into()
+                ]))
+            ))
+        );
+        assert_eq!(
+            parse_value(&kind(ColumnType::Text), Some("{}")),
+            Some(Vec::<String>::new().into())
+        );
+        assert_eq!(
+            parse_value(&kind(ColumnType::Integer), None),
+            Some(Value::Array(ArrayType::Int, None))
+        );
+        // nested arrays are not supported
+        assert_eq!(
+            parse_value(&kind(ColumnType::Integer), Some("{{1,2},{3,4}}")),
+            None
+        );
+    }
+}
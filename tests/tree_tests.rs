@@ -0,0 +1,133 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, tests_cfg::category, ConnectionTrait, Schema, Set, TreeItem};
+
+// cargo test --features sqlx-sqlite,runtime-async-std-native-tls --test tree_tests
+#[sea_orm_macros::test]
+async fn tree_tests() -> Result<(), DbErr> {
+    let ctx = TestContext::new("tree_tests").await;
+    create_category_table(&ctx.db).await?;
+    create_cycle(&ctx.db).await?;
+    find_descendants_cycle(&ctx.db).await?;
+    find_ancestors_cycle(&ctx.db).await?;
+    find_descendants_sorted(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_category_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let db_backend = db.get_database_backend();
+    let create = Schema::new(db_backend).create_table_from_entity(category::Entity);
+    create_table_without_asserts(db, &create).await?;
+
+    Ok(())
+}
+
+/// 1 -> 2 -> 3 -> 1, and 2 -> 4
+pub async fn create_cycle(db: &DatabaseConnection) -> Result<(), DbErr> {
+    for (id, parent_id) in [(1, None), (2, Some(1)), (3, Some(2)), (4, Some(2))] {
+        category::ActiveModel {
+            id: Set(id),
+            parent_id: Set(parent_id),
+            name: Set(format!("Category {id}")),
+        }
+        .insert(db)
+        .await?;
+    }
+    category::ActiveModel {
+        id: Set(1),
+        parent_id: Set(Some(3)),
+        ..Default::default()
+    }
+    .update(db)
+    .await?;
+
+    Ok(())
+}
+
+fn item(id: i32, parent_id: i32, depth: u32, path: &str) -> TreeItem<category::Model> {
+    TreeItem {
+        model: category::Model {
+            id,
+            parent_id: Some(parent_id),
+            name: format!("Category {id}"),
+        },
+        depth,
+        path: path.to_owned(),
+    }
+}
+
+pub async fn find_descendants_cycle(db: &DatabaseConnection) -> Result<(), DbErr> {
+    assert_eq!(
+        category::Entity::find_by_id(1)
+            .find_descendants(category::Relation::Parent.def())
+            .all(db)
+            .await?,
+        [
+            item(1, 3, 0, "1"),
+            item(2, 1, 1, "1/2"),
+            item(3, 2, 2, "1/2/3"),
+            item(4, 2, 2, "1/2/4"),
+        ]
+    );
+
+    Ok(())
+}
+
+pub async fn find_ancestors_cycle(db: &DatabaseConnection) -> Result<(), DbErr> {
+    assert_eq!(
+        category::Entity::find_by_id(4)
+            .find_ancestors(category::Relation::Parent.def())
+            .all(db)
+            .await?,
+        [
+            item(4, 2, 0, "4"),
+            item(2, 1, 1, "4/2"),
+            item(1, 3, 2, "4/2/1"),
+            item(3, 2, 3, "4/2/1/3"),
+        ]
+    );
+
+    Ok(())
+}
+
+/// Siblings come in the order of their keys, `6` before `10`
+pub async fn find_descendants_sorted(db: &DatabaseConnection) -> Result<(), DbErr> {
+    for (id, parent_id) in [(5, None), (10, Some(5)), (6, Some(5)), (11, Some(10))] {
+        category::ActiveModel {
+            id: Set(id),
+            parent_id: Set(parent_id),
+            name: Set(format!("Category {id}")),
+        }
+        .insert(db)
+        .await?;
+    }
+
+    assert_eq!(
+        category::Entity::find_by_id(5)
+            .find_descendants(category::Relation::Parent.def())
+            .all(db)
+            .await?,
+        [
+            TreeItem {
+                model: category::Model {
+                    id: 5,
+                    parent_id: None,
+                    name: "Category 5".to_owned(),
+                },
+                depth: 0,
+                path: "5".to_owned(),
+            },
+            item(6, 5, 1, "5/6"),
+            item(10, 5, 1, "5/10"),
+            item(11, 10, 2, "5/10/11"),
+        ]
+    );
+
+    Ok(())
+}